@@ -0,0 +1,168 @@
+//! Generate ISO 3166 enumerations into a Rust source file.
+//!
+//! ```text
+//! iso3166-codegen --lukes-json <PATH> [--data-date <YYYY-MM-DD>] [--lukes-format <FORMAT>]
+//!                 [--m49 <LANGUAGE>=<PATH>]... [--cldr <PATH>]... [--data-source <SOURCE>]
+//!                 [--name-index] [--iso-codes <PATH>] [--sovereignty <PATH>] [--groups <PATH>]
+//!                 [--currencies <PATH>] [--withdrawn-currencies <PATH>]
+//!                 [--calling-codes <PATH>] [--phone-number-metadata <PATH>]
+//!                 [--public-suffix-list <PATH>]
+//!                 [--territory-info <PATH>] [--time-zones <PATH>] [--geography <PATH>]
+//!                 [--neighbours <PATH>] [--country-info <PATH>] [--boundaries <PATH>]
+//!                 [--boundary-tolerance <DEGREES>] [--iban-registry <PATH>] [--examples]
+//!                 [--output <PATH>]
+//! ```
+//!
+//! The format is named after the upstream file, e.g. `all.csv`, and defaults to `all.json`. M49
+//! tables are given with the ISO 639-1 code of their language, e.g. `--m49 fr=m49-fr.csv`, and CLDR
+//! files are the `territories.json` of each locale to include. `--name-index` generates the name
+//! lookup index, which includes the formal and common names from the `iso_3166-1.json` file of
+//! Debian's `iso-codes` when `--iso-codes` is given. `--sovereignty` generates the sovereignty
+//! methods from a CSV file such as the one bundled with `iso3166-parsers`, and `--groups` generates
+//! the group memberships from a JSON file in the same way. `--currencies` and
+//! `--withdrawn-currencies` generate the ISO 4217 currencies from SIX's `list-one.xml` and
+//! `list-three.xml`, and `--calling-codes` generates the E.164 calling codes from a CSV file such
+//! as the one bundled with `iso3166-parsers`, or `--phone-number-metadata` from libphonenumber's
+//! `PhoneNumberMetadata.xml`. `--public-suffix-list` generates the country code
+//! top-level domains from Mozilla's `public_suffix_list.dat`, and `--territory-info` generates the
+//! languages of each country from CLDR's `territoryInfo.json`. `--time-zones` generates the IANA
+//! time zones of each country from tzdata's `zone.tab` or `zone1970.tab`. `--geography` generates
+//! the capital, centroid and bounding box of each country from a CSV file such as the one bundled
+//! with `iso3166-parsers`, and `--neighbours` generates the land and maritime neighbours of each
+//! country in the same way. `--country-info` generates the land neighbours from the `neighbours`
+//! column of `GeoNames`' `countryInfo.txt` instead, keeping the maritime neighbours of
+//! `--neighbours`. `--boundaries` generates the simplified boundary of each country from a
+//! `GeoJSON` file such as Natural Earth's Admin 0 countries, simplified to within
+//! `--boundary-tolerance` degrees. `--iban-registry` generates the IBAN format of each country from
+//! a SWIFT IBAN Registry text file such as the one bundled with `iso3166-parsers`. When
+//! `--data-date` is not given, `DATA_DATE` is generated as `unknown`, and when `--output` is not
+//! given, the generated code is written to stdout.
+
+use iso3166_codegen::{Builder, Source};
+use iso3166_parsers::{lukes::Format, m49::Language};
+use std::{
+    env,
+    io::{self, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+const USAGE: &str = "Usage: iso3166-codegen --lukes-json <PATH> [--data-date <YYYY-MM-DD>] \
+                     [--lukes-format <FORMAT>] [--m49 <LANGUAGE>=<PATH>]... \
+                     [--cldr <PATH>]... [--data-source <SOURCE>] [--name-index] \
+                     [--iso-codes <PATH>] [--sovereignty <PATH>] [--groups <PATH>] \
+                     [--currencies <PATH>] [--withdrawn-currencies <PATH>] \
+                     [--calling-codes <PATH>] [--phone-number-metadata <PATH>] \
+                     [--public-suffix-list <PATH>] \
+                     [--territory-info <PATH>] [--time-zones <PATH>] \
+                     [--geography <PATH>] [--neighbours <PATH>] [--country-info <PATH>] \
+                     [--boundaries <PATH>] [--boundary-tolerance <DEGREES>] \
+                     [--iban-registry <PATH>] [--examples] [--output <PATH>]";
+
+fn main() -> ExitCode {
+    let (builder, output) = match parse_args(env::args().skip(1)) {
+        Ok(Some(parsed)) => parsed,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match output {
+        Some(path) => builder.write(path),
+        None => builder
+            .render()
+            .and_then(|code| Ok(io::stdout().write_all(code.as_bytes())?)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Could not generate code: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parse the command-line arguments, returning `None` when help was requested.
+fn parse_args(
+    mut args: impl Iterator<Item = String>,
+) -> Result<Option<(Builder, Option<PathBuf>)>, String> {
+    let mut builder = Builder::new();
+    let mut output = Option::<PathBuf>::None;
+    let mut lukes_path = Option::<PathBuf>::None;
+    let mut lukes_format = Format::default();
+
+    while let Some(arg) = args.next() {
+        if arg == "--help" || arg == "-h" {
+            return Ok(None);
+        }
+
+        if arg == "--examples" {
+            builder = builder.examples(true);
+            continue;
+        }
+
+        if arg == "--name-index" {
+            builder = builder.name_index(true);
+            continue;
+        }
+
+        let value = args
+            .next()
+            .ok_or_else(|| format!("`{arg}` requires a value"))?;
+
+        match arg.as_str() {
+            "--lukes-json" => lukes_path = Some(value.into()),
+            "--lukes-format" => {
+                lukes_format = value
+                    .parse()
+                    .map_err(|error| format!("Invalid `--lukes-format`: {error}"))?;
+            }
+            "--m49" => {
+                let (code, path) = value
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid `--m49` value `{value}`"))?;
+                let language = Language::from_code(code)
+                    .ok_or_else(|| format!("Unknown `--m49` language `{code}`"))?;
+                builder = builder.m49_file(language, path);
+            }
+            "--cldr" => builder = builder.cldr_file(value),
+            "--iso-codes" => builder = builder.iso_codes_file(value),
+            "--sovereignty" => builder = builder.sovereignty_file(value),
+            "--groups" => builder = builder.groups_file(value),
+            "--currencies" => builder = builder.currencies_file(value),
+            "--withdrawn-currencies" => builder = builder.withdrawn_currencies_file(value),
+            "--calling-codes" => builder = builder.calling_codes_file(value),
+            "--phone-number-metadata" => builder = builder.phone_number_metadata_file(value),
+            "--public-suffix-list" => builder = builder.public_suffix_list_file(value),
+            "--territory-info" => builder = builder.territory_info_file(value),
+            "--time-zones" => builder = builder.time_zones_file(value),
+            "--geography" => builder = builder.geography_file(value),
+            "--neighbours" => builder = builder.neighbours_file(value),
+            "--country-info" => builder = builder.country_info_file(value),
+            "--boundaries" => builder = builder.boundaries_file(value),
+            "--boundary-tolerance" => {
+                let tolerance = value
+                    .parse()
+                    .map_err(|error| format!("Invalid `--boundary-tolerance`: {error}"))?;
+                builder = builder.boundary_tolerance(tolerance);
+            }
+            "--iban-registry" => builder = builder.iban_registry_file(value),
+            "--data-date" => builder = builder.data_date(value),
+            "--data-source" => builder = builder.data_source(value),
+            "--output" => output = Some(value.into()),
+            arg => return Err(format!("Unknown argument `{arg}`")),
+        }
+    }
+
+    if let Some(path) = lukes_path {
+        builder = builder.source(Source::LukesFile(path, lukes_format));
+    }
+
+    Ok(Some((builder, output)))
+}
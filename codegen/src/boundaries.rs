@@ -0,0 +1,311 @@
+//! Code generation for the simplified boundary of each country.
+
+use crate::{Error, lukes::Config};
+use iso3166_parsers::{
+    boundaries::{Boundary, Position},
+    lukes::Country,
+};
+use proc_macro2::{Ident, Literal, TokenStream};
+
+/// The number of rows of the grid index, each 10 degrees of latitude.
+const ROWS: usize = 18;
+
+/// The number of columns of the grid index, each 10 degrees of longitude.
+const COLUMNS: usize = 36;
+
+/// A simplified ring, ready to be generated.
+struct Ring {
+    country: Ident,
+    hole: bool,
+    points: Vec<Position>,
+    area: f64,
+}
+
+impl Ring {
+    /// The south, west, north and east edges of the ring.
+    fn bounds(&self) -> [f64; 4] {
+        self.points.iter().fold(
+            [f64::MAX, f64::MAX, f64::MIN, f64::MIN],
+            |[south, west, north, east], [longitude, latitude]| {
+                [
+                    south.min(*latitude),
+                    west.min(*longitude),
+                    north.max(*latitude),
+                    east.max(*longitude),
+                ]
+            },
+        )
+    }
+}
+
+/// Convert decimal degrees to a literal number of millionths of a degree.
+fn microdegrees(value: f64) -> Literal {
+    #[allow(clippy::cast_possible_truncation)]
+    Literal::i32_unsuffixed((value * 1e6).round() as i32)
+}
+
+/// The distance from a point to the segment between two others, in degrees.
+fn segment_distance(point: Position, start: Position, end: Position) -> f64 {
+    let [x, y] = point;
+    let [x1, y1] = start;
+    let [dx, dy] = [end[0] - x1, end[1] - y1];
+    let length = dx * dx + dy * dy;
+    let t = if length == 0.0 {
+        0.0
+    } else {
+        (((x - x1) * dx + (y - y1) * dy) / length).clamp(0.0, 1.0)
+    };
+
+    (x - (x1 + t * dx)).hypot(y - (y1 + t * dy))
+}
+
+/// The area of a ring, in square degrees.
+fn area(points: &[Position]) -> f64 {
+    let twice = (0..points.len())
+        .map(|i| {
+            let [x1, y1] = points[(i + points.len() - 1) % points.len()];
+            let [x2, y2] = points[i];
+            x1 * y2 - x2 * y1
+        })
+        .sum::<f64>();
+
+    twice.abs() / 2.0
+}
+
+/// Simplify a closed ring with the Douglas-Peucker algorithm, so that every dropped position is
+/// within `tolerance` degrees of the result.
+///
+/// The ring is split at the position furthest from its first, and at least three positions are
+/// kept from any ring which has them. The closing position is dropped.
+pub(crate) fn simplify(ring: &[Position], tolerance: f64) -> Vec<Position> {
+    let mut points = ring.to_vec();
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if points.len() < 3 || tolerance <= 0.0 {
+        return points;
+    }
+
+    let first = points[0];
+    let far = (1..points.len())
+        .max_by(|a, b| {
+            let distance = |i: &usize| segment_distance(points[*i], first, first);
+            distance(a).total_cmp(&distance(b))
+        })
+        .unwrap_or(1);
+
+    let count = points.len();
+    points.push(first);
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[far] = true;
+
+    let mut stack = vec![(0, far), (far, count)];
+    while let Some((start, end)) = stack.pop() {
+        let furthest = (start + 1..end)
+            .map(|i| (i, segment_distance(points[i], points[start], points[end])))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((index, distance)) = furthest
+            && distance > tolerance
+        {
+            keep[index] = true;
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+
+    // Keep small rings, such as islands, as triangles rather than dropping them.
+    if keep.iter().filter(|keep| **keep).count() < 3 {
+        let apex = (1..count)
+            .filter(|i| *i != far)
+            .max_by(|a, b| {
+                let distance = |i: &usize| segment_distance(points[*i], first, points[far]);
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap_or(1);
+        keep[apex] = true;
+    }
+
+    points
+        .into_iter()
+        .take(count)
+        .zip(keep)
+        .filter_map(|(point, keep)| keep.then_some(point))
+        .collect()
+}
+
+/// The range of grid cells an edge pair falls in, clamped to the grid.
+fn cells(low: f64, high: f64, origin: f64, count: usize) -> std::ops::RangeInclusive<usize> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let cell = |value: f64| (((value - origin) / 10.0).floor().max(0.0) as usize).min(count - 1);
+    cell(low)..=cell(high)
+}
+
+/// Generate `BoundaryRing`, `BOUNDARY_POINTS`, `BOUNDARY_RINGS`, `BOUNDARY_GRID` and
+/// `BOUNDARY_TOLERANCE` from the given boundaries, simplified to within `tolerance` degrees.
+///
+/// Boundaries of countries which are not in `data` are left out, as are rings which simplify to
+/// fewer than three positions.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    boundaries: &[Boundary],
+    tolerance: f64,
+) -> Result<TokenStream, Error> {
+    let tolerance = tolerance.max(0.0);
+    let mut rings = Vec::<Ring>::new();
+
+    for boundary in boundaries {
+        let Some(country) = data.iter().find(|c| c.alpha2_str() == boundary.alpha2) else {
+            continue;
+        };
+
+        let in_range = |[longitude, latitude]: &Position| {
+            (-180.0..=180.0).contains(longitude) && (-90.0..=90.0).contains(latitude)
+        };
+
+        for polygon in &boundary.polygons {
+            let outlines = std::iter::once((false, &polygon.exterior))
+                .chain(polygon.holes.iter().map(|hole| (true, hole)));
+
+            for (hole, ring) in outlines {
+                if !ring.iter().all(in_range) {
+                    return Err(Error::InvalidBoundaries(boundary.alpha2.clone()));
+                }
+
+                let points = simplify(ring, tolerance);
+                if points.len() < 3 {
+                    continue;
+                }
+
+                rings.push(Ring {
+                    country: config.ident(country),
+                    hole,
+                    area: area(&points),
+                    points,
+                });
+            }
+        }
+    }
+
+    // The sort is stable, so rings of equal area stay in file order.
+    rings.sort_by(|a, b| a.area.total_cmp(&b.area));
+
+    let mut grid = vec![Vec::<u32>::new(); ROWS * COLUMNS];
+    let mut point = Vec::new();
+    let mut ring_tokens = Vec::new();
+
+    for (index, ring) in rings.iter().enumerate() {
+        let [south, west, north, east] = ring.bounds();
+        let index = u32::try_from(index).unwrap_or(u32::MAX);
+        for row in cells(south, north, -90.0, ROWS) {
+            for column in cells(west, east, -180.0, COLUMNS) {
+                grid[row * COLUMNS + column].push(index);
+            }
+        }
+
+        let start = point.len();
+        point.extend(ring.points.iter().map(|[longitude, latitude]| {
+            let latitude = microdegrees(*latitude);
+            let longitude = microdegrees(*longitude);
+            quote::quote!([#latitude, #longitude])
+        }));
+        let end = point.len();
+
+        let country = &ring.country;
+        let hole = ring.hole;
+        let start = Literal::u32_unsuffixed(u32::try_from(start).unwrap_or(u32::MAX));
+        let end = Literal::u32_unsuffixed(u32::try_from(end).unwrap_or(u32::MAX));
+        let bounds = [south, west, north, east].map(microdegrees);
+        ring_tokens.push(quote::quote! {
+            BoundaryRing {
+                country: Alpha2::#country,
+                hole: #hole,
+                start: #start,
+                end: #end,
+                bounds: [#(#bounds),*],
+            }
+        });
+    }
+
+    let cell = grid.iter().map(|rings| {
+        let rings = rings.iter().copied().map(Literal::u32_unsuffixed);
+        quote::quote!(&[#(#rings),*])
+    });
+    let tolerance = Literal::f64_unsuffixed(tolerance);
+
+    Ok(quote::quote! {
+        /// A ring of a country's simplified boundary, as a range of [`BOUNDARY_POINTS`].
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct BoundaryRing {
+            /// The country the ring belongs to.
+            pub country: Alpha2,
+            /// Whether the ring is a hole cut out of the country, such as an enclave.
+            pub hole: bool,
+            /// The index of the ring's first point.
+            pub start: u32,
+            /// The index after the ring's last point.
+            pub end: u32,
+            /// The south, west, north and east edges of the ring, in millionths of a degree.
+            pub bounds: [i32; 4],
+        }
+
+        /// The points of every boundary ring, as latitude and longitude in millionths of a
+        /// degree. Rings are implicitly closed.
+        pub const BOUNDARY_POINTS: &[[i32; 2]] = &[#(#point),*];
+
+        /// Every boundary ring, ordered from the smallest area to the largest.
+        pub const BOUNDARY_RINGS: &[BoundaryRing] = &[#(#ring_tokens),*];
+
+        /// The indexes into [`BOUNDARY_RINGS`] of the rings whose bounds overlap each cell of a
+        /// 10 degree grid, in rows from 90°S and columns from 180°W.
+        pub const BOUNDARY_GRID: &[&[u32]] = &[#(#cell),*];
+
+        /// The tolerance the boundaries were simplified with, in degrees.
+        pub const BOUNDARY_TOLERANCE: f64 = #tolerance;
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{segment_distance, simplify};
+
+    #[test]
+    fn simplify_within_tolerance() {
+        let ring = (0..=360)
+            .map(|degree| {
+                let angle = f64::from(degree).to_radians();
+                [angle.cos() * 5.0, angle.sin() * 5.0]
+            })
+            .collect::<Vec<_>>();
+
+        for tolerance in [0.01, 0.1, 1.0] {
+            let simplified = simplify(&ring, tolerance);
+            assert!(simplified.len() >= 3);
+            assert!(simplified.len() < ring.len());
+
+            let mut closed = simplified.clone();
+            closed.push(simplified[0]);
+            for point in &ring {
+                let distance = closed
+                    .windows(2)
+                    .map(|edge| segment_distance(*point, edge[0], edge[1]))
+                    .fold(f64::MAX, f64::min);
+                assert!(distance <= tolerance, "{point:?} is {distance} away");
+            }
+        }
+    }
+
+    #[test]
+    fn simplify_square() {
+        let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0]];
+
+        for (tolerance, expected) in [(0.0, 4), (0.5, 4), (100.0, 3)] {
+            assert_eq!(expected, simplify(&square, tolerance).len(), "{tolerance}");
+        }
+    }
+}
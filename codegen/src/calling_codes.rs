@@ -0,0 +1,104 @@
+//! Code generation for the international calling codes of each country.
+
+use crate::{Error, lukes::Config};
+use iso3166_parsers::{e164::Record, lukes::Country};
+use proc_macro2::TokenStream;
+
+/// Generate `CallingPrefix`, `CALLING_PREFIXES` and `Alpha2::calling_codes()` from the given
+/// records.
+///
+/// Records for countries which are not in `data` are left out.
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    records: &[Record],
+) -> Result<TokenStream, Error> {
+    if let Some(record) = records.iter().find(|record| {
+        record.country_code.is_empty()
+            || record
+                .full_prefixes()
+                .any(|prefix| !prefix.bytes().all(|b| b.is_ascii_digit()))
+    }) {
+        return Err(Error::InvalidCallingCode(record.alpha2.clone()));
+    }
+
+    let mut prefix = Vec::new();
+    let mut prefix_code = Vec::new();
+    let mut prefix_country = Vec::new();
+    let mut country = Vec::new();
+    let mut codes = Vec::new();
+
+    for c in data {
+        let mut own = Vec::new();
+        for record in records
+            .iter()
+            .filter(|record| record.alpha2 == c.alpha2_str())
+        {
+            for full in record.full_prefixes() {
+                prefix.push(full);
+                prefix_code.push(record.country_code.as_str());
+                prefix_country.push(config.ident(c));
+            }
+
+            let code = format!("+{}", record.country_code);
+            if !own.contains(&code) {
+                own.push(code);
+            }
+        }
+
+        if !own.is_empty() {
+            country.push(config.ident(c));
+            codes.push(quote::quote!(&[#(#own),*]));
+        }
+    }
+
+    let calling_codes_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "assert_eq!(&[\"+1\"], Alpha2::Canada.calling_codes());",
+        "assert_eq!(&[\"+44\"], Alpha2::Guernsey.calling_codes());",
+        "assert!(Alpha2::BouvetIsland.calling_codes().is_empty());",
+    ]);
+
+    Ok(quote::quote! {
+        /// The prefix of the E.164 numbers in a country, such as a NANP area code.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct CallingPrefix {
+            /// The digits of the prefix, including the calling code, e.g. `1416`.
+            pub prefix: &'static str,
+            /// The digits of the calling code, e.g. `1`.
+            pub country_code: &'static str,
+            /// The country which the prefix identifies.
+            pub country: Alpha2,
+        }
+
+        /// The prefixes of the E.164 numbers in each country, grouped by country in numeric order.
+        ///
+        /// Countries which do not share their calling code have a single prefix, the calling
+        /// code itself. Within a shared calling code, the prefix of the country which is not
+        /// identified by a national prefix, e.g. the United States within `+1`, is the calling
+        /// code itself.
+        pub const CALLING_PREFIXES: &[CallingPrefix] = &[
+            #(
+                CallingPrefix {
+                    prefix: #prefix,
+                    country_code: #prefix_code,
+                    country: Alpha2::#prefix_country,
+                },
+            )*
+        ];
+
+        impl Alpha2 {
+            /// Get the international calling codes of the country, e.g. `+44`.
+            #calling_codes_example
+            pub const fn calling_codes(&self) -> &'static [&'static str] {
+                match self {
+                    #(
+                        Self::#country => #codes,
+                    )*
+                    _ => &[],
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,125 @@
+//! Code generation for the country code top-level domains of each country.
+
+use crate::lukes::Config;
+use iso3166_parsers::{lukes::Country, psl::TopLevelDomain};
+use proc_macro2::TokenStream;
+
+/// The ASCII ccTLDs which IANA delegated for a country under a code other than its alpha-2 code.
+const EXCEPTIONS: &[(&str, &str)] = &[("ac", "SH"), ("uk", "GB")];
+
+/// The ASCII ccTLDs which are delegated but not used in favour of an exception.
+const DORMANT: &[&str] = &["gb"];
+
+/// Find the alpha-2 code of the country a top-level domain belongs to, if it is a ccTLD.
+fn alpha2(tld: &TopLevelDomain) -> Option<String> {
+    if tld.is_idn() {
+        return tld.country.clone();
+    }
+
+    if let Some((_, alpha2)) = EXCEPTIONS.iter().find(|(name, _)| *name == tld.ascii) {
+        return Some((*alpha2).to_owned());
+    }
+
+    (tld.ascii.len() == 2).then(|| tld.ascii.to_ascii_uppercase())
+}
+
+/// Order the ccTLDs of a country, with its own alpha-2 code first, then the exceptions, the
+/// dormant ccTLDs, and finally the internationalized ccTLDs.
+fn rank(country: &Country, tld: &TopLevelDomain) -> u8 {
+    let dormant = DORMANT.contains(&tld.ascii.as_str());
+
+    if !dormant && tld.ascii.eq_ignore_ascii_case(country.alpha2_str()) {
+        0
+    } else if tld.is_idn() {
+        3
+    } else if dormant {
+        2
+    } else {
+        1
+    }
+}
+
+/// Generate `Cctld`, `CCTLDS` and `Alpha2::cctlds()` from the given top-level domains.
+///
+/// Top-level domains which are not ccTLDs of a country in `data`, such as `.eu` and `.su`, are
+/// left out.
+pub(crate) fn generate(config: &Config, data: &[Country], tlds: &[TopLevelDomain]) -> TokenStream {
+    let mut country = Vec::new();
+    let mut cctlds = Vec::new();
+    let mut all = Vec::new();
+
+    for c in data {
+        let mut own = tlds
+            .iter()
+            .filter(|tld| alpha2(tld).as_deref() == Some(c.alpha2_str()))
+            .collect::<Vec<_>>();
+        if own.is_empty() {
+            continue;
+        }
+        own.sort_by_key(|tld| rank(c, tld));
+
+        let ident = config.ident(c);
+        let entries = own
+            .iter()
+            .map(|tld| {
+                let name = &tld.name;
+                let ascii = &tld.ascii;
+                quote::quote! {
+                    Cctld {
+                        name: #name,
+                        ascii: #ascii,
+                        country: Alpha2::#ident,
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        all.extend(entries.iter().cloned());
+        country.push(ident);
+        cctlds.push(quote::quote!(&[#(#entries),*]));
+    }
+
+    let cctlds_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "let cctlds = Alpha2::UnitedKingdom.cctlds();",
+        "assert_eq!(\"uk\", cctlds[0].name);",
+        "assert_eq!(\"gb\", cctlds[1].name);",
+        "",
+        "let cctlds = Alpha2::Russia.cctlds();",
+        "assert_eq!(\"ru\", cctlds[0].name);",
+        "assert_eq!(\"рф\", cctlds[1].name);",
+        "assert_eq!(\"xn--p1ai\", cctlds[1].ascii);",
+    ]);
+
+    quote::quote! {
+        /// A country code top-level domain.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct Cctld {
+            /// The domain without the leading dot, e.g. `uk` or `рф`.
+            pub name: &'static str,
+            /// The ASCII form of the domain, which is the punycode A-label for internationalized
+            /// domains, e.g. `xn--p1ai`.
+            pub ascii: &'static str,
+            /// The country the domain belongs to.
+            pub country: Alpha2,
+        }
+
+        /// Every country code top-level domain, grouped by country in numeric order.
+        pub const CCTLDS: &[Cctld] = &[#(#all),*];
+
+        impl Alpha2 {
+            /// Get the country code top-level domains of the country, with the ccTLD in general
+            /// use first, followed by any internationalized ccTLDs.
+            #cctlds_example
+            pub const fn cctlds(&self) -> &'static [Cctld] {
+                match self {
+                    #(
+                        Self::#country => #cctlds,
+                    )*
+                    _ => &[],
+                }
+            }
+        }
+    }
+}
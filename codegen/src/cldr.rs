@@ -0,0 +1,98 @@
+//! Code generation from CLDR territory display names.
+
+use crate::lukes::Config;
+use iso3166_parsers::{cldr::DisplayNames, lukes::Country};
+use proc_macro2::TokenStream;
+
+/// Wrap an optional string for use in generated code.
+fn option(value: Option<&str>) -> TokenStream {
+    if let Some(value) = value {
+        quote::quote!(Some(#value))
+    } else {
+        quote::quote!(None)
+    }
+}
+
+/// Generate `LocalizedName`, `CLDR_LOCALES` and `Alpha2::localized_name()` from the given locales.
+pub(crate) fn generate(config: &Config, data: &[Country], locales: &[DisplayNames]) -> TokenStream {
+    let mut locale = Vec::new();
+    let mut locale_fn = Vec::new();
+    let mut locale_tables = Vec::new();
+
+    for names in locales {
+        let func = quote::format_ident!("cldr_{}", names.locale.replace('-', "_").to_lowercase());
+
+        let mut ident = Vec::new();
+        let mut name = Vec::new();
+        let mut short = Vec::new();
+        let mut variant = Vec::new();
+
+        for country in data {
+            let Some(display) = names.names.get(country.alpha2_str()) else {
+                continue;
+            };
+
+            ident.push(config.ident(country));
+            name.push(display.name.as_str());
+            short.push(option(display.short.as_deref()));
+            variant.push(option(display.variant.as_deref()));
+        }
+
+        locale_tables.push(quote::quote! {
+            const fn #func(value: &Alpha2) -> Option<LocalizedName> {
+                match value {
+                    #(
+                        Alpha2::#ident => Some(LocalizedName {
+                            name: #name,
+                            short: #short,
+                            variant: #variant,
+                        }),
+                    )*
+                    _ => None,
+                }
+            }
+        });
+        locale.push(names.locale.as_str());
+        locale_fn.push(func);
+    }
+
+    quote::quote! {
+        /// The name of a country in a CLDR locale.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct LocalizedName {
+            /// The standard name, e.g. `Vereinigtes Königreich`.
+            pub name: &'static str,
+            /// The short form, e.g. `VK`, if CLDR has one.
+            pub short: Option<&'static str>,
+            /// The variant form, e.g. `Kongo (Republik)`, if CLDR has one.
+            pub variant: Option<&'static str>,
+        }
+
+        /// The CLDR locales which were included when the crate was generated.
+        pub const CLDR_LOCALES: &[&str] = &[#(#locale),*];
+
+        #(#locale_tables)*
+
+        impl Alpha2 {
+            /// Get the CLDR name of the country in the given BCP 47 locale, if it was included when
+            /// the crate was generated.
+            ///
+            /// Locales which were not included fall back to their parent by removing subtags, so
+            /// `de-AT` uses `de` unless `de-AT` was included.
+            pub fn localized_name(&self, locale: &str) -> Option<LocalizedName> {
+                let mut locale = locale;
+
+                loop {
+                    match locale {
+                        #(
+                            #locale => return #locale_fn(self),
+                        )*
+                        _ => {}
+                    }
+
+                    locale = &locale[..locale.rfind('-')?];
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Code generation for the ISO 4217 currencies used by each country.
+
+use crate::{lukes::Config, names::normalize};
+use iso3166_parsers::{iso_codes::Record as IsoCodesRecord, iso4217::Entry, lukes::Country};
+use proc_macro2::TokenStream;
+use std::collections::BTreeMap;
+
+/// The details of a currency, taken from the list of current currencies where possible.
+struct Currency<'a> {
+    name: &'a str,
+    numeric: u16,
+    minor_units: Option<u8>,
+    fund: bool,
+    current: bool,
+}
+
+/// Index the countries by their normalized names, so the upper-cased names used by the currency
+/// lists, such as `KOREA (THE REPUBLIC OF)`, can be matched to them.
+fn countries_by_name<'a>(
+    data: &'a [Country],
+    iso_codes: &[IsoCodesRecord],
+) -> BTreeMap<String, &'a Country> {
+    let mut retval = BTreeMap::new();
+
+    for country in data {
+        retval.insert(normalize(&country.name), country);
+    }
+
+    for record in iso_codes {
+        let Some(country) = data
+            .iter()
+            .find(|country| country.alpha2_str() == record.alpha_2)
+        else {
+            continue;
+        };
+
+        for name in [
+            Some(&record.name),
+            record.official_name.as_ref(),
+            record.common_name.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            retval.entry(normalize(name)).or_insert(country);
+        }
+    }
+
+    retval
+}
+
+/// Generate `Currency`, `FormerCurrency` and `Alpha2::currencies()` from the current and
+/// withdrawn currency lists.
+///
+/// Entries for entities which are not countries in `data`, such as the European Union, are left
+/// out of the mapping, but their currencies are still generated.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    iso_codes: &[IsoCodesRecord],
+    current: &[Entry],
+    withdrawn: &[Entry],
+) -> TokenStream {
+    let by_name = countries_by_name(data, iso_codes);
+    let country = |entry: &Entry| by_name.get(&normalize(&entry.country)).copied();
+
+    let mut currencies = BTreeMap::<&str, Currency<'_>>::new();
+    for (entry, is_current) in current
+        .iter()
+        .map(|entry| (entry, true))
+        .chain(withdrawn.iter().map(|entry| (entry, false)))
+    {
+        let (Some(code), Some(numeric)) = (entry.code.as_deref(), entry.numeric) else {
+            continue;
+        };
+
+        currencies.entry(code).or_insert(Currency {
+            name: &entry.name,
+            numeric,
+            minor_units: entry.minor_units,
+            fund: entry.fund,
+            current: is_current,
+        });
+    }
+
+    let mut variant = Vec::new();
+    let mut doc = Vec::new();
+    let mut code = Vec::new();
+    let mut name = Vec::new();
+    let mut numeric = Vec::new();
+    let mut minor_units = Vec::new();
+    let mut fund = Vec::new();
+    let mut is_current = Vec::new();
+    let mut users = Vec::new();
+    let mut former_users = Vec::new();
+
+    for (c, currency) in &currencies {
+        let ident = quote::format_ident!("{c}");
+        let countries = |list: &[Entry]| {
+            let mut countries = list
+                .iter()
+                .filter(|entry| entry.code.as_deref() == Some(*c))
+                .filter_map(country)
+                .collect::<Vec<_>>();
+            countries.sort_by_key(|country| country.numeric);
+            countries.dedup_by_key(|country| country.numeric);
+
+            let idents = countries.into_iter().map(|country| config.ident(country));
+            quote::quote!(&[#(Alpha2::#idents),*])
+        };
+
+        users.push(countries(current));
+        former_users.push(countries(withdrawn));
+        doc.push(format!(
+            " {} (`{c}`, {:03})",
+            currency.name, currency.numeric
+        ));
+        code.push(*c);
+        name.push(currency.name);
+        numeric.push(currency.numeric);
+        minor_units.push(if let Some(minor_units) = currency.minor_units {
+            quote::quote!(Some(#minor_units))
+        } else {
+            quote::quote!(None)
+        });
+        fund.push(currency.fund);
+        is_current.push(currency.current);
+        variant.push(ident);
+    }
+
+    let mut country_ident = Vec::new();
+    let mut country_currencies = Vec::new();
+    let mut former_ident = Vec::new();
+    let mut former_currencies = Vec::new();
+
+    for c in data {
+        let entries = |list: &[Entry]| {
+            list.iter()
+                .filter(|entry| {
+                    entry.code.is_some()
+                        && country(entry).is_some_and(|other| other.numeric == c.numeric)
+                })
+                .map(|entry| {
+                    (
+                        quote::format_ident!("{}", entry.code.as_deref().unwrap_or_default()),
+                        entry.withdrawn.as_deref().unwrap_or_default().to_owned(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let current = entries(current);
+        if !current.is_empty() {
+            let codes = current.into_iter().map(|(code, _)| code);
+            country_ident.push(config.ident(c));
+            country_currencies.push(quote::quote!(&[#(Currency::#codes),*]));
+        }
+
+        let withdrawn = entries(withdrawn);
+        if !withdrawn.is_empty() {
+            let (codes, dates) = withdrawn.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+            former_ident.push(config.ident(c));
+            former_currencies.push(quote::quote! {
+                &[
+                    #(
+                        FormerCurrency {
+                            currency: Currency::#codes,
+                            withdrawn: #dates,
+                        },
+                    )*
+                ]
+            });
+        }
+    }
+
+    let countries_example = config.example(&[
+        "use iso3166_static::{Alpha2, Currency};",
+        "",
+        "assert_eq!(&[Alpha2::Liechtenstein, Alpha2::Switzerland], Currency::CHF.countries());",
+        "assert!(Currency::EUR.countries().contains(&Alpha2::Germany));",
+        "assert!(Currency::DEM.countries().is_empty());",
+        "assert_eq!(&[Alpha2::Germany], Currency::DEM.former_countries());",
+    ]);
+    let currencies_example = config.example(&[
+        "use iso3166_static::{Alpha2, Currency};",
+        "",
+        "assert_eq!(&[Currency::EUR], Alpha2::Germany.currencies());",
+        "assert_eq!(&[Currency::BTN, Currency::INR], Alpha2::Bhutan.currencies());",
+        "assert!(Alpha2::Antarctica.currencies().is_empty());",
+    ]);
+    let former_example = config.example(&[
+        "use iso3166_static::{Alpha2, Currency};",
+        "",
+        "let former = Alpha2::Germany.former_currencies();",
+        "assert_eq!(Currency::DEM, former[0].currency);",
+        "assert_eq!(\"2002-03\", former[0].withdrawn);",
+    ]);
+
+    quote::quote! {
+        /// An ISO 4217 currency, named by its alphabetic code.
+        #[allow(clippy::upper_case_acronyms)]
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[non_exhaustive]
+        pub enum Currency {
+            #(
+                #[doc = #doc]
+                #variant,
+            )*
+        }
+
+        /// A currency which a country no longer uses.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct FormerCurrency {
+            /// The withdrawn currency.
+            pub currency: Currency,
+            /// When the currency was withdrawn from the country, in `YYYY-MM` form, or as a range
+            /// of months in `YYYY-MM to YYYY-MM` form.
+            pub withdrawn: &'static str,
+        }
+
+        impl Currency {
+            /// Every currency, in order of their alphabetic codes.
+            pub const ALL: &[Currency] = &[#(Currency::#variant),*];
+
+            /// Get the currency with the given alphabetic code, e.g. `EUR`.
+            pub fn from_code(code: &str) -> Option<Self> {
+                match code {
+                    #(
+                        #code => Some(Self::#variant),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Get the alphabetic code of the currency, e.g. `EUR`.
+            pub const fn code(&self) -> &'static str {
+                match self {
+                    #(
+                        Self::#variant => #code,
+                    )*
+                }
+            }
+
+            /// Get the numeric code of the currency, e.g. `978`.
+            ///
+            /// Numeric codes may be reused after a currency is withdrawn, so they are not unique.
+            pub const fn numeric(&self) -> u16 {
+                match self {
+                    #(
+                        Self::#variant => #numeric,
+                    )*
+                }
+            }
+
+            /// Get the English name of the currency, e.g. `Euro`.
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    #(
+                        Self::#variant => #name,
+                    )*
+                }
+            }
+
+            /// Get the number of digits after the decimal separator, if it applies to the
+            /// currency and it is still in use.
+            pub const fn minor_units(&self) -> Option<u8> {
+                match self {
+                    #(
+                        Self::#variant => #minor_units,
+                    )*
+                }
+            }
+
+            /// Determine whether the currency is a fund rather than a currency in circulation,
+            /// e.g. the Mexican Unidad de Inversion.
+            pub const fn is_fund(&self) -> bool {
+                match self {
+                    #(
+                        Self::#variant => #fund,
+                    )*
+                }
+            }
+
+            /// Determine whether the currency is in the list of current currencies.
+            pub const fn is_current(&self) -> bool {
+                match self {
+                    #(
+                        Self::#variant => #is_current,
+                    )*
+                }
+            }
+
+            /// Get the countries which currently use the currency, in numeric order.
+            #countries_example
+            pub const fn countries(&self) -> &'static [Alpha2] {
+                match self {
+                    #(
+                        Self::#variant => #users,
+                    )*
+                }
+            }
+
+            /// Get the countries from which the currency has been withdrawn, in numeric order.
+            pub const fn former_countries(&self) -> &'static [Alpha2] {
+                match self {
+                    #(
+                        Self::#variant => #former_users,
+                    )*
+                }
+            }
+        }
+
+        impl Alpha2 {
+            /// Get the currencies currently used in the country, in the order of the currency
+            /// list.
+            #currencies_example
+            pub const fn currencies(&self) -> &'static [Currency] {
+                match self {
+                    #(
+                        Self::#country_ident => #country_currencies,
+                    )*
+                    _ => &[],
+                }
+            }
+
+            /// Get the currencies which have been withdrawn from the country, in the order of the
+            /// currency list.
+            #former_example
+            pub const fn former_currencies(&self) -> &'static [FormerCurrency] {
+                match self {
+                    #(
+                        Self::#former_ident => #former_currencies,
+                    )*
+                    _ => &[],
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Code generation for the capital, centroid and bounding box of each country.
+
+use crate::{Error, lukes::Config};
+use iso3166_parsers::{geo::Record, lukes::Country};
+use proc_macro2::{Literal, TokenStream};
+
+/// Convert decimal degrees to a literal number of millionths of a degree.
+fn microdegrees(value: f64) -> Literal {
+    #[allow(clippy::cast_possible_truncation)]
+    Literal::i32_unsuffixed((value * 1e6).round() as i32)
+}
+
+/// Generate the tokens of a `Coordinates` value.
+fn coordinates(latitude: f64, longitude: f64) -> TokenStream {
+    let latitude = microdegrees(latitude);
+    let longitude = microdegrees(longitude);
+
+    quote::quote! {
+        Coordinates {
+            latitude_e6: #latitude,
+            longitude_e6: #longitude,
+        }
+    }
+}
+
+/// Generate `Coordinates`, `BoundingBox`, `Capital`, `Alpha2::capital()`, `Alpha2::centroid()`
+/// and `Alpha2::bounding_box()` from the given records.
+///
+/// Records for countries which are not in `data` are left out.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    records: &[Record],
+) -> Result<TokenStream, Error> {
+    if let Some(record) = records.iter().find(|record| !record.is_valid()) {
+        return Err(Error::InvalidGeography(record.alpha2.clone()));
+    }
+
+    let mut country = Vec::new();
+    let mut centroid = Vec::new();
+    let mut bounding_box = Vec::new();
+    let mut capital_country = Vec::new();
+    let mut capital = Vec::new();
+
+    for c in data {
+        let Some(record) = records
+            .iter()
+            .find(|record| record.alpha2 == c.alpha2_str())
+        else {
+            continue;
+        };
+
+        country.push(config.ident(c));
+        centroid.push(coordinates(record.latitude, record.longitude));
+
+        let south_west = coordinates(record.south, record.west);
+        let north_east = coordinates(record.north, record.east);
+        bounding_box.push(quote::quote! {
+            BoundingBox {
+                south_west: #south_west,
+                north_east: #north_east,
+            }
+        });
+
+        if let (Some(name), Some(latitude), Some(longitude)) = (
+            &record.capital,
+            record.capital_latitude,
+            record.capital_longitude,
+        ) {
+            let position = coordinates(latitude, longitude);
+            capital_country.push(config.ident(c));
+            capital.push(quote::quote! {
+                Capital {
+                    name: #name,
+                    coordinates: #position,
+                }
+            });
+        }
+    }
+
+    let coordinates_example = config.example(&[
+        "use iso3166_static::Coordinates;",
+        "",
+        "let berlin = Coordinates::from_degrees(52.52, 13.405);",
+        "assert_eq!(13_405_000, berlin.longitude_e6);",
+        "assert_eq!(52.52, berlin.latitude());",
+    ]);
+    let bounding_box_example = config.example(&[
+        "use iso3166_static::{Alpha2, Coordinates};",
+        "",
+        "let fiji = Alpha2::Fiji.bounding_box().expect(\"bounding box\");",
+        "assert!(fiji.crosses_antimeridian());",
+        "assert!(fiji.contains(Coordinates::from_degrees(-16.8, -179.9)));",
+        "assert!(!fiji.contains(Coordinates::from_degrees(-16.8, 0.0)));",
+    ]);
+    let capital_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "let capital = Alpha2::Germany.capital().expect(\"capital\");",
+        "assert_eq!(\"Berlin\", capital.name);",
+        "assert_eq!(52.52, capital.coordinates.latitude());",
+        "assert_eq!(None, Alpha2::Antarctica.capital());",
+    ]);
+    let centroid_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "let centroid = Alpha2::Australia.centroid().expect(\"centroid\");",
+        "assert!(centroid.latitude() < 0.0);",
+    ]);
+
+    Ok(quote::quote! {
+        /// A point on the Earth's surface, stored in millionths of a degree.
+        #coordinates_example
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Coordinates {
+            /// The latitude, in millionths of a degree north of the equator.
+            pub latitude_e6: i32,
+            /// The longitude, in millionths of a degree east of the prime meridian.
+            pub longitude_e6: i32,
+        }
+
+        impl Coordinates {
+            /// Create coordinates from a latitude and longitude in decimal degrees, rounded to
+            /// the nearest millionth of a degree.
+            pub const fn from_degrees(latitude: f64, longitude: f64) -> Self {
+                Self {
+                    latitude_e6: Self::microdegrees(latitude),
+                    longitude_e6: Self::microdegrees(longitude),
+                }
+            }
+
+            /// Get the latitude, in decimal degrees north of the equator.
+            pub const fn latitude(&self) -> f64 {
+                self.latitude_e6 as f64 / 1e6
+            }
+
+            /// Get the longitude, in decimal degrees east of the prime meridian.
+            pub const fn longitude(&self) -> f64 {
+                self.longitude_e6 as f64 / 1e6
+            }
+
+            const fn microdegrees(value: f64) -> i32 {
+                let value = value * 1e6;
+                if value < 0.0 {
+                    (value - 0.5) as i32
+                } else {
+                    (value + 0.5) as i32
+                }
+            }
+        }
+
+        /// The smallest latitude and longitude range which contains a country.
+        ///
+        /// The western edge is east of the eastern edge when the box crosses the antimeridian, as
+        /// it does for Fiji and Russia.
+        #bounding_box_example
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct BoundingBox {
+            /// The southern and western edges of the box.
+            pub south_west: Coordinates,
+            /// The northern and eastern edges of the box.
+            pub north_east: Coordinates,
+        }
+
+        impl BoundingBox {
+            /// Get the southern edge of the box, in decimal degrees.
+            pub const fn south(&self) -> f64 {
+                self.south_west.latitude()
+            }
+
+            /// Get the western edge of the box, in decimal degrees.
+            pub const fn west(&self) -> f64 {
+                self.south_west.longitude()
+            }
+
+            /// Get the northern edge of the box, in decimal degrees.
+            pub const fn north(&self) -> f64 {
+                self.north_east.latitude()
+            }
+
+            /// Get the eastern edge of the box, in decimal degrees.
+            pub const fn east(&self) -> f64 {
+                self.north_east.longitude()
+            }
+
+            /// Whether the box crosses the antimeridian, at 180 degrees of longitude.
+            pub const fn crosses_antimeridian(&self) -> bool {
+                self.south_west.longitude_e6 > self.north_east.longitude_e6
+            }
+
+            /// Whether the given point is within the box, including its edges.
+            pub const fn contains(&self, point: Coordinates) -> bool {
+                let latitude = point.latitude_e6 >= self.south_west.latitude_e6
+                    && point.latitude_e6 <= self.north_east.latitude_e6;
+                let longitude = if self.crosses_antimeridian() {
+                    point.longitude_e6 >= self.south_west.longitude_e6
+                        || point.longitude_e6 <= self.north_east.longitude_e6
+                } else {
+                    point.longitude_e6 >= self.south_west.longitude_e6
+                        && point.longitude_e6 <= self.north_east.longitude_e6
+                };
+
+                latitude && longitude
+            }
+        }
+
+        /// The capital city of a country.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Capital {
+            /// The English name of the city, e.g. `Berlin`.
+            pub name: &'static str,
+            /// The location of the city.
+            pub coordinates: Coordinates,
+        }
+
+        impl Alpha2 {
+            /// Get the capital city of the country, if it has one.
+            ///
+            /// Uninhabited territories, and territories governed from elsewhere, have no capital.
+            #capital_example
+            pub const fn capital(&self) -> Option<Capital> {
+                match self {
+                    #(
+                        Self::#capital_country => Some(#capital),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Get a representative centre point of the country.
+            ///
+            /// This is an approximate geographic centre, meant for placing a marker on a map or
+            /// measuring rough distances, rather than an exact centroid of the country's area.
+            #centroid_example
+            pub const fn centroid(&self) -> Option<Coordinates> {
+                match self {
+                    #(
+                        Self::#country => Some(#centroid),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Get the bounding box of the country, leaving out territories which have codes of
+            /// their own.
+            pub const fn bounding_box(&self) -> Option<BoundingBox> {
+                match self {
+                    #(
+                        Self::#country => Some(#bounding_box),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
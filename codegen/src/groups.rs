@@ -0,0 +1,302 @@
+//! Code generation for the membership of countries in international groupings.
+
+use crate::{Error, lukes::Config};
+use iso3166_parsers::{
+    groups::{Group, parse_date},
+    lukes::Country,
+};
+use proc_macro2::{Literal, TokenStream};
+
+/// Convert a `YYYY-MM-DD` date into a `Date` expression, if it is valid.
+fn date(value: &str) -> Option<TokenStream> {
+    let (year, month, day) = parse_date(value)?;
+    let year = Literal::u16_unsuffixed(year);
+    let month = Literal::u8_unsuffixed(month);
+    let day = Literal::u8_unsuffixed(day);
+
+    Some(quote::quote!(Date { year: #year, month: #month, day: #day }))
+}
+
+/// Generate `Date`, `Group`, `Membership` and `Alpha2::is_member_of()` from the given groups.
+///
+/// Memberships of countries which are not in `data` are left out. Memberships are current when
+/// they have begun by `as_of` and not ended, or when they have not ended if `as_of` is `None`.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    groups: &[Group],
+    as_of: Option<&str>,
+) -> Result<TokenStream, Error> {
+    let is_current = if let Some(as_of) = as_of {
+        let as_of = date(as_of).ok_or_else(|| Error::InvalidDate(as_of.to_owned()))?;
+        quote::quote!(self.is_active_on(#as_of))
+    } else {
+        quote::quote!(self.left.is_none())
+    };
+
+    let mut variant = Vec::new();
+    let mut doc = Vec::new();
+    let mut code = Vec::new();
+    let mut name = Vec::new();
+    let mut history = Vec::new();
+
+    for group in groups {
+        let ident = syn::parse_str::<syn::Ident>(&group.variant)
+            .map_err(|_| Error::InvalidGroup(group.code.clone()))?;
+
+        let mut country = Vec::new();
+        let mut joined = Vec::new();
+        let mut left = Vec::new();
+
+        for member in &group.members {
+            let invalid = || Error::InvalidGroup(group.code.clone());
+            let joined_date = date(&member.joined).ok_or_else(invalid)?;
+            let left_date = if let Some(left) = member.left.as_deref() {
+                let left = date(left).ok_or_else(invalid)?;
+                quote::quote!(Some(#left))
+            } else {
+                quote::quote!(None)
+            };
+
+            let Some(c) = data
+                .iter()
+                .find(|country| country.alpha2_str() == member.alpha2)
+            else {
+                continue;
+            };
+
+            country.push(config.ident(c));
+            joined.push(joined_date);
+            left.push(left_date);
+        }
+
+        variant.push(ident);
+        doc.push(format!(" {} (`{}`)", group.name, group.code));
+        code.push(group.code.as_str());
+        name.push(group.name.as_str());
+        history.push(quote::quote! {
+            &[
+                #(
+                    Membership {
+                        country: Alpha2::#country,
+                        joined: #joined,
+                        left: #left,
+                    },
+                )*
+            ]
+        });
+    }
+
+    let members_example = config.example(&[
+        "use iso3166_static::{Alpha2, Group};",
+        "",
+        "assert_eq!(27, Group::EuropeanUnion.members().count());",
+        "assert!(Group::EuropeanUnion.members().all(|c| c.is_member_of(Group::EuropeanEconomicArea)));",
+    ]);
+    let member_of_example = config.example(&[
+        "use iso3166_static::{Alpha2, Date, Group};",
+        "",
+        "assert!(!Alpha2::UnitedKingdom.is_member_of(Group::EuropeanUnion));",
+        "let brexit = Date::new(2020, 1, 31).expect(\"valid date\");",
+        "assert!(Alpha2::UnitedKingdom.was_member_of(Group::EuropeanUnion, brexit));",
+        "",
+        "let after = Date::parse(\"2020-02-01\").expect(\"valid date\");",
+        "assert!(!Alpha2::UnitedKingdom.was_member_of(Group::EuropeanUnion, after));",
+    ]);
+    let date_example = config.example(&[
+        "use iso3166_static::Date;",
+        "",
+        "let date = Date::parse(\"2024-02-29\").expect(\"valid date\");",
+        "assert_eq!(Date::new(2024, 2, 29), Some(date));",
+        "assert_eq!(\"2024-02-29\", date.to_string());",
+        "assert_eq!(None, Date::parse(\"2023-02-29\"));",
+        "assert_eq!(None, Date::parse(\"2020-2-1\"));",
+        "assert_eq!(None, Date::parse(\"2020/01/31\"));",
+    ]);
+
+    Ok(quote::quote! {
+        /// An international grouping of countries.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[non_exhaustive]
+        pub enum Group {
+            #(
+                #[doc = #doc]
+                #variant,
+            )*
+        }
+
+        /// A day in the Gregorian calendar, used for the dates of [`Membership`]s.
+        ///
+        /// Dates are ordered chronologically, and can only be created for days which exist.
+        #date_example
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Date {
+            year: u16,
+            month: u8,
+            day: u8,
+        }
+
+        impl Date {
+            /// Create a date from its year, month and day, or `None` if the day does not exist.
+            pub const fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+                let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+                let days = match month {
+                    2 if leap => 29,
+                    2 => 28,
+                    4 | 6 | 9 | 11 => 30,
+                    1..=12 => 31,
+                    _ => return None,
+                };
+
+                if day >= 1 && day <= days {
+                    Some(Self { year, month, day })
+                } else {
+                    None
+                }
+            }
+
+            /// Parse a date in `YYYY-MM-DD` form, or `None` if it is in any other form or the
+            /// day does not exist.
+            pub const fn parse(value: &str) -> Option<Self> {
+                let bytes = value.as_bytes();
+                if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+                    return None;
+                }
+
+                let mut digits = [0u16; 10];
+                let mut i = 0;
+                while i < bytes.len() {
+                    if i != 4 && i != 7 {
+                        if !bytes[i].is_ascii_digit() {
+                            return None;
+                        }
+                        digits[i] = (bytes[i] - b'0') as u16;
+                    }
+                    i += 1;
+                }
+
+                let year = digits[0] * 1000 + digits[1] * 100 + digits[2] * 10 + digits[3];
+                let month = digits[5] * 10 + digits[6];
+                let day = digits[8] * 10 + digits[9];
+                Self::new(year, month as u8, day as u8)
+            }
+
+            /// Get the year.
+            pub const fn year(&self) -> u16 {
+                self.year
+            }
+
+            /// Get the month, from 1 to 12.
+            pub const fn month(&self) -> u8 {
+                self.month
+            }
+
+            /// Get the day of the month, from 1.
+            pub const fn day(&self) -> u8 {
+                self.day
+            }
+        }
+
+        impl core::fmt::Display for Date {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+            }
+        }
+
+        /// A period of membership of a country in a [`Group`].
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct Membership {
+            /// The member country.
+            pub country: Alpha2,
+            /// The date the country joined the group.
+            pub joined: Date,
+            /// The last day the country was a member, if it has left.
+            pub left: Option<Date>,
+        }
+
+        impl Membership {
+            /// Determine whether the membership was active on the given date.
+            pub fn is_active_on(&self, date: Date) -> bool {
+                self.joined <= date && self.left.is_none_or(|left| date <= left)
+            }
+
+            /// Determine whether the membership is current, as of the date of the data.
+            ///
+            /// A membership which is scheduled to begin after that date, such as an agreed
+            /// accession, is not yet current.
+            pub fn is_current(&self) -> bool {
+                #is_current
+            }
+        }
+
+        impl Group {
+            /// Every group, in the order of the source data.
+            pub const ALL: &[Group] = &[#(Group::#variant),*];
+
+            /// Get the short code of the group, e.g. `EU`.
+            pub const fn code(&self) -> &'static str {
+                match self {
+                    #(
+                        Self::#variant => #code,
+                    )*
+                }
+            }
+
+            /// Get the English name of the group, e.g. `European Union`.
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    #(
+                        Self::#variant => #name,
+                    )*
+                }
+            }
+
+            /// Get every period of membership of the group, ordered by the date they began.
+            pub const fn history(&self) -> &'static [Membership] {
+                match self {
+                    #(
+                        Self::#variant => #history,
+                    )*
+                }
+            }
+
+            /// Get the current members of the group, as of the date of the data.
+            ///
+            /// See [`Membership::is_current()`], and [`Group::members_on()`] for the members on
+            /// another date.
+            #members_example
+            pub fn members(&self) -> impl Iterator<Item = Alpha2> {
+                self.history()
+                    .iter()
+                    .filter(|membership| membership.is_current())
+                    .map(|membership| membership.country)
+            }
+
+            /// Get the members of the group on the given date.
+            pub fn members_on(&self, date: Date) -> impl Iterator<Item = Alpha2> {
+                self.history()
+                    .iter()
+                    .filter(move |membership| membership.is_active_on(date))
+                    .map(|membership| membership.country)
+            }
+        }
+
+        impl Alpha2 {
+            /// Determine whether the country is currently a member of the given group, as of the
+            /// date of the data.
+            ///
+            /// See [`Membership::is_current()`], and [`Alpha2::was_member_of()`] for membership on
+            /// another date.
+            #member_of_example
+            pub fn is_member_of(&self, group: Group) -> bool {
+                group.members().any(|member| member == *self)
+            }
+
+            /// Determine whether the country was a member of the given group on the given date.
+            pub fn was_member_of(&self, group: Group, date: Date) -> bool {
+                group.members_on(date).any(|member| member == *self)
+            }
+        }
+    })
+}
@@ -0,0 +1,183 @@
+//! Code generation for the IBAN format of each country.
+
+use crate::{Error, lukes::Config};
+use iso3166_parsers::{
+    iban::{Characters, Entry},
+    lukes::Country,
+};
+use proc_macro2::{Literal, TokenStream};
+use std::ops::Range;
+
+/// The longest IBAN allowed by ISO 13616.
+const MAX_LENGTH: usize = 34;
+
+/// Check that an entry's lengths, positions and example agree with each other.
+fn is_consistent(entry: &Entry) -> bool {
+    let length = entry
+        .bban
+        .iter()
+        .map(|segment| segment.length)
+        .sum::<usize>();
+    let within = |range: &Option<Range<usize>>| range.as_ref().is_none_or(|r| r.end <= length);
+
+    length == entry.bban_length
+        && entry.iban_length == length + 4
+        && entry.iban_length <= MAX_LENGTH
+        && within(&entry.bank)
+        && within(&entry.branch)
+        && entry.is_valid(&entry.example)
+}
+
+/// Convert an optional range to a `(start, end)` tuple, which fits in `u8`s once the entry is
+/// consistent.
+fn offsets(range: Option<&Range<usize>>) -> TokenStream {
+    range.map_or_else(
+        || quote::quote!(None),
+        |range| {
+            let start = Literal::usize_unsuffixed(range.start);
+            let end = Literal::usize_unsuffixed(range.end);
+            quote::quote!(Some((#start, #end)))
+        },
+    )
+}
+
+/// Generate `BbanCharacters`, `BbanSegment`, `IbanFormat`, `IBAN_FORMATS` and
+/// `Alpha2::iban_format()` from the given registry entries.
+///
+/// Entries for countries which are not in `data` are left out, and territories which use another
+/// country's IBANs get its format.
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    entries: &[Entry],
+) -> Result<TokenStream, Error> {
+    if let Some(entry) = entries.iter().find(|entry| !is_consistent(entry)) {
+        return Err(Error::InvalidIban(entry.alpha2.clone()));
+    }
+
+    let mut format = Vec::new();
+    let mut country = Vec::new();
+    let mut index = Vec::new();
+
+    for c in data {
+        let Some(entry) = entries.iter().find(|entry| entry.alpha2 == c.alpha2_str()) else {
+            continue;
+        };
+
+        let ident = config.ident(c);
+        let length = Literal::usize_unsuffixed(entry.iban_length);
+        let segments = entry.bban.iter().map(|segment| {
+            let length = Literal::usize_unsuffixed(segment.length);
+            let characters = match segment.characters {
+                Characters::Digits => quote::quote!(Digits),
+                Characters::Letters => quote::quote!(Letters),
+                Characters::Alphanumeric => quote::quote!(Alphanumeric),
+            };
+            quote::quote! {
+                BbanSegment { length: #length, characters: BbanCharacters::#characters }
+            }
+        });
+        let bank = offsets(entry.bank.as_ref());
+        let branch = offsets(entry.branch.as_ref());
+        let sepa = entry.sepa;
+        let example = &entry.example;
+
+        format.push(quote::quote! {
+            IbanFormat {
+                country: Alpha2::#ident,
+                length: #length,
+                bban: &[#(#segments),*],
+                bank: #bank,
+                branch: #branch,
+                sepa: #sepa,
+                example: #example,
+            }
+        });
+
+        let position = format.len() - 1;
+        country.push(ident);
+        index.push(Literal::usize_unsuffixed(position));
+
+        for territory in data.iter().filter(|territory| {
+            entry
+                .territories
+                .iter()
+                .any(|t| t == territory.alpha2_str())
+                && !entries.iter().any(|e| e.alpha2 == territory.alpha2_str())
+        }) {
+            country.push(config.ident(territory));
+            index.push(Literal::usize_unsuffixed(position));
+        }
+    }
+
+    let iban_format_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "let germany = Alpha2::Germany.iban_format().expect(\"IBAN format\");",
+        "assert_eq!(22, germany.length);",
+        "assert_eq!(Some((0, 8)), germany.bank);",
+        "assert_eq!(",
+        "    Some(Alpha2::UnitedKingdom),",
+        "    Alpha2::Guernsey.iban_format().map(|format| format.country)",
+        ");",
+        "assert_eq!(None, Alpha2::UnitedStatesOfAmerica.iban_format());",
+    ]);
+
+    Ok(quote::quote! {
+        /// The kind of characters in a segment of a BBAN.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub enum BbanCharacters {
+            /// Digits, `0` to `9`.
+            Digits,
+            /// Upper case letters, `A` to `Z`.
+            Letters,
+            /// Upper case letters and digits.
+            Alphanumeric,
+        }
+
+        /// A fixed-length segment of a BBAN, e.g. four digits.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct BbanSegment {
+            /// The number of characters in the segment.
+            pub length: u8,
+            /// The kind of characters in the segment.
+            pub characters: BbanCharacters,
+        }
+
+        /// The IBAN format of a country, from the SWIFT IBAN Registry.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct IbanFormat {
+            /// The country whose alpha-2 code starts the IBANs.
+            pub country: Alpha2,
+            /// The length of the IBANs, including the country code and check digits.
+            pub length: u8,
+            /// The segments of the BBAN, the part of the IBAN after the check digits.
+            pub bban: &'static [BbanSegment],
+            /// The start and end offsets of the bank identifier within the BBAN, if it has one.
+            pub bank: Option<(u8, u8)>,
+            /// The start and end offsets of the branch identifier within the BBAN, if it has one.
+            pub branch: Option<(u8, u8)>,
+            /// Whether the country is in the Single Euro Payments Area.
+            pub sepa: bool,
+            /// An example IBAN in electronic format.
+            pub example: &'static str,
+        }
+
+        /// The IBAN format of every country in the registry, in numeric order.
+        pub const IBAN_FORMATS: &[IbanFormat] = &[#(#format),*];
+
+        impl Alpha2 {
+            /// Get the format of the IBANs used in the country, which for territories such as
+            /// Guernsey is that of the country whose IBANs they use.
+            #iban_format_example
+            pub const fn iban_format(&self) -> Option<&'static IbanFormat> {
+                match self {
+                    #(
+                        Self::#country => Some(&IBAN_FORMATS[#index]),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,138 @@
+//! Code generation for the languages spoken in each country.
+
+use crate::lukes::Config;
+use iso3166_parsers::{
+    cldr::{OfficialStatus, TerritoryInfo},
+    lukes::Country,
+};
+use proc_macro2::{Literal, TokenStream};
+
+/// Convert a CLDR official status to the generated variant.
+fn status(value: Option<OfficialStatus>) -> TokenStream {
+    match value {
+        None => quote::quote!(None),
+        Some(OfficialStatus::Official) => quote::quote!(Some(OfficialStatus::Official)),
+        Some(OfficialStatus::DeFactoOfficial) => {
+            quote::quote!(Some(OfficialStatus::DeFactoOfficial))
+        }
+        Some(OfficialStatus::OfficialRegional) => {
+            quote::quote!(Some(OfficialStatus::OfficialRegional))
+        }
+        Some(OfficialStatus::OfficialMinority) => {
+            quote::quote!(Some(OfficialStatus::OfficialMinority))
+        }
+    }
+}
+
+/// Generate `OfficialStatus`, `CountryLanguage` and `Alpha2::languages()` from the given
+/// territory information.
+///
+/// Territories which are not in `data` are left out.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn generate(config: &Config, data: &[Country], info: &[TerritoryInfo]) -> TokenStream {
+    let mut country = Vec::new();
+    let mut languages = Vec::new();
+
+    for c in data {
+        let Some(territory) = info
+            .iter()
+            .find(|territory| territory.territory == c.alpha2_str())
+        else {
+            continue;
+        };
+        if territory.languages.is_empty() {
+            continue;
+        }
+
+        let mut own = territory.languages.iter().collect::<Vec<_>>();
+        own.sort_by(|a, b| {
+            b.population_percent
+                .total_cmp(&a.population_percent)
+                .then_with(|| a.language.cmp(&b.language))
+        });
+
+        let entries = own.iter().map(|language| {
+            // CLDR separates the script of a language with an underscore, e.g. `zh_Hant`.
+            let code = language.language.replace('_', "-");
+            let locale = format!("{code}-{}", c.alpha2_str());
+            #[allow(clippy::cast_possible_truncation)]
+            let percent = Literal::f32_suffixed(language.population_percent as f32);
+            let official_status = status(language.official_status);
+
+            quote::quote! {
+                CountryLanguage {
+                    code: #code,
+                    locale: #locale,
+                    population_percent: #percent,
+                    official_status: #official_status,
+                }
+            }
+        });
+
+        country.push(config.ident(c));
+        languages.push(quote::quote!(&[#(#entries),*]));
+    }
+
+    let languages_example = config.example(&[
+        "use iso3166_static::{Alpha2, OfficialStatus};",
+        "",
+        "let languages = Alpha2::Canada.languages();",
+        "assert_eq!(\"en\", languages[0].code);",
+        "assert_eq!(\"fr-CA\", languages[1].locale);",
+        "assert_eq!(Some(OfficialStatus::Official), languages[1].official_status);",
+    ]);
+
+    quote::quote! {
+        /// The official status of a language in a country.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[non_exhaustive]
+        pub enum OfficialStatus {
+            /// The language is official throughout the country.
+            Official,
+            /// The language is used as if it were official, without being designated as such.
+            DeFactoOfficial,
+            /// The language is official in a region of the country.
+            OfficialRegional,
+            /// The language is official for a minority of the country's population.
+            OfficialMinority,
+        }
+
+        /// A language spoken in a country.
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        pub struct CountryLanguage {
+            /// The ISO 639 code of the language, with its script when it is written in several,
+            /// e.g. `fr` or `zh-Hant`.
+            pub code: &'static str,
+            /// The BCP 47 locale tag of the language in the country, e.g. `fr-CA`.
+            pub locale: &'static str,
+            /// The percentage of the country's population which speaks the language.
+            pub population_percent: f32,
+            /// The official status of the language in the country, if it has one.
+            pub official_status: Option<OfficialStatus>,
+        }
+
+        impl CountryLanguage {
+            /// Whether the language is official, or de facto official, throughout the country.
+            pub const fn is_official(&self) -> bool {
+                matches!(
+                    self.official_status,
+                    Some(OfficialStatus::Official | OfficialStatus::DeFactoOfficial)
+                )
+            }
+        }
+
+        impl Alpha2 {
+            /// Get the languages spoken in the country, ordered by the share of the population
+            /// which speaks them.
+            #languages_example
+            pub const fn languages(&self) -> &'static [CountryLanguage] {
+                match self {
+                    #(
+                        Self::#country => #languages,
+                    )*
+                    _ => &[],
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1903 @@
+//! ISO 3166 Code Generation
+
+#![doc = include_str!("../README.md")]
+
+mod boundaries;
+mod calling_codes;
+mod cctlds;
+mod cldr;
+mod currencies;
+mod geography;
+mod groups;
+mod iban;
+mod languages;
+mod lukes;
+mod m49;
+mod names;
+mod neighbours;
+mod sovereignty;
+mod time_zones;
+
+use iso3166_parsers::{
+    boundaries::{Boundary, ReadError as BoundariesReadError, read as read_boundaries},
+    cldr::{
+        DisplayNames, ReadError as CldrReadError, TerritoryInfo, read_territories,
+        read_territory_info,
+    },
+    e164::{
+        MetadataError as PhoneNumberMetadataError, ReadError as CallingCodesReadError,
+        Record as CallingCodesRecord, read as read_e164, read_metadata as read_phone_numbers,
+    },
+    geo::{ReadError as GeographyReadError, Record as GeographyRecord, read as read_geo},
+    groups::{Group, ReadError as GroupsReadError, read as read_groups},
+    iban::{Entry as IbanEntry, ReadError as IbanReadError, read as read_iban},
+    iso_codes::{ReadError as IsoCodesReadError, Record as IsoCodesRecord, read_3166_1},
+    iso4217::{Entry as CurrencyEntry, ReadError as CurrencyReadError, read as read_currencies},
+    lukes::{Country, Error as RecordError, Format, ReadError, SNAPSHOT, read_format, validate},
+    m49::{Language, ReadError as M49ReadError, Record as M49Record, merge, read as read_m49},
+    neighbours::{
+        ReadError as NeighboursReadError, Record as NeighboursRecord, merge_land,
+        read as read_neighbours, read_country_info,
+    },
+    psl::{ReadError as PslReadError, TopLevelDomain, read_top_level_domains},
+    sovereignty::{
+        ReadError as SovereigntyReadError, Record as SovereigntyRecord, read as read_sovereignty,
+    },
+    tzdata::{ReadError as TimeZonesReadError, Zone, read_zones},
+};
+use proc_macro2::TokenStream;
+use std::{
+    error::Error as StdError,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The upstream location of Luke Duncalfe's dataset, used when no data source is given.
+pub const DEFAULT_DATA_SOURCE: &str =
+    "https://github.com/lukes/ISO-3166-Countries-with-Regional-Codes";
+
+/// The data date used when none is given, since it cannot be recovered from the data itself.
+pub const UNKNOWN_DATA_DATE: &str = "unknown";
+
+/// The tolerance country boundaries are simplified with when no other is given, in degrees.
+pub const DEFAULT_BOUNDARY_TOLERANCE: f64 = 0.001;
+
+/// An enumeration of errors which can occur while generating code.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// No data source was configured.
+    MissingSource,
+    /// The data date is not in `YYYY-MM-DD` form.
+    InvalidDate(String),
+    /// A requested derive is not a valid path.
+    InvalidDerive(String),
+    /// A record in the data is invalid.
+    InvalidRecord(RecordError),
+    /// The data format lacks codes needed to generate every enumeration.
+    IncompleteFormat(Format),
+    /// The generated code could not be parsed as a Rust source file.
+    InvalidOutput(syn::Error),
+    /// The data could not be read or the output could not be written.
+    Io(io::Error),
+    /// The data could not be parsed in its format.
+    Read(ReadError),
+    /// An M49 table could not be parsed.
+    M49(M49ReadError),
+    /// A CLDR file could not be parsed.
+    Cldr(CldrReadError),
+    /// An `iso-codes` file could not be parsed.
+    IsoCodes(IsoCodesReadError),
+    /// A sovereignty table could not be parsed.
+    Sovereignty(SovereigntyReadError),
+    /// A groups file could not be parsed.
+    Groups(GroupsReadError),
+    /// The group with the given code has an invalid variant name or date.
+    InvalidGroup(String),
+    /// An ISO 4217 currency list could not be parsed.
+    Currencies(CurrencyReadError),
+    /// A calling code table could not be parsed.
+    CallingCodes(CallingCodesReadError),
+    /// A libphonenumber `PhoneNumberMetadata.xml` file could not be parsed.
+    PhoneNumberMetadata(PhoneNumberMetadataError),
+    /// The country with the given alpha-2 code has a calling code or prefix which is not made of
+    /// digits.
+    InvalidCallingCode(String),
+    /// A Public Suffix List file could not be parsed.
+    PublicSuffixList(PslReadError),
+    /// A tzdata zone table could not be parsed.
+    TimeZones(TimeZonesReadError),
+    /// A geographic metadata table could not be parsed.
+    Geography(GeographyReadError),
+    /// The country with the given alpha-2 code has coordinates which are out of range, or which
+    /// are outside its bounding box.
+    InvalidGeography(String),
+    /// A neighbour table or `GeoNames` `countryInfo.txt` file could not be parsed.
+    Neighbours(NeighboursReadError),
+    /// The country with the given alpha-2 code lists itself as a neighbour, or a neighbour which
+    /// does not list it back.
+    InvalidNeighbours(String),
+    /// A `GeoJSON` boundary file could not be parsed.
+    Boundaries(BoundariesReadError),
+    /// The country with the given alpha-2 code has a boundary position which is out of range.
+    InvalidBoundaries(String),
+    /// An IBAN Registry file could not be parsed.
+    Iban(IbanReadError),
+    /// The country with the given alpha-2 code has an IBAN format whose lengths, identifier
+    /// positions and example do not agree.
+    InvalidIban(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Error::MissingSource => f.write_str("No data source was given"),
+            Error::InvalidDate(date) => write!(f, "The date {date} is not in YYYY-MM-DD form"),
+            Error::InvalidDerive(derive) => write!(f, "The derive {derive} is not a valid path"),
+            Error::InvalidRecord(error) => write!(f, "Invalid record: {error}"),
+            Error::IncompleteFormat(format) => write!(
+                f,
+                "The {format} format does not contain both alpha-2 and alpha-3 codes; \
+                 use all.json, all.csv or all.xml instead"
+            ),
+            Error::InvalidOutput(error) => write!(f, "Invalid generated code: {error}"),
+            Error::Io(error) => write!(f, "Could not access data: {error}"),
+            Error::Read(error) => write!(f, "Could not parse data: {error}"),
+            Error::M49(error) => write!(f, "Could not parse M49 data: {error}"),
+            Error::Cldr(error) => write!(f, "Could not parse CLDR data: {error}"),
+            Error::IsoCodes(error) => write!(f, "Could not parse iso-codes data: {error}"),
+            Error::Sovereignty(error) => write!(f, "Could not parse sovereignty data: {error}"),
+            Error::Groups(error) => write!(f, "Could not parse group data: {error}"),
+            Error::InvalidGroup(code) => {
+                write!(f, "The group {code} has an invalid variant name or date")
+            }
+            Error::Currencies(error) => write!(f, "Could not parse ISO 4217 data: {error}"),
+            Error::CallingCodes(error) => write!(f, "Could not parse calling code data: {error}"),
+            Error::PhoneNumberMetadata(error) => {
+                write!(f, "Could not parse libphonenumber metadata: {error}")
+            }
+            Error::InvalidCallingCode(alpha2) => {
+                write!(
+                    f,
+                    "The calling code or prefix of {alpha2} is not made of digits"
+                )
+            }
+            Error::PublicSuffixList(error) => {
+                write!(f, "Could not parse Public Suffix List data: {error}")
+            }
+            Error::TimeZones(error) => write!(f, "Could not parse time zone data: {error}"),
+            Error::Geography(error) => write!(f, "Could not parse geographic data: {error}"),
+            Error::InvalidGeography(alpha2) => {
+                write!(f, "The geographic data of {alpha2} is inconsistent")
+            }
+            Error::Neighbours(error) => write!(f, "Could not parse neighbour data: {error}"),
+            Error::InvalidNeighbours(alpha2) => {
+                write!(f, "The neighbours of {alpha2} are not listed both ways")
+            }
+            Error::Boundaries(error) => write!(f, "Could not parse boundary data: {error}"),
+            Error::InvalidBoundaries(alpha2) => {
+                write!(f, "The boundary of {alpha2} has a position out of range")
+            }
+            Error::Iban(error) => write!(f, "Could not parse IBAN registry data: {error}"),
+            Error::InvalidIban(alpha2) => write!(f, "The IBAN format of {alpha2} is inconsistent"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Read(error) => Some(error),
+            Error::M49(error)
+            | Error::Sovereignty(error)
+            | Error::CallingCodes(error)
+            | Error::Geography(error)
+            | Error::Neighbours(error) => Some(error),
+            Error::Cldr(error) => Some(error),
+            Error::IsoCodes(error) => Some(error),
+            Error::Groups(error) => Some(error),
+            Error::Currencies(error) => Some(error),
+            Error::PhoneNumberMetadata(error) => Some(error),
+            Error::PublicSuffixList(error) => Some(error),
+            Error::TimeZones(error) => Some(error),
+            Error::Boundaries(error) => Some(error),
+            Error::Iban(error) => Some(error),
+            Error::InvalidOutput(error) => Some(error),
+            Error::InvalidRecord(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+impl From<RecordError> for Error {
+    fn from(value: RecordError) -> Self {
+        Error::InvalidRecord(value)
+    }
+}
+
+impl From<CldrReadError> for Error {
+    fn from(value: CldrReadError) -> Self {
+        Error::Cldr(value)
+    }
+}
+
+impl From<IsoCodesReadError> for Error {
+    fn from(value: IsoCodesReadError) -> Self {
+        Error::IsoCodes(value)
+    }
+}
+
+impl From<GroupsReadError> for Error {
+    fn from(value: GroupsReadError) -> Self {
+        Error::Groups(value)
+    }
+}
+
+impl From<CurrencyReadError> for Error {
+    fn from(value: CurrencyReadError) -> Self {
+        Error::Currencies(value)
+    }
+}
+
+impl From<PhoneNumberMetadataError> for Error {
+    fn from(value: PhoneNumberMetadataError) -> Self {
+        Error::PhoneNumberMetadata(value)
+    }
+}
+
+impl From<TimeZonesReadError> for Error {
+    fn from(value: TimeZonesReadError) -> Self {
+        Error::TimeZones(value)
+    }
+}
+
+impl From<BoundariesReadError> for Error {
+    fn from(value: BoundariesReadError) -> Self {
+        Error::Boundaries(value)
+    }
+}
+
+impl From<IbanReadError> for Error {
+    fn from(value: IbanReadError) -> Self {
+        Error::Iban(value)
+    }
+}
+
+impl From<PslReadError> for Error {
+    fn from(value: PslReadError) -> Self {
+        Error::PublicSuffixList(value)
+    }
+}
+
+impl From<ReadError> for Error {
+    fn from(value: ReadError) -> Self {
+        Error::Read(value)
+    }
+}
+
+/// The location of the source data.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Source {
+    /// The path to a copy of Luke Duncalfe's `all.json` file.
+    LukesJsonFile(PathBuf),
+    /// The contents of Luke Duncalfe's `all.json` file.
+    LukesJson(Vec<u8>),
+    /// The path to a copy of one of Luke Duncalfe's files, in the given format.
+    LukesFile(PathBuf, Format),
+    /// The contents of one of Luke Duncalfe's files, in the given format.
+    Lukes(Vec<u8>, Format),
+    /// The snapshot of Luke Duncalfe's `all.json` bundled with `iso3166-parsers`.
+    Snapshot,
+}
+
+impl Source {
+    /// The format of the source data.
+    fn format(&self) -> Format {
+        match self {
+            Source::LukesFile(_, format) | Source::Lukes(_, format) => *format,
+            Source::LukesJsonFile(_) | Source::LukesJson(_) | Source::Snapshot => Format::AllJson,
+        }
+    }
+
+    fn read(&self) -> Result<Vec<u8>, Error> {
+        match self {
+            Source::LukesJsonFile(path) | Source::LukesFile(path, _) => Ok(fs::read(path)?),
+            Source::LukesJson(bytes) | Source::Lukes(bytes, _) => Ok(bytes.clone()),
+            Source::Snapshot => Ok(SNAPSHOT.as_bytes().to_vec()),
+        }
+    }
+}
+
+/// The location of a supplementary data file, such as an M49 table.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Table {
+    File(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+impl Table {
+    fn bytes(&self) -> Result<Vec<u8>, Error> {
+        match self {
+            Table::File(path) => Ok(fs::read(path)?),
+            Table::Bytes(bytes) => Ok(bytes.clone()),
+        }
+    }
+
+    fn read_m49(&self) -> Result<Vec<M49Record>, Error> {
+        read_m49(self.bytes()?.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::M49)
+    }
+
+    fn read_cldr(&self) -> Result<DisplayNames, Error> {
+        Ok(read_territories(self.bytes()?.as_slice())?)
+    }
+
+    fn read_iso_codes(&self) -> Result<Vec<IsoCodesRecord>, Error> {
+        Ok(read_3166_1(self.bytes()?.as_slice()).collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn read_groups(&self) -> Result<Vec<Group>, Error> {
+        Ok(read_groups(self.bytes()?.as_slice())?)
+    }
+
+    fn read_currencies(&self) -> Result<Vec<CurrencyEntry>, Error> {
+        Ok(read_currencies(self.bytes()?.as_slice())?)
+    }
+
+    fn read_calling_codes(&self) -> Result<Vec<CallingCodesRecord>, Error> {
+        read_e164(self.bytes()?.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::CallingCodes)
+    }
+
+    fn read_phone_number_metadata(&self) -> Result<Vec<CallingCodesRecord>, Error> {
+        Ok(read_phone_numbers(self.bytes()?.as_slice())?)
+    }
+
+    fn read_iban_registry(&self) -> Result<Vec<IbanEntry>, Error> {
+        Ok(read_iban(self.bytes()?.as_slice())?)
+    }
+
+    fn read_top_level_domains(&self) -> Result<Vec<TopLevelDomain>, Error> {
+        Ok(read_top_level_domains(self.bytes()?.as_slice())?)
+    }
+
+    fn read_territory_info(&self) -> Result<Vec<TerritoryInfo>, Error> {
+        Ok(read_territory_info(self.bytes()?.as_slice())?)
+    }
+
+    fn read_zones(&self) -> Result<Vec<Zone>, Error> {
+        Ok(read_zones(self.bytes()?.as_slice())?)
+    }
+
+    fn read_geography(&self) -> Result<Vec<GeographyRecord>, Error> {
+        read_geo(self.bytes()?.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::Geography)
+    }
+
+    fn read_neighbours(&self) -> Result<Vec<NeighboursRecord>, Error> {
+        read_neighbours(self.bytes()?.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::Neighbours)
+    }
+
+    fn read_country_info(&self) -> Result<Vec<NeighboursRecord>, Error> {
+        read_country_info(self.bytes()?.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::Neighbours)
+    }
+
+    fn read_boundaries(&self) -> Result<Vec<Boundary>, Error> {
+        Ok(read_boundaries(self.bytes()?.as_slice())?)
+    }
+
+    fn read_sovereignty(&self) -> Result<Vec<SovereigntyRecord>, Error> {
+        read_sovereignty(self.bytes()?.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::Sovereignty)
+    }
+}
+
+/// A country from the source data, as it will be generated.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct Variant {
+    /// The name of the enum variant.
+    pub ident: String,
+    /// The English short name of the country.
+    pub name: String,
+    /// The alpha-2 code.
+    pub alpha2: String,
+    /// The alpha-3 code.
+    pub alpha3: String,
+    /// The numeric code.
+    pub numeric: u16,
+}
+
+/// Determine whether the given string is a user-assigned alpha-2 code.
+#[must_use]
+pub fn is_user_assigned_alpha2(code: &str) -> bool {
+    lukes::USER_ALPHA2.contains(&code)
+}
+
+/// Determine whether the given string is a user-assigned alpha-3 code.
+#[must_use]
+pub fn is_user_assigned_alpha3(code: &str) -> bool {
+    matches!(
+        code.as_bytes(),
+        [b'A', b'A', b'A'..=b'Z']
+            | [b'Q', b'M'..=b'Z', b'A'..=b'Z']
+            | [b'X', b'A'..=b'Z', b'A'..=b'Z']
+            | [b'Z', b'Z', b'A'..=b'Z']
+    )
+}
+
+/// Determine whether the given number is a user-assigned numeric code.
+#[must_use]
+pub fn is_user_assigned_numeric(code: u16) -> bool {
+    lukes::USER_NUMERIC.contains(&code)
+}
+
+/// How enum variants should be named.
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum VariantNaming {
+    /// Use the pascal-cased English short name, e.g. `UnitedStatesOfAmerica`.
+    #[default]
+    Name,
+    /// Use the alpha-2 code, e.g. `US`.
+    Alpha2,
+    /// Use the alpha-3 code, e.g. `USA`.
+    Alpha3,
+    /// Use a custom function to create the variant name from a country.
+    Custom(fn(&Country) -> String),
+}
+
+/// A predicate used to select which countries are generated.
+type Filter = Box<dyn Fn(&Country) -> bool>;
+
+/// A builder used to configure and run the code generator.
+pub struct Builder {
+    source: Option<Source>,
+    data_source: Option<String>,
+    data_date: Option<String>,
+    filter: Option<Filter>,
+    derives: Vec<String>,
+    naming: VariantNaming,
+    examples: bool,
+    m49: Vec<(Language, Table)>,
+    cldr: Vec<Table>,
+    name_index: bool,
+    iso_codes: Option<Table>,
+    sovereignty: Option<Table>,
+    groups: Option<Table>,
+    currencies: Option<Table>,
+    withdrawn_currencies: Option<Table>,
+    calling_codes: Option<Table>,
+    phone_number_metadata: Option<Table>,
+    public_suffix_list: Option<Table>,
+    territory_info: Option<Table>,
+    time_zones: Option<Table>,
+    geography: Option<Table>,
+    neighbours: Option<Table>,
+    country_info: Option<Table>,
+    boundaries: Option<Table>,
+    boundary_tolerance: f64,
+    iban_registry: Option<Table>,
+}
+
+impl Debug for Builder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Builder")
+            .field("source", &self.source)
+            .field("data_source", &self.data_source)
+            .field("data_date", &self.data_date)
+            .field("filter", &self.filter.is_some())
+            .field("derives", &self.derives)
+            .field("naming", &self.naming)
+            .field("examples", &self.examples)
+            .field("m49", &self.m49)
+            .field("cldr", &self.cldr)
+            .field("name_index", &self.name_index)
+            .field("iso_codes", &self.iso_codes)
+            .field("sovereignty", &self.sovereignty)
+            .field("groups", &self.groups)
+            .field("currencies", &self.currencies)
+            .field("withdrawn_currencies", &self.withdrawn_currencies)
+            .field("calling_codes", &self.calling_codes)
+            .field("phone_number_metadata", &self.phone_number_metadata)
+            .field("public_suffix_list", &self.public_suffix_list)
+            .field("territory_info", &self.territory_info)
+            .field("time_zones", &self.time_zones)
+            .field("geography", &self.geography)
+            .field("neighbours", &self.neighbours)
+            .field("country_info", &self.country_info)
+            .field("boundaries", &self.boundaries)
+            .field("boundary_tolerance", &self.boundary_tolerance)
+            .field("iban_registry", &self.iban_registry)
+            .finish()
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder {
+    /// Create a new builder with no data source.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            source: None,
+            data_source: None,
+            data_date: None,
+            filter: None,
+            derives: Vec::new(),
+            naming: VariantNaming::default(),
+            examples: false,
+            m49: Vec::new(),
+            cldr: Vec::new(),
+            name_index: false,
+            iso_codes: None,
+            sovereignty: None,
+            groups: None,
+            currencies: None,
+            withdrawn_currencies: None,
+            calling_codes: None,
+            phone_number_metadata: None,
+            public_suffix_list: None,
+            territory_info: None,
+            time_zones: None,
+            geography: None,
+            neighbours: None,
+            country_info: None,
+            boundaries: None,
+            boundary_tolerance: DEFAULT_BOUNDARY_TOLERANCE,
+            iban_registry: None,
+        }
+    }
+
+    /// Set the source data to generate code from.
+    #[must_use]
+    pub fn source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Set the description of where the data came from, used in the `DATA_SOURCE` constant.
+    ///
+    /// This defaults to [`DEFAULT_DATA_SOURCE`].
+    #[must_use]
+    pub fn data_source(mut self, data_source: impl Into<String>) -> Self {
+        self.data_source = Some(data_source.into());
+        self
+    }
+
+    /// Set the date the data was retrieved from upstream, in `YYYY-MM-DD` form.
+    ///
+    /// When it is not given, `DATA_DATE` is generated as [`UNKNOWN_DATA_DATE`].
+    #[must_use]
+    pub fn data_date(mut self, data_date: impl Into<String>) -> Self {
+        self.data_date = Some(data_date.into());
+        self
+    }
+
+    /// Only generate variants for countries where the given function returns `true`.
+    ///
+    /// User-assigned codes are always generated.
+    #[must_use]
+    pub fn filter(mut self, filter: impl Fn(&Country) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Add a derive (e.g. `"serde::Serialize"`) to each of the generated enumerations.
+    #[must_use]
+    pub fn derive(mut self, derive: impl Into<String>) -> Self {
+        self.derives.push(derive.into());
+        self
+    }
+
+    /// Choose how the enum variants are named.
+    #[must_use]
+    pub fn naming(mut self, naming: VariantNaming) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Whether to include the doctest examples used by `iso3166-static` in the generated docs.
+    ///
+    /// This is disabled by default, as the examples refer to the `iso3166_static` crate.
+    #[must_use]
+    pub fn examples(mut self, examples: bool) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    /// Add the contents of the UN's M49 table in the given language.
+    ///
+    /// When any M49 tables are given, a `Language` enum and an `Alpha2::name_in()` method are
+    /// generated, returning each country's M49 name in the languages which were given.
+    #[must_use]
+    pub fn m49(mut self, language: Language, table: impl Into<Vec<u8>>) -> Self {
+        self.m49.push((language, Table::Bytes(table.into())));
+        self
+    }
+
+    /// Add the path to a copy of the UN's M49 table in the given language.
+    ///
+    /// See [`Builder::m49()`] for details.
+    #[must_use]
+    pub fn m49_file(mut self, language: Language, path: impl Into<PathBuf>) -> Self {
+        self.m49.push((language, Table::File(path.into())));
+        self
+    }
+
+    /// Add the contents of a CLDR `territories.json` file, as found in each locale's directory of
+    /// the `cldr-localenames-full` package.
+    ///
+    /// When any CLDR files are given, a `LocalizedName` struct, a `CLDR_LOCALES` constant and an
+    /// `Alpha2::localized_name()` method are generated for the locales which were given.
+    #[must_use]
+    pub fn cldr(mut self, territories: impl Into<Vec<u8>>) -> Self {
+        self.cldr.push(Table::Bytes(territories.into()));
+        self
+    }
+
+    /// Add the path to a CLDR `territories.json` file.
+    ///
+    /// See [`Builder::cldr()`] for details.
+    #[must_use]
+    pub fn cldr_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cldr.push(Table::File(path.into()));
+        self
+    }
+
+    /// Whether to generate the normalized name index used for name lookups.
+    ///
+    /// When enabled, a `NameKind` enum, an `IndexedName` struct, a `NAME_INDEX` constant and a
+    /// `transliterate()` function are generated. The index contains the short names from the
+    /// source data, the English M49 names if an English table was given, the names from
+    /// `iso-codes` if given, and a list of common aliases.
+    #[must_use]
+    pub fn name_index(mut self, name_index: bool) -> Self {
+        self.name_index = name_index;
+        self
+    }
+
+    /// Set the contents of the `iso_3166-1.json` file from Debian's `iso-codes`, which provides
+    /// formal and common names.
+    ///
+    /// When given, an `Alpha2::formal_name()` method is generated, and the names are added to the
+    /// name index if it is enabled.
+    #[must_use]
+    pub fn iso_codes(mut self, iso_3166_1: impl Into<Vec<u8>>) -> Self {
+        self.iso_codes = Some(Table::Bytes(iso_3166_1.into()));
+        self
+    }
+
+    /// Set the path to a copy of the `iso_3166-1.json` file from Debian's `iso-codes`.
+    ///
+    /// See [`Builder::iso_codes()`] for details.
+    #[must_use]
+    pub fn iso_codes_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.iso_codes = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a sovereignty CSV file, as read by `iso3166_parsers::sovereignty`.
+    ///
+    /// When given, `sovereign()`, `dependencies()` and `is_independent()` methods are generated
+    /// for each of the country types.
+    #[must_use]
+    pub fn sovereignty(mut self, table: impl Into<Vec<u8>>) -> Self {
+        self.sovereignty = Some(Table::Bytes(table.into()));
+        self
+    }
+
+    /// Set the path to a sovereignty CSV file.
+    ///
+    /// See [`Builder::sovereignty()`] for details.
+    #[must_use]
+    pub fn sovereignty_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.sovereignty = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a groups JSON file, as read by `iso3166_parsers::groups`.
+    ///
+    /// When given, a `Group` enum and the `Date` and `Membership` structs are generated, along
+    /// with `Alpha2::is_member_of()` and `Alpha2::was_member_of()`. Current membership is as of
+    /// the date given to [`Builder::data_date()`], so accessions scheduled after it are not yet
+    /// current, and without one every membership which has not ended is current.
+    #[must_use]
+    pub fn groups(mut self, groups: impl Into<Vec<u8>>) -> Self {
+        self.groups = Some(Table::Bytes(groups.into()));
+        self
+    }
+
+    /// Set the path to a groups JSON file.
+    ///
+    /// See [`Builder::groups()`] for details.
+    #[must_use]
+    pub fn groups_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.groups = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of an ISO 4217 list of current currencies, in the format of SIX's
+    /// `list-one.xml`.
+    ///
+    /// When given, a `Currency` enum and a `FormerCurrency` struct are generated, along with
+    /// `Alpha2::currencies()`, `Alpha2::former_currencies()` and `Currency::countries()`. The
+    /// country names in the list are matched to countries using their short names, and the names
+    /// from `iso-codes` if given.
+    #[must_use]
+    pub fn currencies(mut self, list_one: impl Into<Vec<u8>>) -> Self {
+        self.currencies = Some(Table::Bytes(list_one.into()));
+        self
+    }
+
+    /// Set the path to a copy of SIX's `list-one.xml`.
+    ///
+    /// See [`Builder::currencies()`] for details.
+    #[must_use]
+    pub fn currencies_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.currencies = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of an ISO 4217 list of withdrawn currencies, in the format of SIX's
+    /// `list-three.xml`.
+    ///
+    /// The withdrawn currencies are added to those generated by [`Builder::currencies()`], and
+    /// are returned by `Alpha2::former_currencies()`.
+    #[must_use]
+    pub fn withdrawn_currencies(mut self, list_three: impl Into<Vec<u8>>) -> Self {
+        self.withdrawn_currencies = Some(Table::Bytes(list_three.into()));
+        self
+    }
+
+    /// Set the path to a copy of SIX's `list-three.xml`.
+    ///
+    /// See [`Builder::withdrawn_currencies()`] for details.
+    #[must_use]
+    pub fn withdrawn_currencies_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.withdrawn_currencies = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a calling code CSV file, as read by `iso3166_parsers::e164`.
+    ///
+    /// When given, a `CallingPrefix` struct, a `CALLING_PREFIXES` constant and an
+    /// `Alpha2::calling_codes()` method are generated.
+    #[must_use]
+    pub fn calling_codes(mut self, table: impl Into<Vec<u8>>) -> Self {
+        self.calling_codes = Some(Table::Bytes(table.into()));
+        self
+    }
+
+    /// Set the path to a calling code CSV file.
+    ///
+    /// See [`Builder::calling_codes()`] for details.
+    #[must_use]
+    pub fn calling_codes_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.calling_codes = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of libphonenumber's `PhoneNumberMetadata.xml`, as read by
+    /// `iso3166_parsers::e164::read_metadata`.
+    ///
+    /// When given, the items of [`Builder::calling_codes()`] are generated from the metadata
+    /// instead of a calling code table.
+    #[must_use]
+    pub fn phone_number_metadata(mut self, xml: impl Into<Vec<u8>>) -> Self {
+        self.phone_number_metadata = Some(Table::Bytes(xml.into()));
+        self
+    }
+
+    /// Set the path to libphonenumber's `PhoneNumberMetadata.xml`.
+    ///
+    /// See [`Builder::phone_number_metadata()`] for details.
+    #[must_use]
+    pub fn phone_number_metadata_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.phone_number_metadata = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a Public Suffix List file, as read by `iso3166_parsers::psl`.
+    ///
+    /// When given, a `Cctld` struct, a `CCTLDS` constant and an `Alpha2::cctlds()` method are
+    /// generated. The ccTLDs IANA delegated under another code, `.uk` and `.ac`, are mapped to
+    /// their countries, and internationalized ccTLDs to the country named in their comment.
+    #[must_use]
+    pub fn public_suffix_list(mut self, list: impl Into<Vec<u8>>) -> Self {
+        self.public_suffix_list = Some(Table::Bytes(list.into()));
+        self
+    }
+
+    /// Set the path to a copy of the Public Suffix List.
+    ///
+    /// See [`Builder::public_suffix_list()`] for details.
+    #[must_use]
+    pub fn public_suffix_list_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.public_suffix_list = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of CLDR's `supplemental/territoryInfo.json`, as read by
+    /// `iso3166_parsers::cldr::read_territory_info`.
+    ///
+    /// When given, an `OfficialStatus` enumeration, a `CountryLanguage` struct and an
+    /// `Alpha2::languages()` method are generated.
+    #[must_use]
+    pub fn territory_info(mut self, file: impl Into<Vec<u8>>) -> Self {
+        self.territory_info = Some(Table::Bytes(file.into()));
+        self
+    }
+
+    /// Set the path to a copy of CLDR's `supplemental/territoryInfo.json`.
+    ///
+    /// See [`Builder::territory_info()`] for details.
+    #[must_use]
+    pub fn territory_info_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.territory_info = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a tzdata `zone.tab` or `zone1970.tab` file, as read by
+    /// `iso3166_parsers::tzdata::read_zones`.
+    ///
+    /// When given, an `Alpha2::time_zones()` method and a `country_for_zone()` function are
+    /// generated. A zone in `zone1970.tab` is listed for every country which uses it, and belongs
+    /// to the first of them.
+    #[must_use]
+    pub fn time_zones(mut self, table: impl Into<Vec<u8>>) -> Self {
+        self.time_zones = Some(Table::Bytes(table.into()));
+        self
+    }
+
+    /// Set the path to a copy of tzdata's `zone.tab` or `zone1970.tab`.
+    ///
+    /// See [`Builder::time_zones()`] for details.
+    #[must_use]
+    pub fn time_zones_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.time_zones = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a geographic metadata CSV file, as read by
+    /// `iso3166_parsers::geo::read`.
+    ///
+    /// When given, `Coordinates`, `BoundingBox` and `Capital` structs, and
+    /// `Alpha2::capital()`, `Alpha2::centroid()` and `Alpha2::bounding_box()` methods are
+    /// generated.
+    #[must_use]
+    pub fn geography(mut self, table: impl Into<Vec<u8>>) -> Self {
+        self.geography = Some(Table::Bytes(table.into()));
+        self
+    }
+
+    /// Set the path to a geographic metadata CSV file.
+    ///
+    /// See [`Builder::geography()`] for details.
+    #[must_use]
+    pub fn geography_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.geography = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a neighbour CSV file, as read by `iso3166_parsers::neighbours::read`.
+    ///
+    /// When given, `Alpha2::land_neighbours()` and `Alpha2::maritime_neighbours()` methods are
+    /// generated.
+    #[must_use]
+    pub fn neighbours(mut self, table: impl Into<Vec<u8>>) -> Self {
+        self.neighbours = Some(Table::Bytes(table.into()));
+        self
+    }
+
+    /// Set the path to a neighbour CSV file.
+    ///
+    /// See [`Builder::neighbours()`] for details.
+    #[must_use]
+    pub fn neighbours_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.neighbours = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a `GeoNames` `countryInfo.txt` file, as read by
+    /// `iso3166_parsers::neighbours::read_country_info`.
+    ///
+    /// When given, `Alpha2::land_neighbours()` is generated from its `neighbours` column instead
+    /// of the land neighbours of [`Builder::neighbours()`], whose maritime neighbours are kept.
+    /// Without a neighbour table, `Alpha2::maritime_neighbours()` is empty for every country.
+    #[must_use]
+    pub fn country_info(mut self, file: impl Into<Vec<u8>>) -> Self {
+        self.country_info = Some(Table::Bytes(file.into()));
+        self
+    }
+
+    /// Set the path to a `GeoNames` `countryInfo.txt` file.
+    ///
+    /// See [`Builder::country_info()`] for details.
+    #[must_use]
+    pub fn country_info_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.country_info = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the contents of a `GeoJSON` file of country boundaries, as read by
+    /// `iso3166_parsers::boundaries::read`, such as Natural Earth's Admin 0 countries.
+    ///
+    /// When given, the `BoundaryRing` struct and the `BOUNDARY_POINTS`, `BOUNDARY_RINGS`,
+    /// `BOUNDARY_GRID` and `BOUNDARY_TOLERANCE` constants are generated, with each ring simplified
+    /// to within [`Builder::boundary_tolerance()`].
+    #[must_use]
+    pub fn boundaries(mut self, geojson: impl Into<Vec<u8>>) -> Self {
+        self.boundaries = Some(Table::Bytes(geojson.into()));
+        self
+    }
+
+    /// Set the path to a `GeoJSON` file of country boundaries.
+    ///
+    /// See [`Builder::boundaries()`] for details.
+    #[must_use]
+    pub fn boundaries_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.boundaries = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Set the distance in degrees within which boundaries are simplified, trading accuracy near
+    /// coasts and borders for size.
+    ///
+    /// This defaults to [`DEFAULT_BOUNDARY_TOLERANCE`], and negative values are treated as zero,
+    /// which keeps every position.
+    #[must_use]
+    pub fn boundary_tolerance(mut self, tolerance: f64) -> Self {
+        self.boundary_tolerance = tolerance;
+        self
+    }
+
+    /// Set the contents of a SWIFT IBAN Registry text file, as read by
+    /// `iso3166_parsers::iban::read`.
+    ///
+    /// When given, the `BbanCharacters` enum, the `BbanSegment` and `IbanFormat` structs, an
+    /// `IBAN_FORMATS` constant and an `Alpha2::iban_format()` method are generated.
+    #[must_use]
+    pub fn iban_registry(mut self, registry: impl Into<Vec<u8>>) -> Self {
+        self.iban_registry = Some(Table::Bytes(registry.into()));
+        self
+    }
+
+    /// Set the path to a SWIFT IBAN Registry text file.
+    ///
+    /// See [`Builder::iban_registry()`] for details.
+    #[must_use]
+    pub fn iban_registry_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.iban_registry = Some(Table::File(path.into()));
+        self
+    }
+
+    /// Resolve the countries which will be generated, in numeric order.
+    ///
+    /// This does not include user-assigned codes, and does not require a data date.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::MissingSource`] when no source was given.
+    /// - [`Error::Io`] and [`Error::Read`] when the data could not be read.
+    /// - [`Error::IncompleteFormat`] when the data is in a slim format.
+    /// - [`Error::InvalidRecord`] when the data contains an invalid record.
+    pub fn variants(&self) -> Result<Vec<Variant>, Error> {
+        let source = self.source.as_ref().ok_or(Error::MissingSource)?;
+        let (countries, _) = self.countries(&source.read()?, source.format())?;
+
+        Ok(lukes::variants(&self.config(Vec::new()), &countries))
+    }
+
+    /// Parse and validate the given data, returning the filtered countries in numeric order, and
+    /// the number of records in the data.
+    fn countries(&self, bytes: &[u8], format: Format) -> Result<(Vec<Country>, usize), Error> {
+        if !(format.has_alpha2() && format.has_alpha3()) {
+            return Err(Error::IncompleteFormat(format));
+        }
+
+        let records = read_format(format, bytes).collect::<Result<Vec<_>, _>>()?;
+        let count = records.len();
+        let mut countries = validate(records)?
+            .into_iter()
+            .filter(|country| self.filter.as_ref().is_none_or(|filter| filter(country)))
+            .collect::<Vec<_>>();
+        countries.sort_by_key(|country| country.numeric);
+
+        Ok((countries, count))
+    }
+
+    fn config(&self, derives: Vec<syn::Path>) -> lukes::Config {
+        lukes::Config {
+            naming: self.naming,
+            derives,
+            examples: self.examples,
+        }
+    }
+
+    /// Generate the code as a token stream.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::MissingSource`] when the builder is incomplete.
+    /// - [`Error::InvalidDate`] and [`Error::InvalidDerive`] when the builder was given invalid
+    ///   values.
+    /// - [`Error::Io`] and [`Error::Read`] when the data could not be read.
+    /// - [`Error::IncompleteFormat`] when the data is in a slim format.
+    /// - [`Error::InvalidRecord`] when the data contains an invalid record.
+    /// - [`Error::M49`], [`Error::Cldr`], [`Error::IsoCodes`], [`Error::Sovereignty`],
+    ///   [`Error::Groups`], [`Error::Currencies`], [`Error::CallingCodes`],
+    ///   [`Error::PhoneNumberMetadata`], [`Error::PublicSuffixList`], [`Error::TimeZones`],
+    ///   [`Error::Geography`],
+    ///   [`Error::Neighbours`], [`Error::Boundaries`] and [`Error::Iban`] when supplementary
+    ///   data could not be read.
+    /// - [`Error::InvalidGroup`] when a group has an invalid variant name or date.
+    /// - [`Error::InvalidCallingCode`] when a calling code or prefix is not made of digits.
+    /// - [`Error::InvalidGeography`] when a country's coordinates are out of range or outside its
+    ///   bounding box.
+    /// - [`Error::InvalidNeighbours`] when a country's neighbours are not listed both ways.
+    /// - [`Error::InvalidBoundaries`] when a country's boundary has a position out of range.
+    /// - [`Error::InvalidIban`] when a country's IBAN format is inconsistent.
+    #[allow(clippy::too_many_lines)]
+    pub fn generate(&self) -> Result<TokenStream, Error> {
+        let source = self.source.as_ref().ok_or(Error::MissingSource)?;
+        let data_date = self.data_date.as_deref();
+        if let Some(date) = data_date.filter(|date| !is_iso_date(date)) {
+            return Err(Error::InvalidDate(date.to_owned()));
+        }
+
+        let derives = self
+            .derives
+            .iter()
+            .map(|derive| {
+                syn::parse_str::<syn::Path>(derive)
+                    .map_err(|_| Error::InvalidDerive(derive.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let bytes = source.read()?;
+        let (countries, count) = self.countries(&bytes, source.format())?;
+        let config = self.config(derives);
+
+        let mut retval = lukes::provenance(
+            self.data_source.as_deref().unwrap_or(DEFAULT_DATA_SOURCE),
+            data_date.unwrap_or(UNKNOWN_DATA_DATE),
+            &bytes,
+            count,
+        );
+        retval.extend(lukes::generate(&config, &countries));
+
+        let m49_names = if self.m49.is_empty() {
+            None
+        } else {
+            let tables = self
+                .m49
+                .iter()
+                .map(|(language, table)| Ok((*language, table.read_m49()?)))
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            Some(merge(tables))
+        };
+
+        if let Some(names) = &m49_names {
+            retval.extend(m49::generate(&config, &countries, names));
+        }
+
+        let iso_codes = match &self.iso_codes {
+            Some(table) => table.read_iso_codes()?,
+            None => Vec::new(),
+        };
+
+        if self.iso_codes.is_some() {
+            retval.extend(names::formal(&config, &countries, &iso_codes));
+        }
+
+        if let Some(table) = &self.sovereignty {
+            retval.extend(sovereignty::generate(
+                &config,
+                &countries,
+                &table.read_sovereignty()?,
+            ));
+        }
+
+        if let Some(table) = &self.groups {
+            retval.extend(groups::generate(
+                &config,
+                &countries,
+                &table.read_groups()?,
+                data_date,
+            )?);
+        }
+
+        if self.currencies.is_some() || self.withdrawn_currencies.is_some() {
+            let read = |table: Option<&Table>| match table {
+                Some(table) => table.read_currencies(),
+                None => Ok(Vec::new()),
+            };
+
+            retval.extend(currencies::generate(
+                &config,
+                &countries,
+                &iso_codes,
+                &read(self.currencies.as_ref())?,
+                &read(self.withdrawn_currencies.as_ref())?,
+            ));
+        }
+
+        let calling_codes = match (&self.phone_number_metadata, &self.calling_codes) {
+            (Some(table), _) => Some(table.read_phone_number_metadata()?),
+            (None, Some(table)) => Some(table.read_calling_codes()?),
+            (None, None) => None,
+        };
+        if let Some(records) = calling_codes {
+            retval.extend(calling_codes::generate(&config, &countries, &records)?);
+        }
+
+        if let Some(table) = &self.territory_info {
+            retval.extend(languages::generate(
+                &config,
+                &countries,
+                &table.read_territory_info()?,
+            ));
+        }
+
+        if let Some(table) = &self.time_zones {
+            retval.extend(time_zones::generate(
+                &config,
+                &countries,
+                &table.read_zones()?,
+            ));
+        }
+
+        if let Some(table) = &self.geography {
+            retval.extend(geography::generate(
+                &config,
+                &countries,
+                &table.read_geography()?,
+            )?);
+        }
+
+        if self.neighbours.is_some() || self.country_info.is_some() {
+            let mut records = match &self.neighbours {
+                Some(table) => table.read_neighbours()?,
+                None => Vec::new(),
+            };
+            if let Some(table) = &self.country_info {
+                records = merge_land(records, table.read_country_info()?);
+            }
+
+            retval.extend(neighbours::generate(&config, &countries, &records)?);
+        }
+
+        if let Some(table) = &self.iban_registry {
+            retval.extend(iban::generate(
+                &config,
+                &countries,
+                &table.read_iban_registry()?,
+            )?);
+        }
+
+        if self.boundaries.is_some() {
+            retval.extend(self.generate_boundaries_for(&config, &countries)?);
+        }
+
+        if let Some(table) = &self.public_suffix_list {
+            retval.extend(cctlds::generate(
+                &config,
+                &countries,
+                &table.read_top_level_domains()?,
+            ));
+        }
+
+        if self.name_index {
+            retval.extend(names::generate(
+                &config,
+                &countries,
+                m49_names.as_ref(),
+                &iso_codes,
+            ));
+        }
+
+        if !self.cldr.is_empty() {
+            retval.extend(self.generate_cldr_for(&config, &countries)?);
+        }
+
+        Ok(retval)
+    }
+
+    /// Generate only the CLDR localized names, for use alongside separately generated
+    /// enumerations, such as those in `iso3166-static`.
+    ///
+    /// This does not require a data date, but must use the same source, filter and naming as the
+    /// enumerations.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::MissingSource`] when no source was given.
+    /// - [`Error::Io`], [`Error::Read`] and [`Error::Cldr`] when the data could not be read.
+    /// - [`Error::InvalidRecord`] when the data contains an invalid record.
+    pub fn generate_cldr(&self) -> Result<TokenStream, Error> {
+        let source = self.source.as_ref().ok_or(Error::MissingSource)?;
+        let (countries, _) = self.countries(&source.read()?, source.format())?;
+
+        self.generate_cldr_for(&self.config(Vec::new()), &countries)
+    }
+
+    fn generate_cldr_for(
+        &self,
+        config: &lukes::Config,
+        countries: &[Country],
+    ) -> Result<TokenStream, Error> {
+        let locales = self
+            .cldr
+            .iter()
+            .map(Table::read_cldr)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(cldr::generate(config, countries, &locales))
+    }
+
+    /// Generate only the country boundaries, for use alongside separately generated enumerations,
+    /// such as those in `iso3166-static`.
+    ///
+    /// This does not require a data date, but must use the same source, filter and naming as the
+    /// enumerations. The tables are empty when no boundaries were given.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::MissingSource`] when no source was given.
+    /// - [`Error::Io`], [`Error::Read`] and [`Error::Boundaries`] when the data could not be read.
+    /// - [`Error::InvalidRecord`] when the data contains an invalid record.
+    /// - [`Error::InvalidBoundaries`] when a country's boundary has a position out of range.
+    pub fn generate_boundaries(&self) -> Result<TokenStream, Error> {
+        let source = self.source.as_ref().ok_or(Error::MissingSource)?;
+        let (countries, _) = self.countries(&source.read()?, source.format())?;
+
+        self.generate_boundaries_for(&self.config(Vec::new()), &countries)
+    }
+
+    fn generate_boundaries_for(
+        &self,
+        config: &lukes::Config,
+        countries: &[Country],
+    ) -> Result<TokenStream, Error> {
+        let boundaries = match &self.boundaries {
+            Some(table) => table.read_boundaries()?,
+            None => Vec::new(),
+        };
+
+        boundaries::generate(config, countries, &boundaries, self.boundary_tolerance)
+    }
+
+    /// Generate the code as a formatted Rust source file.
+    ///
+    /// # Errors
+    ///
+    /// - Any error from [`Builder::generate()`].
+    /// - [`Error::InvalidOutput`] when the generated code is not a valid Rust source file.
+    pub fn render(&self) -> Result<String, Error> {
+        unparse(self.generate()?)
+    }
+
+    /// Generate only the CLDR localized names as a formatted Rust source file.
+    ///
+    /// # Errors
+    ///
+    /// - Any error from [`Builder::generate_cldr()`].
+    /// - [`Error::InvalidOutput`] when the generated code is not a valid Rust source file.
+    pub fn render_cldr(&self) -> Result<String, Error> {
+        unparse(self.generate_cldr()?)
+    }
+
+    /// Generate only the country boundaries as a formatted Rust source file.
+    ///
+    /// # Errors
+    ///
+    /// - Any error from [`Builder::generate_boundaries()`].
+    /// - [`Error::InvalidOutput`] when the generated code is not a valid Rust source file.
+    pub fn render_boundaries(&self) -> Result<String, Error> {
+        unparse(self.generate_boundaries()?)
+    }
+
+    /// Generate the code and write it to the given path as a formatted Rust source file.
+    ///
+    /// # Errors
+    ///
+    /// - Any error from [`Builder::render()`].
+    /// - [`Error::Io`] when the file could not be written.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        fs::write(path, self.render()?)?;
+        Ok(())
+    }
+}
+
+/// Format generated code as a Rust source file.
+fn unparse(tokens: TokenStream) -> Result<String, Error> {
+    let file = syn::parse2::<syn::File>(tokens).map_err(Error::InvalidOutput)?;
+
+    let mut retval = String::from("// @generated by iso3166-codegen, do not edit by hand.\n\n");
+    retval.push_str(&prettyplease::unparse(&file));
+
+    Ok(retval)
+}
+
+/// Check whether the given string is a valid date in `YYYY-MM-DD` form.
+fn is_iso_date(value: &str) -> bool {
+    iso3166_parsers::groups::parse_date(value).is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Builder, Error, Format, Language, Source, VariantNaming};
+    use iso3166_parsers::m49::SNAPSHOT as M49;
+
+    const ALL_JSON: &[u8] = include_bytes!("../../static/src/all.json");
+
+    fn builder() -> Builder {
+        Builder::new()
+            .source(Source::LukesJson(ALL_JSON.to_vec()))
+            .data_date("2025-12-29")
+    }
+
+    #[test]
+    fn missing_source() {
+        let err = Builder::new().data_date("2025-12-29").generate();
+        assert!(matches!(err, Err(Error::MissingSource)));
+    }
+
+    #[test]
+    fn unknown_date() {
+        let output = Builder::new()
+            .source(Source::LukesJson(ALL_JSON.to_vec()))
+            .render()
+            .expect("rendered");
+        assert!(output.contains(r#"pub const DATA_DATE: &str = "unknown";"#));
+    }
+
+    #[test]
+    fn invalid_date() {
+        let err = builder().data_date("Dec. 29, 2025").generate();
+        assert!(matches!(err, Err(Error::InvalidDate(_))));
+    }
+
+    #[test]
+    fn invalid_derive() {
+        let err = builder().derive("not a path").generate();
+        assert!(matches!(err, Err(Error::InvalidDerive(_))));
+    }
+
+    #[test]
+    fn filter_and_naming() {
+        let output = builder()
+            .filter(|country| country.region.as_ref().is_some_and(|r| r.name == "Europe"))
+            .naming(VariantNaming::Alpha2)
+            .derive("serde::Serialize")
+            .generate()
+            .expect("generated")
+            .to_string();
+
+        assert!(output.contains("Self :: DE =>"));
+        assert!(!output.contains("Self :: US =>"));
+        assert!(output.contains("serde :: Serialize"));
+        assert!(output.contains("DATA_RECORD_COUNT : usize = 249usize"));
+    }
+
+    #[test]
+    fn csv_source() {
+        const ALL_CSV: &str = "\
+name,alpha-2,alpha-3,country-code,iso_3166-2,region,sub-region,intermediate-region,region-code,sub-region-code,intermediate-region-code
+United States of America,US,USA,840,ISO 3166-2:US,Americas,Northern America,,019,021,
+Germany,DE,DEU,276,ISO 3166-2:DE,Europe,Western Europe,,150,155,
+";
+
+        let variants = Builder::new()
+            .source(Source::Lukes(ALL_CSV.as_bytes().to_vec(), Format::AllCsv))
+            .variants()
+            .expect("variants");
+
+        assert_eq!(2, variants.len());
+        assert_eq!("Germany", variants[0].ident);
+        assert_eq!("USA", variants[1].alpha3);
+    }
+
+    #[test]
+    fn slim_source() {
+        let err = Builder::new()
+            .source(Source::Lukes(b"[]".to_vec(), Format::Slim2Json))
+            .variants();
+        assert!(matches!(
+            err,
+            Err(Error::IncompleteFormat(Format::Slim2Json))
+        ));
+    }
+
+    #[test]
+    fn m49_names() {
+        const M49_FR: &str = "\
+Code global;Nom global;Code région;Nom région;Code sous-région;Nom sous-région;Code région intermédiaire;Nom région intermédiaire;Pays ou zone;Code M49;Code ISO-alpha2;Code ISO-alpha3;Pays les moins avancés (PMA);Pays en développement sans littoral (PDSL);Petits États insulaires en développement (PEID)
+001;Monde;150;Europe;155;Europe occidentale;;;Allemagne;276;DE;DEU;;;";
+
+        let output = builder()
+            .m49(Language::English, M49)
+            .m49(Language::French, M49_FR)
+            .generate()
+            .expect("generated")
+            .to_string();
+
+        assert!(output.contains("pub enum Language"));
+        assert!(output.contains("(Self :: Germany , Language :: English) => Some (\"Germany\")"));
+        assert!(output.contains("(Self :: Germany , Language :: French) => Some (\"Allemagne\")"));
+        assert!(!output.contains("(Self :: France , Language :: French)"));
+        assert!(
+            !builder()
+                .generate()
+                .expect("generated")
+                .to_string()
+                .contains("name_in")
+        );
+    }
+
+    #[test]
+    fn cldr() {
+        const DE: &[u8] = include_bytes!("../../parsers/src/cldr/de/territories.json");
+
+        let output = builder()
+            .cldr(DE)
+            .render_cldr()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains(r#"pubconstCLDR_LOCALES:&[&str]=&["de"];"#));
+        assert!(output.contains(
+            r#"Alpha2::UnitedKingdom=>{Some(LocalizedName{name:"VereinigtesKönigreich",short:Some("VK"),variant:None,})}"#
+        ));
+        assert!(output.contains(r#"variant:Some("Kongo(Republik)")"#));
+        assert!(!output.contains("pubenumAlpha2"));
+
+        let full = builder()
+            .cldr(DE)
+            .generate()
+            .expect("generated")
+            .to_string();
+        assert!(full.contains("pub enum Alpha2"));
+        assert!(full.contains("fn localized_name"));
+    }
+
+    #[test]
+    fn name_index() {
+        let output = builder()
+            .name_index(true)
+            .iso_codes(iso3166_parsers::iso_codes::SNAPSHOT)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains(
+            r#"IndexedName{key:"cotedivoire",name:"Côted'Ivoire",country:Alpha2::CoteDIvoire,kind:NameKind::Short,}"#
+        ));
+        assert!(output.contains(r#"key:"ivorycoast""#));
+        assert!(output.contains(
+            r#"key:"unitedstatesofamerica",name:"UnitedStatesofAmerica",country:Alpha2::UnitedStatesOfAmerica,kind:NameKind::Short"#
+        ));
+        assert!(output.contains(
+            r#"key:"southkorea",name:"SouthKorea",country:Alpha2::SouthKorea,kind:NameKind::Common"#
+        ));
+        assert!(!builder().render().expect("rendered").contains("NAME_INDEX"));
+    }
+
+    #[test]
+    fn formal_names() {
+        let output = builder()
+            .iso_codes(iso3166_parsers::iso_codes::SNAPSHOT)
+            .generate()
+            .expect("generated")
+            .to_string();
+
+        assert!(
+            output.contains("Self :: UnitedStatesOfAmerica => Some (\"United States of America\")")
+        );
+        assert!(
+            output
+                .contains("Self :: NorthKorea => Some (\"Democratic People's Republic of Korea\")")
+        );
+        assert!(!output.contains("NAME_INDEX"));
+        assert!(
+            !builder()
+                .generate()
+                .expect("generated")
+                .to_string()
+                .contains("formal_name")
+        );
+    }
+
+    #[test]
+    fn sovereignty() {
+        let output = builder()
+            .sovereignty(iso3166_parsers::sovereignty::SNAPSHOT)
+            .generate()
+            .expect("generated")
+            .to_string();
+
+        assert!(output.contains("Self :: PuertoRico => Some (Self :: UnitedStatesOfAmerica)"));
+        assert!(output.contains("Self :: China => & [Self :: HongKong , Self :: Macao]"));
+        assert!(!output.contains("Self :: Antarctica => Some (Self ::"));
+
+        let europe = builder()
+            .filter(|country| country.region.as_ref().is_some_and(|r| r.name == "Europe"))
+            .sovereignty(iso3166_parsers::sovereignty::SNAPSHOT)
+            .generate()
+            .expect("generated")
+            .to_string();
+        assert!(europe.contains("Self :: SvalbardAndJanMayen => Some (Self :: Norway)"));
+        assert!(!europe.contains("Self :: Greenland"));
+    }
+
+    #[test]
+    fn groups() {
+        let output = builder()
+            .groups(iso3166_parsers::groups::SNAPSHOT)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains("pubenumGroup{"));
+        assert!(output.contains(
+            "Membership{country:Alpha2::UnitedKingdom,joined:Date{year:1973,month:1,day:1,},left:Some(Date{year:2020,month:1,day:31,}),}"
+        ));
+        assert!(output.contains(r#"Self::EuropeanUnion=>"EU","#));
+        assert!(output.contains(
+            "fnis_current(&self)->bool{self.is_active_on(Date{year:2025,month:12,day:29,})}"
+        ));
+
+        let output = Builder::new()
+            .source(Source::LukesJson(ALL_JSON.to_vec()))
+            .groups(iso3166_parsers::groups::SNAPSHOT)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+        assert!(output.contains("fnis_current(&self)->bool{self.left.is_none()}"));
+
+        let err = builder()
+            .groups(r#"[{"code":"X","variant":"X","name":"X","members":[{"alpha-2":"DE","joined":"2021-02-29"}]}]"#)
+            .generate();
+        assert!(matches!(err, Err(Error::InvalidGroup(code)) if code == "X"));
+
+        let err = builder()
+            .groups(r#"[{"code":"X","variant":"X","name":"X","members":[{"alpha-2":"DE","joined":"1 May"}]}]"#)
+            .generate();
+        assert!(matches!(err, Err(Error::InvalidGroup(code)) if code == "X"));
+
+        let err = builder()
+            .groups(r#"[{"code":"X","variant":"not valid","name":"X","members":[]}]"#)
+            .generate();
+        assert!(matches!(err, Err(Error::InvalidGroup(_))));
+    }
+
+    #[test]
+    fn currencies() {
+        const LIST_ONE: &str = r#"<ISO_4217 Pblshd="2024-06-25"><CcyTbl>
+<CcyNtry><CtryNm>KOREA (THE REPUBLIC OF)</CtryNm><CcyNm>Won</CcyNm><Ccy>KRW</Ccy><CcyNbr>410</CcyNbr><CcyMnrUnts>0</CcyMnrUnts></CcyNtry>
+<CcyNtry><CtryNm>UNITED STATES OF AMERICA (THE)</CtryNm><CcyNm>US Dollar</CcyNm><Ccy>USD</Ccy><CcyNbr>840</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+<CcyNtry><CtryNm>UNITED STATES OF AMERICA (THE)</CtryNm><CcyNm IsFund="true">US Dollar (Next day)</CcyNm><Ccy>USN</Ccy><CcyNbr>997</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+<CcyNtry><CtryNm>EUROPEAN UNION</CtryNm><CcyNm>Euro</CcyNm><Ccy>EUR</Ccy><CcyNbr>978</CcyNbr><CcyMnrUnts>2</CcyMnrUnts></CcyNtry>
+</CcyTbl></ISO_4217>"#;
+
+        let output = builder()
+            .currencies(LIST_ONE)
+            .withdrawn_currencies(iso3166_parsers::iso4217::LIST_THREE)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains("pubenumCurrency{"));
+        assert!(output.contains("Self::UnitedStatesOfAmerica=>&[Currency::USD,Currency::USN],"));
+        assert!(output.contains("Self::SouthKorea=>&[Currency::KRW],"));
+        assert!(output.contains("Self::EUR=>&[],"));
+        assert!(output.contains(
+            r#"Self::Germany=>{&[FormerCurrency{currency:Currency::DEM,withdrawn:"2002-03",},]}"#
+        ));
+        assert!(output.contains("Self::USN=>true,"));
+        assert!(output.contains("Self::DEM=>None,"));
+
+        let err = builder()
+            .currencies("<CcyNtry><CcyNbr>X</CcyNbr></CcyNtry>")
+            .generate();
+        assert!(matches!(err, Err(Error::Currencies(_))));
+    }
+
+    #[test]
+    fn calling_codes() {
+        let output = builder()
+            .calling_codes(iso3166_parsers::e164::SNAPSHOT)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(
+            output.contains(
+                r#"CallingPrefix{prefix:"1416",country_code:"1",country:Alpha2::Canada,}"#
+            )
+        );
+        assert!(output.contains(
+            r#"CallingPrefix{prefix:"1",country_code:"1",country:Alpha2::UnitedStatesOfAmerica,}"#
+        ));
+        assert!(
+            output.contains(r#"Self::SaintHelenaAscensionAndTristanDaCunha=>&["+247","+290"],"#)
+        );
+        assert!(!output.contains("Self::BouvetIsland=>&["));
+
+        let err = builder()
+            .calling_codes("alpha-2,country-code,prefixes\nUS,+1,\n")
+            .generate();
+        assert!(matches!(err, Err(Error::InvalidCallingCode(alpha2)) if alpha2 == "US"));
+    }
+
+    #[test]
+    fn phone_number_metadata() {
+        let from_table = builder()
+            .calling_codes(iso3166_parsers::e164::SNAPSHOT)
+            .render()
+            .expect("rendered");
+        let from_metadata = builder()
+            .calling_codes("alpha-2,country-code,prefixes\n")
+            .phone_number_metadata(iso3166_parsers::e164::PHONE_NUMBER_METADATA)
+            .render()
+            .expect("rendered");
+        assert_eq!(from_table, from_metadata);
+
+        let err = builder()
+            .phone_number_metadata("<territory id=\"GB\">")
+            .generate();
+        assert!(matches!(err, Err(Error::PhoneNumberMetadata(_))));
+    }
+
+    #[test]
+    fn cctlds() {
+        let output = builder()
+            .public_suffix_list(iso3166_parsers::psl::SNAPSHOT)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains(
+            r#"Self::UnitedKingdom=>{&[Cctld{name:"uk",ascii:"uk",country:Alpha2::UnitedKingdom,},Cctld{name:"gb",ascii:"gb",country:Alpha2::UnitedKingdom,},]}"#
+        ));
+        assert!(output.contains(
+            r#"Cctld{name:"ac",ascii:"ac",country:Alpha2::SaintHelenaAscensionAndTristanDaCunha,}"#
+        ));
+        assert!(output.contains(r#"Cctld{name:"рф",ascii:"xn--p1ai",country:Alpha2::Russia,}"#));
+        assert!(!output.contains(r#"name:"eu""#));
+        assert!(!output.contains("Self::SaintBarthelemy=>{&["));
+
+        let err = builder().public_suffix_list(&b"uk\n\xff\n"[..]).generate();
+        assert!(matches!(err, Err(Error::PublicSuffixList(_))));
+    }
+
+    #[test]
+    fn languages() {
+        let output = builder()
+            .territory_info(iso3166_parsers::cldr::TERRITORY_INFO)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains(
+            r#"Self::Canada=>{&[CountryLanguage{code:"en",locale:"en-CA",population_percent:86f32,official_status:Some(OfficialStatus::Official),},CountryLanguage{code:"fr",locale:"fr-CA","#
+        ));
+        assert!(output.contains(
+            r#"CountryLanguage{code:"tts",locale:"tts-TH",population_percent:24f32,official_status:None,}"#
+        ));
+        assert!(!output.contains("Self::Antarctica=>{&["));
+
+        let err = builder().territory_info("{\"supplemental\":{}}").generate();
+        assert!(matches!(err, Err(Error::Cldr(_))));
+    }
+
+    #[test]
+    fn time_zones() {
+        let output = builder()
+            .time_zones(iso3166_parsers::tzdata::ZONE_TAB)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains(r#"Self::Germany=>&["Europe/Berlin","Europe/Busingen"],"#));
+        assert!(output.contains(r#""Europe/Berlin"=>Some(Alpha2::Germany),"#));
+
+        let output = builder()
+            .time_zones(iso3166_parsers::tzdata::ZONE1970_TAB)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains(r#"Self::Norway=>&["Europe/Berlin"],"#));
+        assert!(output.contains(r#""Europe/Berlin"=>Some(Alpha2::Germany),"#));
+
+        let err = builder().time_zones("DE\t+5230+01322\n").generate();
+        assert!(matches!(err, Err(Error::TimeZones(_))));
+    }
+
+    #[test]
+    fn geography() {
+        let output = builder()
+            .geography(iso3166_parsers::geo::SNAPSHOT)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains(
+            r#"Self::Germany=>{Some(Capital{name:"Berlin",coordinates:Coordinates{latitude_e6:52520000,longitude_e6:13400000,},})}"#
+        ));
+        assert!(output.contains(
+            r"Self::Fiji=>{Some(BoundingBox{south_west:Coordinates{latitude_e6:-20700000,longitude_e6:176900000,},"
+        ));
+        assert!(!output.contains("Self::Antarctica=>{Some(Capital"));
+
+        let err = builder()
+            .geography("alpha-2,capital,capital-latitude,capital-longitude,latitude,longitude,south,west,north,east\nDE,Berlin,52.52,13.40,0,10.45,47.27,5.87,55.06,15.04\n")
+            .generate();
+        assert!(matches!(err, Err(Error::InvalidGeography(alpha2)) if alpha2 == "DE"));
+
+        let err = builder()
+            .geography("alpha-2,latitude\nDE,north\n")
+            .generate();
+        assert!(matches!(err, Err(Error::Geography(_))));
+    }
+
+    #[test]
+    fn neighbours() {
+        let output = builder()
+            .neighbours(iso3166_parsers::neighbours::SNAPSHOT)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains("Self::Lesotho=>&[Self::SouthAfrica],"));
+        assert!(output.contains("Self::SanMarino=>&[Self::Italy],"));
+        assert!(output.contains(
+            "Self::Iceland=>{&[Self::FaroeIslands,Self::Greenland,Self::SvalbardAndJanMayen]}"
+        ));
+
+        let err = builder()
+            .neighbours("alpha-2,land,maritime\nFR,ES,\nES,,\n")
+            .generate();
+        assert!(matches!(err, Err(Error::InvalidNeighbours(alpha2)) if alpha2 == "FR"));
+
+        let err = builder().neighbours("alpha-2,land\nFR\n").generate();
+        assert!(matches!(err, Err(Error::Neighbours(_))));
+    }
+
+    #[test]
+    fn country_info() {
+        let country_info = "#ISO\tISO3\tISO-Numeric\tfips\tCountry\tCapital\tArea(in sq km)\t\
+                            Population\tContinent\ttld\tCurrencyCode\tCurrencyName\tPhone\t\
+                            Postal Code Format\tPostal Code Regex\tLanguages\tgeonameid\t\
+                            neighbours\tEquivalentFipsCode\n\
+                            LS\tLSO\t426\tLT\tLesotho\tMaseru\t30355\t2108132\tAF\t.ls\tLSL\t\
+                            Loti\t266\t\t\ten-LS,st,zu,xh\t932692\tZA\t\n\
+                            ZA\tZAF\t710\tSF\tSouth Africa\tPretoria\t1219912\t57779622\t\
+                            AF\t.za\tZAR\tRand\t27\t\t\ten-ZA\t953987\t\
+                            BW,LS,MZ,NA,SZ,XK,ZW\t\n";
+
+        let output = builder()
+            .neighbours(iso3166_parsers::neighbours::SNAPSHOT)
+            .country_info(country_info)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+        assert!(output.contains("Self::Lesotho=>&[Self::SouthAfrica],"));
+        assert!(output.contains("Self::Botswana,Self::Lesotho,Self::Mozambique,"));
+        assert!(output.contains(
+            "Self::Iceland=>{&[Self::FaroeIslands,Self::Greenland,Self::SvalbardAndJanMayen]}"
+        ));
+
+        let output = builder()
+            .country_info(country_info)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+        assert!(output.contains("Self::Lesotho=>&[Self::SouthAfrica],"));
+        assert!(!output.contains("Self::FaroeIslands,Self::Greenland"));
+
+        let err = builder().country_info("LS\tLSO\tmany\n").generate();
+        assert!(matches!(err, Err(Error::Neighbours(_))));
+    }
+
+    #[test]
+    fn iban_registry() {
+        let output = builder()
+            .iban_registry(iso3166_parsers::iban::SNAPSHOT)
+            .render()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains(
+            r#"IbanFormat{country:Alpha2::Germany,length:22,bban:&[BbanSegment{length:8,characters:BbanCharacters::Digits,},BbanSegment{length:10,characters:BbanCharacters::Digits,},],bank:Some((0,8)),branch:None,sepa:true,example:"DE89370400440532013000",}"#
+        ));
+        assert!(output.contains("Self::AlandIslands=>Some(&IBAN_FORMATS["));
+        assert!(!output.contains("Kosovo"));
+
+        let err = builder()
+            .iban_registry(iso3166_parsers::iban::SNAPSHOT.replacen(
+                "DE89370400440532013000",
+                "DE88370400440532013000",
+                1,
+            ))
+            .generate();
+        assert!(matches!(err, Err(Error::InvalidIban(alpha2)) if alpha2 == "DE"));
+
+        let err = builder()
+            .iban_registry("Name of country\tGermany\n")
+            .generate();
+        assert!(matches!(err, Err(Error::Iban(_))));
+    }
+
+    #[test]
+    fn boundaries() {
+        const SQUARES: &str = r#"{"type":"FeatureCollection","features":[
+            {"type":"Feature","properties":{"ISO_A2":"ZA"},"geometry":{"type":"Polygon","coordinates":[
+                [[16,-35],[33,-35],[33,-22],[16,-22],[16,-35]],[[27,-31],[29,-31],[29,-29],[27,-29],[27,-31]]]}},
+            {"type":"Feature","properties":{"ISO_A2":"LS"},"geometry":{"type":"Polygon","coordinates":[
+                [[27,-31],[29,-31],[29,-29],[27,-29],[27,-31]]]}},
+            {"type":"Feature","properties":{"ISO_A2":"XX"},"geometry":{"type":"Polygon","coordinates":[
+                [[0,0],[1,0],[1,1],[0,0]]]}}]}"#;
+
+        let output = builder()
+            .boundaries(SQUARES)
+            .render_boundaries()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+
+        assert!(output.contains("pubconstBOUNDARY_TOLERANCE:f64=0.001;"));
+        assert!(output.contains(
+            "BoundaryRing{country:Alpha2::Lesotho,hole:false,start:4,end:8,bounds:[-31000000,27000000,-29000000,29000000],}"
+        ));
+        assert!(output.contains("country:Alpha2::SouthAfrica,hole:true,start:0,end:4"));
+        assert!(output.contains("country:Alpha2::SouthAfrica,hole:false,start:8,end:12"));
+        assert!(!output.contains("pubenumAlpha2"));
+
+        let empty = builder()
+            .render_boundaries()
+            .expect("rendered")
+            .replace([' ', '\n'], "");
+        assert!(empty.contains("pubconstBOUNDARY_RINGS:&[BoundaryRing]=&[];"));
+
+        let err = builder()
+            .boundaries(r#"{"type":"FeatureCollection","features":[{"type":"Feature","properties":{"ISO_A2":"FR"},"geometry":{"type":"Polygon","coordinates":[[[0,0],[200,0],[0,1],[0,0]]]}}]}"#)
+            .generate();
+        assert!(matches!(err, Err(Error::InvalidBoundaries(alpha2)) if alpha2 == "FR"));
+
+        let err = builder().boundaries("{}").generate();
+        assert!(matches!(err, Err(Error::Boundaries(_))));
+    }
+
+    #[test]
+    fn render() {
+        let output = builder().render().expect("rendered");
+
+        assert!(output.starts_with("// @generated"));
+        assert!(output.contains("pub enum Alpha2 {\n"));
+        assert!(output.contains("    /// United States of America (840, US, USA)\n"));
+    }
+}
@@ -0,0 +1,753 @@
+//! Code generation from the Luke Duncalfe's JSON.
+
+use crate::{Variant, VariantNaming};
+use heck::ToPascalCase;
+use iso3166_parsers::lukes::Country;
+use proc_macro2::{Span, TokenStream};
+use sha2::{Digest, Sha256};
+use std::{fmt::Write as _, ops::Range};
+use syn::{Ident, LitByteStr, Path};
+
+/// The user-assigned alpha-2 codes.
+pub(crate) const USER_ALPHA2: [&str; 42] = [
+    "AA", "QM", "QN", "QO", "QP", "QQ", "QR", "QS", "QT", "QU", "QV", "QW", "QX", "QY", "QZ", "XA",
+    "XB", "XC", "XD", "XE", "XF", "XG", "XH", "XI", "XJ", "XK", "XL", "XM", "XN", "XO", "XP", "XQ",
+    "XR", "XS", "XT", "XU", "XV", "XW", "XX", "XY", "XZ", "ZZ",
+];
+
+/// The user-assigned numeric codes.
+pub(crate) const USER_NUMERIC: Range<u16> = 900..999;
+
+/// The resolved generator configuration.
+pub(crate) struct Config {
+    pub(crate) naming: VariantNaming,
+    pub(crate) derives: Vec<Path>,
+    pub(crate) examples: bool,
+}
+
+impl Config {
+    /// Create the variant identifier for the given country.
+    pub(crate) fn ident(&self, country: &Country) -> Ident {
+        match self.naming {
+            VariantNaming::Name => name_to_ident(&country.name),
+            VariantNaming::Alpha2 => quote::format_ident!("{}", country.alpha2_str()),
+            VariantNaming::Alpha3 => quote::format_ident!("{}", country.alpha3_str()),
+            VariantNaming::Custom(naming) => quote::format_ident!("{}", naming(country)),
+        }
+    }
+
+    /// Create the doc attributes for an example, if examples are enabled.
+    pub(crate) fn example(&self, lines: &[&str]) -> TokenStream {
+        if !self.examples {
+            return TokenStream::new();
+        }
+
+        let mut docs = vec![String::new(), " # Examples".to_owned(), String::new()];
+        docs.push(" ```rust".to_owned());
+        docs.extend(lines.iter().map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!(" {line}")
+            }
+        }));
+        docs.push(" ```".to_owned());
+
+        quote::quote! {
+            #(
+                #[doc = #docs]
+            )*
+        }
+    }
+}
+
+/// Generate the data provenance constants.
+pub(crate) fn provenance(source: &str, date: &str, bytes: &[u8], count: usize) -> TokenStream {
+    let mut sha256 = String::with_capacity(64);
+    for byte in Sha256::digest(bytes) {
+        let _ = write!(sha256, "{byte:02x}");
+    }
+
+    let source_doc = format!(" The upstream source of the ISO 3166 data (`{source}`).");
+    let date_doc = format!(" The date the ISO 3166 data was retrieved from upstream ({date}).");
+
+    quote::quote! {
+        #[doc = #source_doc]
+        pub const DATA_SOURCE: &str = #source;
+
+        #[doc = #date_doc]
+        pub const DATA_DATE: &str = #date;
+
+        /// The hex-encoded SHA-256 digest of the data file used to generate this crate.
+        pub const DATA_SHA256: &str = #sha256;
+
+        /// The number of records in the data file used to generate this crate.
+        pub const DATA_RECORD_COUNT: usize = #count;
+    }
+}
+
+pub(crate) fn name_to_ident(name: &str) -> Ident {
+    let ident = crate::names::ascii(name.trim())
+        .to_pascal_case()
+        .replace("BoliviaPlurinationalStateOf", "Bolivia")
+        .replace("VirginIslandsBritish", "BritishVirginIslands")
+        .replace("TaiwanProvinceOfChina", "Taiwan")
+        .replace(
+            "CongoDemocraticRepublicOfThe",
+            "DemocraticRepublicOfTheCongo",
+        )
+        .replace("PalestineStateOf", "Palestine")
+        .replace("IranIslamicRepublicOf", "Iran")
+        .replace("KoreaDemocraticPeopleSRepublicOf", "NorthKorea")
+        .replace("KoreaRepublicOf", "SouthKorea")
+        .replace("LaoPeopleSDemocraticRepublic", "Laos")
+        .replace("MoldovaRepublicOf", "Moldova")
+        .replace("NetherlandsKingdomOfThe", "Netherlands")
+        .replace("MicronesiaFederatedStatesOf", "Micronesia")
+        .replace("RussianFederation", "Russia")
+        .replace("Turkiye", "Turkey")
+        .replace(
+            "UnitedKingdomOfGreatBritainAndNorthernIreland",
+            "UnitedKingdom",
+        )
+        .replace("TanzaniaUnitedRepublicOf", "Tanzania")
+        .replace("VenezuelaBolivarianRepublicOf", "Venezuela")
+        .replace("SyrianArabRepublic", "Syria");
+
+    quote::format_ident!("{ident}")
+}
+
+#[allow(clippy::too_many_lines)]
+fn numeric(config: &Config, data: &[Country]) -> TokenStream {
+    let mut ident = Vec::new();
+    let mut code = Vec::new();
+    let mut doc = Vec::new();
+    let mut name = Vec::new();
+    let mut alpha2 = Vec::new();
+    let mut alpha3 = Vec::new();
+
+    for country in data {
+        let n = country.name.as_str();
+        let a2 = country.alpha2_str();
+        let a3 = country.alpha3_str();
+        let cc = country.numeric;
+
+        let id = config.ident(country);
+        let d = format!(" {n} ({a2}, {a3})");
+
+        ident.push(id);
+        code.push(cc);
+        doc.push(d);
+        name.push(n);
+        alpha2.push(a2);
+        alpha3.push(a3);
+    }
+
+    let (user_ident, user_code, user_doc) = USER_NUMERIC
+        .map(|i| {
+            (
+                quote::format_ident!("User{i}"),
+                i,
+                format!("User-assigned {i}"),
+            )
+        })
+        .collect::<(Vec<Ident>, Vec<u16>, Vec<String>)>();
+
+    let derives = &config.derives;
+    let example = config.example(&[
+        "use iso3166_static::Numeric;",
+        "",
+        "assert!(!Numeric::UnitedStatesOfAmerica.is_user_assigned());",
+        "assert!(Numeric::User900.is_user_assigned());",
+    ]);
+
+    let mut retval = quote::quote! {
+        /// ISO 3166-1 Numeric Country Codes.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd #(, #derives)*)]
+        #[non_exhaustive]
+        #[repr(u16)]
+        pub enum Numeric {
+            #(
+                #[doc = #doc]
+                #ident = #code,
+            )*
+            #(
+                #[doc = #user_doc]
+                #user_ident = #user_code,
+            )*
+        }
+    };
+
+    retval.extend(quote::quote! {
+        impl Numeric {
+            /// Try to create a new numeric value from the given 16-bit integer.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UnknownCode`] when the code is unknown.
+            pub const fn from_u16(value: u16) -> Result<Self, Error> {
+                match value {
+                    #(
+                        #code => Ok(Self::#ident),
+                    )*
+                    #(
+                        #user_code => Ok(Self::#user_ident),
+                    )*
+
+                    _ => Err(Error::UnknownCode),
+                }
+            }
+
+            /// Try to create a new numeric code from the given alpha-2 code.
+            ///
+            /// Note that user-assigned alpha-2 codes do not have a direct numeric representation.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UserAssigned`] when attempting to translate to a User-assigned
+            ///   Alpha-2 code to a numeric code.
+            pub const fn from_alpha2(value: Alpha2) -> Result<Self, Error> {
+                match value {
+                    #(
+                        Alpha2::#ident => Ok(Self::#ident),
+                    )*
+                    _ => Err(Error::UserAssigned),
+                }
+            }
+
+            /// Try to create a new numeric code from the given alpha-2 code.
+            ///
+            /// Note that user-assigned alpha-2 codes do not have a direct numeric representation.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UserAssigned`] when attempting to translate to a User-assigned
+            ///   Alpha-3 code to a numeric code.
+            pub const fn from_alpha3(value: Alpha3) -> Result<Self, Error> {
+                match value {
+                    #(
+                        Alpha3::#ident => Ok(Self::#ident),
+                    )*
+                    _ => Err(Error::UserAssigned),
+                }
+            }
+
+            /// Determine whether a given enum value represents a user-assigned value.
+            #example
+            pub const fn is_user_assigned(&self) -> bool {
+                *self as u16 >= 900 && *self as u16 <= 999
+            }
+        }
+
+        impl PartialEq<Alpha2> for Numeric {
+            fn eq(&self, other: &Alpha2) -> bool {
+                match self {
+                    #(
+                        Self::#ident => *other == Alpha2::#ident,
+                    )*
+                    _ => false,
+                }
+            }
+        }
+
+        impl PartialEq<Alpha3> for Numeric {
+            fn eq(&self, other: &Alpha3) -> bool {
+                match self {
+                    #(
+                        Self::#ident => *other == Alpha3::#ident,
+                    )*
+                    _ => false,
+                }
+            }
+        }
+    });
+
+    retval
+}
+
+#[allow(clippy::too_many_lines)]
+fn alpha2(config: &Config, data: &[Country]) -> TokenStream {
+    let mut ident = Vec::new();
+    let mut doc = Vec::new();
+    let mut name = Vec::new();
+    let mut name_ascii = Vec::new();
+    let mut name_upper = Vec::new();
+    let mut alpha2 = Vec::new();
+    let mut alpha2_bytes = Vec::new();
+
+    for country in data {
+        let n = country.name.as_str();
+        let a2 = country.alpha2_str();
+        let a3 = country.alpha3_str();
+        let cc = country.numeric;
+
+        let id = config.ident(country);
+        let d = format!(" {n} ({cc:03}, {a2}, {a3})");
+        let bytes = LitByteStr::new(a2.as_bytes(), Span::mixed_site());
+
+        ident.push(id);
+        doc.push(d);
+        name.push(n);
+        name_ascii.push(crate::names::ascii(n));
+        name_upper.push(n.to_uppercase());
+        alpha2.push(a2);
+        alpha2_bytes.push(bytes);
+    }
+
+    // User-generated Alpha-2
+    let mut user_doc = Vec::new();
+    let mut user_ident = Vec::new();
+    let mut user_alpha2 = Vec::new();
+    let mut user_alpha2_bytes = Vec::new();
+
+    for user in USER_ALPHA2 {
+        let d = format!(" User-assigned {user}");
+        let id = quote::format_ident!("User{user}");
+        let bytes = LitByteStr::new(user.as_bytes(), Span::mixed_site());
+
+        user_doc.push(d);
+        user_ident.push(id);
+        user_alpha2.push(user);
+        user_alpha2_bytes.push(bytes);
+    }
+
+    let derives = &config.derives;
+    let example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "assert!(!Alpha2::UnitedStatesOfAmerica.is_user_assigned());",
+        "assert!(Alpha2::UserXX.is_user_assigned());",
+    ]);
+    let name_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "assert_eq!(Some(\"Réunion\"), Alpha2::Reunion.name());",
+        "assert_eq!(Some(\"Reunion\"), Alpha2::Reunion.name_ascii());",
+        "assert_eq!(Some(\"RÉUNION\"), Alpha2::Reunion.name_upper());",
+        "assert_eq!(None, Alpha2::UserXX.name());",
+    ]);
+
+    quote::quote! {
+        /// ISO 3166-1 Alpha-2 Country Codes.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd #(, #derives)*)]
+        #[non_exhaustive]
+        pub enum Alpha2 {
+            #(
+                #[doc = #doc]
+                #ident,
+            )*
+
+            #(
+                #[doc = #user_doc]
+                #user_ident,
+            )*
+        }
+
+        impl Alpha2 {
+            /// Parse the given alpha-2 string slice into the enum value.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UnknownCode`] when the string value is not a valid code.
+            pub const fn from_str_slice(value: &str) -> Result<Self, Error> {
+                if !value.is_ascii() {
+                    return Err(Error::InvalidCharset);
+                }
+
+                if value.len() != 2 {
+                    return Err(Error::InvalidLength);
+                }
+
+                match value.as_bytes() {
+                    #(
+                        #alpha2_bytes => Ok(Self::#ident),
+                    )*
+
+                    #(
+                        #user_alpha2_bytes => Ok(Self::#user_ident),
+                    )*
+
+                    _ => Err(Error::UnknownCode),
+                }
+            }
+
+            /// Try to convert the given numeric value into the alpha-2 value.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UserAssigned`] when the numeric value is unassigned and cannot be
+            ///   converted.
+            pub const fn from_numeric(value: Numeric) -> Result<Self, Error> {
+                match value {
+                    #(
+                        Numeric::#ident => Ok(Self::#ident),
+                    )*
+                    _ => Err(Error::UserAssigned),
+                }
+            }
+
+            /// Try to convert the given alpha-3 value into the alpha-2 value.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UserAssigned`] when the numeric value is unassigned and cannot be
+            ///   converted.
+            pub const fn from_alpha3(value: Alpha3) -> Result<Self, Error> {
+                match value {
+                    #(
+                        Alpha3::#ident => Ok(Self::#ident),
+                    )*
+                    _ => Err(Error::UserAssigned),
+                }
+            }
+
+            /// Get the string representation of the given Alpha-2 code.
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    #(
+                        Self::#ident => #alpha2,
+                    )*
+
+                    #(
+                        Self::#user_ident => #user_alpha2,
+                    )*
+                }
+            }
+
+            /// Determine whether a given enum value represents a user-assigned value.
+            #example
+            pub const fn is_user_assigned(&self) -> bool {
+                match self {
+                    #(
+                        Self::#user_ident => true,
+                    )*
+
+                    _ => false,
+                }
+            }
+
+            /// Get the English short name of the country, or `None` for user-assigned codes.
+            #name_example
+            pub const fn name(&self) -> Option<&'static str> {
+                match self {
+                    #(
+                        Self::#ident => Some(#name),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Get the English short name of the country transliterated into ASCII, e.g.
+            /// `Cote d'Ivoire`, or `None` for user-assigned codes.
+            pub const fn name_ascii(&self) -> Option<&'static str> {
+                match self {
+                    #(
+                        Self::#ident => Some(#name_ascii),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Get the English short name of the country in uppercase, e.g. `CÔTE D'IVOIRE`, or
+            /// `None` for user-assigned codes.
+            pub const fn name_upper(&self) -> Option<&'static str> {
+                match self {
+                    #(
+                        Self::#ident => Some(#name_upper),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+
+        impl PartialEq<Numeric> for Alpha2 {
+            fn eq(&self, other: &Numeric) -> bool {
+                match self {
+                    #(
+                        Self::#ident => *other == Numeric::#ident,
+                    )*
+                    _ => false,
+                }
+            }
+        }
+
+        impl PartialEq<Alpha3> for Alpha2 {
+            fn eq(&self, other: &Alpha3) -> bool {
+                match self {
+                    #(
+                        Self::#ident => *other == Alpha3::#ident,
+                    )*
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+fn make_user_alpha3(pos1: char, pos2: char, pos3: char) -> (Ident, String, String) {
+    let mut alpha3 = String::new();
+    alpha3.push(pos1);
+    alpha3.push(pos2);
+    alpha3.push(pos3);
+
+    let ident = quote::format_ident!("User{alpha3}");
+    let doc = format!(" User-assigned {alpha3}");
+
+    (ident, doc, alpha3)
+}
+
+#[allow(clippy::too_many_lines)]
+fn alpha3(config: &Config, data: &[Country]) -> TokenStream {
+    let mut ident = Vec::new();
+    let mut doc = Vec::new();
+    let mut name = Vec::new();
+    let mut alpha3 = Vec::new();
+    let mut alpha3_bytes = Vec::new();
+
+    for country in data {
+        let n = country.name.as_str();
+        let a2 = country.alpha2_str();
+        let a3 = country.alpha3_str();
+        let cc = country.numeric;
+
+        let id = config.ident(country);
+        let d = format!(" {n} ({cc:03}, {a2}, {a3})");
+        let bytes = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
+
+        ident.push(id);
+        doc.push(d);
+        name.push(n);
+        alpha3.push(a3);
+        alpha3_bytes.push(bytes);
+    }
+
+    // User-generated Alpha-2
+    let mut user_doc = Vec::new();
+    let mut user_ident = Vec::new();
+    let mut user_alpha3 = Vec::new();
+    let mut user_alpha3_bytes = Vec::new();
+
+    // AAA-AAZ
+    for code in 'A'..='Z' {
+        let (id, d, a3) = make_user_alpha3('A', 'A', code);
+        let a3b = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
+        user_ident.push(id);
+        user_doc.push(d);
+        user_alpha3.push(a3);
+        user_alpha3_bytes.push(a3b);
+    }
+
+    // QMA-QZZ
+    for code in 'M'..='Z' {
+        for code2 in 'A'..='Z' {
+            let (id, d, a3) = make_user_alpha3('Q', code, code2);
+            let a3b = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
+            user_ident.push(id);
+            user_doc.push(d);
+            user_alpha3.push(a3);
+            user_alpha3_bytes.push(a3b);
+        }
+    }
+
+    // XAA - XZZ
+    for code in 'A'..='Z' {
+        for code2 in 'A'..='Z' {
+            let (id, d, a3) = make_user_alpha3('X', code, code2);
+            let a3b = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
+            user_ident.push(id);
+            user_doc.push(d);
+            user_alpha3.push(a3);
+            user_alpha3_bytes.push(a3b);
+        }
+    }
+
+    // ZZA-ZZZ
+    for code in 'A'..='Z' {
+        let (id, d, a3) = make_user_alpha3('Z', 'Z', code);
+        let a3b = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
+        user_ident.push(id);
+        user_doc.push(d);
+        user_alpha3.push(a3);
+        user_alpha3_bytes.push(a3b);
+    }
+
+    let derives = &config.derives;
+    let example = config.example(&[
+        "use iso3166_static::Alpha3;",
+        "",
+        "assert!(!Alpha3::UnitedStatesOfAmerica.is_user_assigned());",
+        "assert!(Alpha3::UserZZZ.is_user_assigned());",
+    ]);
+
+    quote::quote! {
+        /// ISO 3166-1 Alpha-3 Country Codes.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd #(, #derives)*)]
+        #[non_exhaustive]
+        pub enum Alpha3 {
+            #(
+                #[doc = #doc]
+                #ident,
+            )*
+            #(
+                #[doc = #user_doc]
+                #user_ident,
+            )*
+        }
+
+        impl Alpha3 {
+            /// Parse the given alpha-3 string slice into the enum value.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UnknownCode`] when the string value is not a valid alpha-3 code.
+            pub const fn from_str_slice(value: &str) -> Result<Self, Error> {
+                if !value.is_ascii() {
+                    return Err(Error::InvalidCharset);
+                }
+
+                if value.len() != 3 {
+                    return Err(Error::InvalidLength);
+                }
+
+                match value.as_bytes() {
+                    #(
+                        #alpha3_bytes => Ok(Self::#ident),
+                    )*
+
+                    #(
+                        #user_alpha3_bytes => Ok(Self::#user_ident),
+                    )*
+
+                    _ => Err(Error::UnknownCode),
+                }
+            }
+
+            /// Try to convert the given numeric value into the alpha-3 value.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UserAssigned`] when the numeric value is unassigned and cannot be
+            ///   converted.
+            pub const fn from_numeric(value: Numeric) -> Result<Self, Error> {
+                match value {
+                    #(
+                        Numeric::#ident => Ok(Self::#ident),
+                    )*
+                    _ => Err(Error::UserAssigned),
+                }
+            }
+
+            /// Try to convert the given alpha-2 value into the alpha-3 value.
+            ///
+            /// # Errors
+            ///
+            /// - [`Error::UserAssigned`] when the numeric value is unassigned and cannot be
+            ///   converted.
+            pub const fn from_alpha2(value: Alpha2) -> Result<Self, Error> {
+                match value {
+                    #(
+                        Alpha2::#ident => Ok(Self::#ident),
+                    )*
+                    _ => Err(Error::UserAssigned),
+                }
+            }
+
+            /// Get the string representation of the given Alpha-3 code.
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    #(
+                        Self::#ident => #alpha3,
+                    )*
+
+                    #(
+                        Self::#user_ident => #user_alpha3,
+                    )*
+                }
+            }
+
+            /// Determine whether a given enum value represents a user-assigned value.
+            #example
+            pub const fn is_user_assigned(&self) -> bool {
+                match self {
+                    #(
+                        Self::#user_ident => true,
+                    )*
+
+                    _ => false,
+                }
+            }
+        }
+
+        impl PartialEq<Numeric> for Alpha3 {
+            fn eq(&self, other: &Numeric) -> bool {
+                match self {
+                    #(
+                        Self::#ident => *other == Numeric::#ident,
+                    )*
+                    _ => false,
+                }
+            }
+        }
+
+        impl PartialEq<Alpha2> for Alpha3 {
+            fn eq(&self, other: &Alpha2) -> bool {
+                match self {
+                    #(
+                        Self::#ident => *other == Alpha2::#ident,
+                    )*
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+/// Resolve the variants which will be generated from the given countries.
+pub(crate) fn variants(config: &Config, data: &[Country]) -> Vec<Variant> {
+    let mut retval = Vec::new();
+
+    for country in data {
+        let n = country.name.as_str();
+        let a2 = country.alpha2_str();
+        let a3 = country.alpha3_str();
+        let cc = country.numeric;
+
+        retval.push(Variant {
+            ident: config.ident(country).to_string(),
+            name: n.to_owned(),
+            alpha2: a2.to_owned(),
+            alpha3: a3.to_owned(),
+            numeric: cc,
+        });
+    }
+
+    retval
+}
+
+/// Generate the error type and the numeric, alpha-2 and alpha-3 enumerations.
+pub(crate) fn generate(config: &Config, data: &[Country]) -> TokenStream {
+    let mut retval = quote::quote! {
+        /// An enumeration of errors related to ISO 3166 codes.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
+        pub enum Error {
+            /// The given value is not a known ISO 3166-1 code.
+            UnknownCode,
+            /// User-assigned codes cannot be converted between types.
+            UserAssigned,
+            /// The string length is not a viable code.
+            InvalidLength,
+            /// The string contains non-ascii characters.
+            InvalidCharset,
+        }
+    };
+
+    let numeric = numeric(config, data);
+    let alpha2 = alpha2(config, data);
+    let alpha3 = alpha3(config, data);
+
+    retval.extend(numeric);
+    retval.extend(alpha2);
+    retval.extend(alpha3);
+
+    retval
+}
@@ -0,0 +1,104 @@
+//! Code generation from the UN's M49 tables.
+
+use crate::lukes::Config;
+use iso3166_parsers::{
+    lukes::Country,
+    m49::{Language, Names},
+};
+use proc_macro2::TokenStream;
+use std::collections::BTreeMap;
+use syn::Ident;
+
+/// The name of the generated `Language` variant for the given language.
+fn language_ident(language: Language) -> Ident {
+    let name = match language {
+        Language::Arabic => "Arabic",
+        Language::Chinese => "Chinese",
+        Language::English => "English",
+        Language::French => "French",
+        Language::Russian => "Russian",
+        Language::Spanish => "Spanish",
+        _ => unreachable!("unknown UN language {language}"),
+    };
+
+    quote::format_ident!("{name}")
+}
+
+/// Generate the `Language` enum and `Alpha2::name_in()` from the merged M49 names.
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    names: &BTreeMap<u16, Names>,
+) -> TokenStream {
+    let mut language = Vec::new();
+    let mut language_doc = Vec::new();
+    let mut language_code = Vec::new();
+
+    for lang in Language::ALL {
+        language.push(language_ident(lang));
+        language_doc.push(format!(" `{}`", lang.code()));
+        language_code.push(lang.code());
+    }
+
+    let mut ident = Vec::new();
+    let mut name_language = Vec::new();
+    let mut name = Vec::new();
+
+    for country in data {
+        let Some(names) = names.get(&country.numeric) else {
+            continue;
+        };
+
+        for lang in Language::ALL {
+            if let Some(n) = names.get(lang) {
+                ident.push(config.ident(country));
+                name_language.push(language_ident(lang));
+                name.push(n.to_owned());
+            }
+        }
+    }
+
+    let example = config.example(&[
+        "use iso3166_static::{Alpha2, Language};",
+        "",
+        "assert_eq!(Some(\"Germany\"), Alpha2::Germany.name_in(Language::English));",
+        "assert_eq!(None, Alpha2::UserXX.name_in(Language::English));",
+    ]);
+
+    quote::quote! {
+        /// The six official languages of the United Nations, in which the M49 names are published.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[non_exhaustive]
+        pub enum Language {
+            #(
+                #[doc = #language_doc]
+                #language,
+            )*
+        }
+
+        impl Language {
+            /// Get the ISO 639-1 code of the language.
+            pub const fn code(&self) -> &'static str {
+                match self {
+                    #(
+                        Self::#language => #language_code,
+                    )*
+                }
+            }
+        }
+
+        impl Alpha2 {
+            /// Get the UN M49 name of the country or area in the given language, if it was
+            /// included when the crate was generated.
+            #example
+            pub const fn name_in(&self, language: Language) -> Option<&'static str> {
+                match (self, language) {
+                    #(
+                        (Self::#ident, Language::#name_language) => Some(#name),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
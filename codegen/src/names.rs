@@ -0,0 +1,514 @@
+//! Code generation for the normalized country name index.
+
+use crate::lukes::Config;
+use iso3166_parsers::{
+    iso_codes,
+    lukes::Country,
+    m49::{Language, Names},
+};
+use proc_macro2::TokenStream;
+use std::collections::BTreeMap;
+
+/// The ASCII transliterations of the non-ASCII characters found in country names, and others
+/// likely to be typed by users.
+pub(crate) const TRANSLITERATIONS: &[(char, &str)] = &[
+    ('À', "A"),
+    ('Á', "A"),
+    ('Â', "A"),
+    ('Ã', "A"),
+    ('Ä', "A"),
+    ('Å', "A"),
+    ('Æ', "AE"),
+    ('Ç', "C"),
+    ('È', "E"),
+    ('É', "E"),
+    ('Ê', "E"),
+    ('Ë', "E"),
+    ('Ì', "I"),
+    ('Í', "I"),
+    ('Î', "I"),
+    ('Ï', "I"),
+    ('Ð', "D"),
+    ('Ñ', "N"),
+    ('Ò', "O"),
+    ('Ó', "O"),
+    ('Ô', "O"),
+    ('Õ', "O"),
+    ('Ö', "O"),
+    ('Ø', "O"),
+    ('Ù', "U"),
+    ('Ú', "U"),
+    ('Û', "U"),
+    ('Ü', "U"),
+    ('Ý', "Y"),
+    ('Þ', "TH"),
+    ('ß', "ss"),
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('ã', "a"),
+    ('ä', "a"),
+    ('å', "a"),
+    ('æ', "ae"),
+    ('ç', "c"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ð', "d"),
+    ('ñ', "n"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('õ', "o"),
+    ('ö', "o"),
+    ('ø', "o"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('ü', "u"),
+    ('ý', "y"),
+    ('þ', "th"),
+    ('ÿ', "y"),
+    ('Ā', "A"),
+    ('ā', "a"),
+    ('Ă', "A"),
+    ('ă', "a"),
+    ('Ą', "A"),
+    ('ą', "a"),
+    ('Ć', "C"),
+    ('ć', "c"),
+    ('Č', "C"),
+    ('č', "c"),
+    ('Ď', "D"),
+    ('ď', "d"),
+    ('Đ', "D"),
+    ('đ', "d"),
+    ('Ē', "E"),
+    ('ē', "e"),
+    ('Ė', "E"),
+    ('ė', "e"),
+    ('Ę', "E"),
+    ('ę', "e"),
+    ('Ě', "E"),
+    ('ě', "e"),
+    ('Ğ', "G"),
+    ('ğ', "g"),
+    ('Ģ', "G"),
+    ('ģ', "g"),
+    ('Ī', "I"),
+    ('ī', "i"),
+    ('Į', "I"),
+    ('į', "i"),
+    ('İ', "I"),
+    ('ı', "i"),
+    ('Ķ', "K"),
+    ('ķ', "k"),
+    ('Ĺ', "L"),
+    ('ĺ', "l"),
+    ('Ļ', "L"),
+    ('ļ', "l"),
+    ('Ľ', "L"),
+    ('ľ', "l"),
+    ('Ł', "L"),
+    ('ł', "l"),
+    ('Ń', "N"),
+    ('ń', "n"),
+    ('Ņ', "N"),
+    ('ņ', "n"),
+    ('Ň', "N"),
+    ('ň', "n"),
+    ('Ō', "O"),
+    ('ō', "o"),
+    ('Ő', "O"),
+    ('ő', "o"),
+    ('Œ', "OE"),
+    ('œ', "oe"),
+    ('Ŕ', "R"),
+    ('ŕ', "r"),
+    ('Ř', "R"),
+    ('ř', "r"),
+    ('Ś', "S"),
+    ('ś', "s"),
+    ('Ş', "S"),
+    ('ş', "s"),
+    ('Š', "S"),
+    ('š', "s"),
+    ('Ţ', "T"),
+    ('ţ', "t"),
+    ('Ť', "T"),
+    ('ť', "t"),
+    ('Ū', "U"),
+    ('ū', "u"),
+    ('Ů', "U"),
+    ('ů', "u"),
+    ('Ű', "U"),
+    ('ű', "u"),
+    ('Ų', "U"),
+    ('ų', "u"),
+    ('Ÿ', "Y"),
+    ('Ź', "Z"),
+    ('ź', "z"),
+    ('Ż', "Z"),
+    ('ż', "z"),
+    ('Ž', "Z"),
+    ('ž', "z"),
+    ('Ș', "S"),
+    ('ș', "s"),
+    ('Ț', "T"),
+    ('ț', "t"),
+    ('‘', "'"),
+    ('’', "'"),
+    ('“', "\""),
+    ('”', "\""),
+    ('–', "-"),
+    ('—', "-"),
+];
+
+/// Words which are ignored when comparing names, e.g. `The Gambia` and `Gambia`.
+pub(crate) const STOP_WORDS: [&str; 2] = ["and", "the"];
+
+/// Common names and abbreviations which are not in the source data, by alpha-2 code.
+const ALIASES: &[(&str, &str)] = &[
+    ("AE", "UAE"),
+    ("AE", "Emirates"),
+    ("BA", "Bosnia"),
+    ("BN", "Brunei"),
+    ("BO", "Bolivia"),
+    ("BQ", "Caribbean Netherlands"),
+    ("CD", "Democratic Republic of the Congo"),
+    ("CD", "DR Congo"),
+    ("CD", "DRC"),
+    ("CD", "Congo-Kinshasa"),
+    ("CG", "Republic of the Congo"),
+    ("CG", "Congo-Brazzaville"),
+    ("CI", "Ivory Coast"),
+    ("CV", "Cape Verde"),
+    ("CZ", "Czech Republic"),
+    ("FK", "Falklands"),
+    ("FM", "Micronesia"),
+    ("GB", "UK"),
+    ("GB", "Great Britain"),
+    ("GB", "Britain"),
+    ("GB", "England"),
+    ("GB", "Scotland"),
+    ("GB", "Wales"),
+    ("GB", "Northern Ireland"),
+    ("HK", "Hong Kong"),
+    ("IR", "Iran"),
+    ("KP", "North Korea"),
+    ("KP", "DPRK"),
+    ("KR", "South Korea"),
+    ("KR", "Republic of Korea"),
+    ("LA", "Laos"),
+    ("MD", "Moldova"),
+    ("MK", "Macedonia"),
+    ("MM", "Burma"),
+    ("MO", "Macau"),
+    ("NL", "Holland"),
+    ("PS", "Palestine"),
+    ("RU", "Russia"),
+    ("SY", "Syria"),
+    ("SZ", "Swaziland"),
+    ("TL", "East Timor"),
+    ("TR", "Turkey"),
+    ("TW", "Taiwan"),
+    ("TZ", "Tanzania"),
+    ("US", "United States"),
+    ("US", "USA"),
+    ("US", "America"),
+    ("VA", "Vatican"),
+    ("VA", "Vatican City"),
+    ("VE", "Venezuela"),
+    ("VG", "British Virgin Islands"),
+    ("VI", "US Virgin Islands"),
+    ("VN", "Vietnam"),
+];
+
+/// The kinds of names in the index, in order of preference.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Kind {
+    Short,
+    Common,
+    Formal,
+    Alias,
+}
+
+impl Kind {
+    fn ident(self) -> syn::Ident {
+        match self {
+            Kind::Short => quote::format_ident!("Short"),
+            Kind::Common => quote::format_ident!("Common"),
+            Kind::Formal => quote::format_ident!("Formal"),
+            Kind::Alias => quote::format_ident!("Alias"),
+        }
+    }
+}
+
+/// Transliterate a character into ASCII, if it is in [`TRANSLITERATIONS`].
+pub(crate) fn transliterate(c: char) -> Option<&'static str> {
+    TRANSLITERATIONS
+        .iter()
+        .find(|(from, _)| *from == c)
+        .map(|(_, to)| *to)
+}
+
+/// Transliterate a name into ASCII, replacing any character without a transliteration with `?`.
+pub(crate) fn ascii(name: &str) -> String {
+    name.chars()
+        .map(|c| match transliterate(c) {
+            Some(ascii) => ascii.to_owned(),
+            None if c.is_ascii() => c.to_string(),
+            None => "?".to_owned(),
+        })
+        .collect()
+}
+
+/// Generate `Alpha2::formal_name()` from the `iso-codes` formal names.
+pub(crate) fn formal(
+    config: &Config,
+    data: &[Country],
+    iso_codes: &[iso_codes::Record],
+) -> TokenStream {
+    let mut ident = Vec::new();
+    let mut formal = Vec::new();
+
+    for country in data {
+        let Some(name) = iso_codes
+            .iter()
+            .find(|record| record.alpha_2 == country.alpha2_str())
+            .and_then(|record| record.official_name.as_deref())
+        else {
+            continue;
+        };
+
+        ident.push(config.ident(country));
+        formal.push(name);
+    }
+
+    let example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "assert_eq!(",
+        "    Some(\"Democratic People's Republic of Korea\"),",
+        "    Alpha2::NorthKorea.formal_name()",
+        ");",
+        "assert_eq!(None, Alpha2::Canada.formal_name());",
+    ]);
+
+    quote::quote! {
+        impl Alpha2 {
+            /// Get the ISO formal name of the country, e.g. `Democratic People's Republic of
+            /// Korea`, if `iso-codes` has one which differs from its short name.
+            #example
+            pub const fn formal_name(&self) -> Option<&'static str> {
+                match self {
+                    #(
+                        Self::#ident => Some(#formal),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// Normalize a name for comparison.
+///
+/// This must match `normalize()` in `iso3166-static`: names are split into words on anything
+/// other than letters and digits, stop words are dropped, `St` is expanded to `saint`, and the
+/// remaining words are transliterated, lowercased, and joined without spaces.
+pub(crate) fn normalize(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| {
+            !word.is_empty()
+                && !STOP_WORDS
+                    .iter()
+                    .any(|stop| word.eq_ignore_ascii_case(stop))
+        })
+        .flat_map(|word| {
+            let word = if word.eq_ignore_ascii_case("st") {
+                "saint"
+            } else {
+                word
+            };
+
+            word.chars().flat_map(|c| match transliterate(c) {
+                Some(ascii) => ascii.to_ascii_lowercase().chars().collect::<Vec<_>>(),
+                None => c.to_lowercase().collect(),
+            })
+        })
+        .collect()
+}
+
+/// Generate the transliteration function and stop words used by the name index and its users.
+fn normalization() -> TokenStream {
+    let from = TRANSLITERATIONS.iter().map(|(from, _)| from);
+    let to = TRANSLITERATIONS.iter().map(|(_, to)| to);
+    let stop_words = STOP_WORDS.iter();
+
+    quote::quote! {
+        /// Words which are ignored when comparing names, e.g. `The Gambia` and `Gambia`.
+        pub const STOP_WORDS: &[&str] = &[#(#stop_words),*];
+
+        /// Transliterate a Latin letter with diacritics, or typographic punctuation, into ASCII,
+        /// preserving case.
+        pub const fn transliterate(c: char) -> Option<&'static str> {
+            match c {
+                #(
+                    #from => Some(#to),
+                )*
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Generate the name index from the countries' names, the English M49 names, the `iso-codes`
+/// names, and [`ALIASES`].
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    m49: Option<&BTreeMap<u16, Names>>,
+    iso_codes: &[iso_codes::Record],
+) -> TokenStream {
+    // The best kind of each (key, country), with the name it was found under.
+    let mut index = BTreeMap::<(String, usize), (Kind, String)>::new();
+    let mut insert = |position: usize, name: &str, kind: Kind| {
+        let key = normalize(name);
+        if key.is_empty() {
+            return;
+        }
+
+        index
+            .entry((key, position))
+            .and_modify(|existing| {
+                if kind < existing.0 {
+                    *existing = (kind, name.to_owned());
+                }
+            })
+            .or_insert_with(|| (kind, name.to_owned()));
+    };
+
+    for (position, country) in data.iter().enumerate() {
+        insert(position, &country.name, Kind::Short);
+
+        if let Some(name) = m49
+            .and_then(|names| names.get(&country.numeric))
+            .and_then(|names| names.get(Language::English))
+        {
+            insert(position, name, Kind::Short);
+        }
+
+        for record in iso_codes
+            .iter()
+            .filter(|record| record.alpha_2 == country.alpha2_str())
+        {
+            insert(position, &record.name, Kind::Short);
+            if let Some(name) = &record.common_name {
+                insert(position, name, Kind::Common);
+            }
+            if let Some(name) = &record.official_name {
+                insert(position, name, Kind::Formal);
+            }
+        }
+
+        for (_, alias) in ALIASES
+            .iter()
+            .filter(|(alpha2, _)| *alpha2 == country.alpha2_str())
+        {
+            insert(position, alias, Kind::Alias);
+        }
+    }
+
+    let mut key = Vec::new();
+    let mut name = Vec::new();
+    let mut ident = Vec::new();
+    let mut kind = Vec::new();
+
+    for ((k, position), (nk, n)) in index {
+        key.push(k);
+        name.push(n);
+        ident.push(config.ident(&data[position]));
+        kind.push(nk.ident());
+    }
+
+    let normalization = normalization();
+
+    quote::quote! {
+        /// The kinds of names in the [`NAME_INDEX`], in order of preference.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[non_exhaustive]
+        pub enum NameKind {
+            /// The ISO or UN English short name, e.g. `Korea, Republic of`.
+            Short,
+            /// The commonly used name, e.g. `South Korea`.
+            Common,
+            /// The ISO formal name, e.g. `United States of America`.
+            Formal,
+            /// A common alias or abbreviation, e.g. `Ivory Coast` or `UK`.
+            Alias,
+        }
+
+        /// A name of a country in the [`NAME_INDEX`].
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct IndexedName {
+            /// The normalized form of the name, which the index is sorted by.
+            pub key: &'static str,
+            /// The name, as written in the source data.
+            pub name: &'static str,
+            /// The country the name refers to.
+            pub country: Alpha2,
+            /// The kind of name.
+            pub kind: NameKind,
+        }
+
+        /// The names of every country, sorted by their normalized form.
+        pub const NAME_INDEX: &[IndexedName] = &[
+            #(
+                IndexedName {
+                    key: #key,
+                    name: #name,
+                    country: Alpha2::#ident,
+                    kind: NameKind::#kind,
+                },
+            )*
+        ];
+
+        #normalization
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ascii, normalize};
+
+    #[test]
+    fn normalized() {
+        for (input, expected) in [
+            ("UNITED STATES", "unitedstates"),
+            ("U.S.A.", "usa"),
+            ("Côte d’Ivoire", "cotedivoire"),
+            ("Cote d'Ivoire", "cotedivoire"),
+            ("The Bahamas", "bahamas"),
+            ("Bosnia & Herzegovina", "bosniaherzegovina"),
+            ("St. Lucia", "saintlucia"),
+            ("Åland", "aland"),
+            ("Россия", "россия"),
+        ] {
+            assert_eq!(expected, normalize(input), "{input}");
+        }
+    }
+
+    #[test]
+    fn ascii_names() {
+        assert_eq!("Cote d'Ivoire", ascii("Côte d’Ivoire"));
+        assert_eq!("Aland Islands", ascii("Åland Islands"));
+        assert_eq!("Turkiye", ascii("Türkiye"));
+        assert_eq!("?", ascii("中"));
+    }
+}
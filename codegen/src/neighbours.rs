@@ -0,0 +1,101 @@
+//! Code generation for the land and maritime neighbours of each country.
+
+use crate::{Error, lukes::Config};
+use iso3166_parsers::{
+    lukes::Country,
+    neighbours::{Record, find_asymmetric},
+};
+use proc_macro2::{Ident, TokenStream};
+
+/// Resolve the neighbour codes which are in `data`, in the order given.
+fn idents(config: &Config, data: &[Country], codes: &[String]) -> Vec<Ident> {
+    codes
+        .iter()
+        .filter_map(|code| data.iter().find(|c| c.alpha2_str() == code))
+        .map(|c| config.ident(c))
+        .collect()
+}
+
+/// Generate `Alpha2::land_neighbours()` and `Alpha2::maritime_neighbours()` from the given
+/// records.
+///
+/// Records and neighbours for countries which are not in `data` are left out.
+pub(crate) fn generate(
+    config: &Config,
+    data: &[Country],
+    records: &[Record],
+) -> Result<TokenStream, Error> {
+    if let Some(record) = find_asymmetric(records) {
+        return Err(Error::InvalidNeighbours(record.alpha2.clone()));
+    }
+
+    let mut land_country = Vec::new();
+    let mut land = Vec::new();
+    let mut maritime_country = Vec::new();
+    let mut maritime = Vec::new();
+
+    for c in data {
+        let Some(record) = records
+            .iter()
+            .find(|record| record.alpha2 == c.alpha2_str())
+        else {
+            continue;
+        };
+
+        let own = idents(config, data, &record.land);
+        if !own.is_empty() {
+            land_country.push(config.ident(c));
+            land.push(quote::quote!(&[#(Self::#own),*]));
+        }
+
+        let own = idents(config, data, &record.maritime);
+        if !own.is_empty() {
+            maritime_country.push(config.ident(c));
+            maritime.push(quote::quote!(&[#(Self::#own),*]));
+        }
+    }
+
+    let land_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "assert_eq!(&[Alpha2::SouthAfrica], Alpha2::Lesotho.land_neighbours());",
+        "assert!(Alpha2::Iceland.land_neighbours().is_empty());",
+    ]);
+    let maritime_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "assert!(Alpha2::Iceland.maritime_neighbours().contains(&Alpha2::Greenland));",
+        "assert!(Alpha2::Lesotho.maritime_neighbours().is_empty());",
+    ]);
+
+    Ok(quote::quote! {
+        impl Alpha2 {
+            /// Get the countries which share a land border with the country, in alphabetical
+            /// order of their alpha-2 codes.
+            ///
+            /// Territories with codes of their own are neighbours in their own right, so Spain
+            /// borders Gibraltar rather than the United Kingdom.
+            #land_example
+            pub const fn land_neighbours(&self) -> &'static [Alpha2] {
+                match self {
+                    #(
+                        Self::#land_country => #land,
+                    )*
+                    _ => &[],
+                }
+            }
+
+            /// Get the countries which share a maritime boundary with the country, whether or
+            /// not the boundary has been agreed, in alphabetical order of their alpha-2 codes.
+            #maritime_example
+            pub const fn maritime_neighbours(&self) -> &'static [Alpha2] {
+                match self {
+                    #(
+                        Self::#maritime_country => #maritime,
+                    )*
+                    _ => &[],
+                }
+            }
+        }
+    })
+}
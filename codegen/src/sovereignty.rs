@@ -0,0 +1,115 @@
+//! Code generation for the sovereignty of each country.
+
+use crate::lukes::Config;
+use iso3166_parsers::{lukes::Country, sovereignty::Record};
+use proc_macro2::TokenStream;
+
+/// Generate `sovereign()`, `dependencies()` and `is_independent()` for each of the country types.
+pub(crate) fn generate(config: &Config, data: &[Country], records: &[Record]) -> TokenStream {
+    let record = |country: &Country| {
+        records
+            .iter()
+            .find(|record| record.alpha2 == country.alpha2_str())
+    };
+    let country = |alpha2: &str| data.iter().find(|country| country.alpha2_str() == alpha2);
+
+    let mut independent = Vec::new();
+    let mut dependency = Vec::new();
+    let mut sovereign = Vec::new();
+
+    for c in data {
+        let Some(record) = record(c) else {
+            continue;
+        };
+
+        if record.independent {
+            independent.push(config.ident(c));
+        }
+
+        // Sovereigns which were filtered out of the generated data are left out.
+        if let Some(s) = record.sovereign.as_deref().and_then(country) {
+            dependency.push(config.ident(c));
+            sovereign.push(config.ident(s));
+        }
+    }
+
+    let mut sovereigns = Vec::new();
+    let mut dependencies = Vec::new();
+    for s in data {
+        let deps = data
+            .iter()
+            .filter(|d| record(d).and_then(|r| r.sovereign.as_deref()) == Some(s.alpha2_str()))
+            .map(|d| config.ident(d))
+            .collect::<Vec<_>>();
+
+        if !deps.is_empty() {
+            sovereigns.push(config.ident(s));
+            dependencies.push(quote::quote!(&[#(Self::#deps),*]));
+        }
+    }
+
+    let mut retval = TokenStream::new();
+    for ty in ["Numeric", "Alpha2", "Alpha3"] {
+        let ty = quote::format_ident!("{ty}");
+        let (sovereign_example, dependencies_example) = if ty == "Alpha2" {
+            (
+                config.example(&[
+                    "use iso3166_static::Alpha2;",
+                    "",
+                    "assert_eq!(Some(Alpha2::UnitedStatesOfAmerica), Alpha2::PuertoRico.sovereign());",
+                    "assert_eq!(None, Alpha2::Antarctica.sovereign());",
+                    "assert_eq!(None, Alpha2::Germany.sovereign());",
+                ]),
+                config.example(&[
+                    "use iso3166_static::Alpha2;",
+                    "",
+                    "assert!(Alpha2::Denmark.dependencies().contains(&Alpha2::Greenland));",
+                    "assert!(Alpha2::Germany.dependencies().is_empty());",
+                ]),
+            )
+        } else {
+            (TokenStream::new(), TokenStream::new())
+        };
+
+        retval.extend(quote::quote! {
+            impl #ty {
+                /// Get the country which administers this one, e.g. the United States for Puerto
+                /// Rico, if it is not independent and has a single sovereign.
+                #sovereign_example
+                pub const fn sovereign(&self) -> Option<Self> {
+                    match self {
+                        #(
+                            Self::#dependency => Some(Self::#sovereign),
+                        )*
+                        _ => None,
+                    }
+                }
+
+                /// Get the countries administered by this one, in numeric order.
+                #dependencies_example
+                pub const fn dependencies(&self) -> &'static [Self] {
+                    match self {
+                        #(
+                            Self::#sovereigns => #dependencies,
+                        )*
+                        _ => &[],
+                    }
+                }
+
+                /// Determine whether ISO considers the country independent.
+                ///
+                /// User-assigned codes are never independent.
+                pub const fn is_independent(&self) -> bool {
+                    match self {
+                        #(
+                            Self::#independent => true,
+                        )*
+                        _ => false,
+                    }
+                }
+            }
+        });
+    }
+
+    retval
+}
@@ -0,0 +1,85 @@
+//! Code generation for the IANA time zones of each country.
+
+use crate::lukes::Config;
+use iso3166_parsers::{lukes::Country, tzdata::Zone};
+use proc_macro2::TokenStream;
+
+/// Generate `Alpha2::time_zones()` and `country_for_zone()` from the given zones.
+///
+/// Zones in countries which are not in `data` are left out.
+pub(crate) fn generate(config: &Config, data: &[Country], zones: &[Zone]) -> TokenStream {
+    let mut country = Vec::new();
+    let mut names = Vec::new();
+    let mut zone = Vec::new();
+    let mut zone_country = Vec::new();
+
+    for c in data {
+        let own = zones
+            .iter()
+            .filter(|zone| zone.countries.iter().any(|alpha2| alpha2 == c.alpha2_str()))
+            .map(|zone| zone.name.as_str())
+            .collect::<Vec<_>>();
+        if own.is_empty() {
+            continue;
+        }
+
+        country.push(config.ident(c));
+        names.push(quote::quote!(&[#(#own),*]));
+    }
+
+    for z in zones {
+        let Some(c) = z
+            .countries
+            .first()
+            .and_then(|alpha2| data.iter().find(|c| c.alpha2_str() == alpha2))
+        else {
+            continue;
+        };
+
+        zone.push(z.name.as_str());
+        zone_country.push(config.ident(c));
+    }
+
+    let time_zones_example = config.example(&[
+        "use iso3166_static::Alpha2;",
+        "",
+        "assert_eq!(&[\"Europe/Berlin\", \"Europe/Busingen\"], Alpha2::Germany.time_zones());",
+        "assert!(Alpha2::Antarctica.time_zones().contains(&\"Antarctica/McMurdo\"));",
+    ]);
+    let country_for_zone_example = config.example(&[
+        "use iso3166_static::{Alpha2, country_for_zone};",
+        "",
+        "assert_eq!(Some(Alpha2::Germany), country_for_zone(\"Europe/Berlin\"));",
+        "assert_eq!(None, country_for_zone(\"Etc/UTC\"));",
+    ]);
+
+    quote::quote! {
+        impl Alpha2 {
+            /// Get the IANA time zones of the country, e.g. `Europe/Berlin`, with the zone of
+            /// the most populous region first.
+            #time_zones_example
+            pub const fn time_zones(&self) -> &'static [&'static str] {
+                match self {
+                    #(
+                        Self::#country => #names,
+                    )*
+                    _ => &[],
+                }
+            }
+        }
+
+        /// Find the country an IANA time zone is in.
+        ///
+        /// Only canonical zone names which are specific to a country are known, so aliases such as
+        /// `US/Eastern` and zones such as `Etc/UTC` return `None`.
+        #country_for_zone_example
+        pub fn country_for_zone(zone: &str) -> Option<Alpha2> {
+            match zone {
+                #(
+                    #zone => Some(Alpha2::#zone_country),
+                )*
+                _ => None,
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Lookups against the dataset bundled with `iso3166-parsers`.
+
+use iso3166_codegen::{Builder, Source, Variant};
+use std::sync::OnceLock;
+
+/// The bundled countries, resolved with the same naming as `iso3166-static`.
+pub(crate) fn variants() -> Result<&'static [Variant], String> {
+    static VARIANTS: OnceLock<Result<Vec<Variant>, String>> = OnceLock::new();
+
+    VARIANTS
+        .get_or_init(|| {
+            Builder::new()
+                .source(Source::Snapshot)
+                .variants()
+                .map_err(|error| format!("Could not load the bundled ISO 3166 data: {error}"))
+        })
+        .as_deref()
+        .map_err(Clone::clone)
+}
+
+/// Find the country with the given alpha-2 code.
+pub(crate) fn by_alpha2(code: &str) -> Result<Option<&'static Variant>, String> {
+    Ok(variants()?.iter().find(|variant| variant.alpha2 == code))
+}
+
+/// Find the country with the given alpha-3 code.
+pub(crate) fn by_alpha3(code: &str) -> Result<Option<&'static Variant>, String> {
+    Ok(variants()?.iter().find(|variant| variant.alpha3 == code))
+}
+
+/// Find the country with the given numeric code.
+pub(crate) fn by_numeric(code: u16) -> Result<Option<&'static Variant>, String> {
+    Ok(variants()?.iter().find(|variant| variant.numeric == code))
+}
+
+/// Find the country with the given `iso3166-static` variant name.
+pub(crate) fn by_ident(ident: &str) -> Result<Option<&'static Variant>, String> {
+    Ok(variants()?.iter().find(|variant| variant.ident == ident))
+}
@@ -1,11 +1,175 @@
 //! Procedural Macros for generating ISO 3166 enumerations and structures
 
+mod countries;
+mod literal;
 mod lukes;
+mod subset;
 
+use literal::Kind;
 use proc_macro::TokenStream;
 
 /// Generate the relevant types from the provided source data.
+///
+/// # Arguments
+///
+/// - `lukes_json`: The path to Luke Duncalfe's `all.json`, relative to the crate's `src` directory.
+/// - `lukes_format`: The upstream file name of the format `lukes_json` is in, e.g. `all.csv` or
+///   `all.xml`, defaults to `all.json`. The `slim-2` and `slim-3` formats lack an alpha code, and
+///   are rejected.
+/// - `data_date`: The date the data was retrieved from upstream, in `YYYY-MM-DD` form. When it
+///   is not given, `DATA_DATE` is `"unknown"`.
+/// - `include_m49`: Generate `Language` and `Alpha2::name_in()` using the English M49 table
+///   bundled with `iso3166-parsers`.
+/// - `m49_ar`, `m49_en`, `m49_es`, `m49_fr`, `m49_ru`, `m49_zh`: The path to the UN's M49 CSV
+///   file in the given language, relative to the crate's `src` directory, used for
+///   `Alpha2::name_in()`.
+/// - `name_index`: Generate `NameKind`, `IndexedName`, `NAME_INDEX` and `transliterate()`, used
+///   for looking up countries by name.
+/// - `include_iso_codes`: Generate `Alpha2::formal_name()`, and add the formal and common names
+///   to the name index, using the snapshot of Debian's `iso-codes` bundled with
+///   `iso3166-parsers`.
+/// - `iso_codes`: The path to a copy of `iso_3166-1.json` from Debian's `iso-codes`, relative to
+///   the crate's `src` directory, used instead of the bundled snapshot.
+/// - `include_sovereignty`: Generate `sovereign()`, `dependencies()` and `is_independent()` using
+///   the sovereignty table bundled with `iso3166-parsers`.
+/// - `sovereignty`: The path to a sovereignty CSV file, relative to the crate's `src` directory,
+///   used instead of the bundled table.
+/// - `include_groups`: Generate `Group`, `Date`, `Membership` and `Alpha2::is_member_of()`
+///   using the group memberships bundled with `iso3166-parsers`.
+/// - `groups`: The path to a groups JSON file, relative to the crate's `src` directory, used
+///   instead of the bundled memberships.
+/// - `include_currencies`: Generate `Currency`, `Alpha2::currencies()` and `Currency::countries()`
+///   using the ISO 4217 list of current currencies bundled with `iso3166-parsers`.
+/// - `currencies`: The path to a copy of SIX's `list-one.xml`, relative to the crate's `src`
+///   directory, used instead of the bundled list.
+/// - `include_withdrawn_currencies`: Add the withdrawn currencies bundled with `iso3166-parsers`,
+///   used for `Alpha2::former_currencies()`.
+/// - `withdrawn_currencies`: The path to a copy of SIX's `list-three.xml`, relative to the
+///   crate's `src` directory, used instead of the bundled list.
+/// - `include_calling_codes`: Generate `CallingPrefix`, `CALLING_PREFIXES` and
+///   `Alpha2::calling_codes()` using the calling code table bundled with `iso3166-parsers`.
+/// - `calling_codes`: The path to a calling code CSV file, relative to the crate's `src`
+///   directory, used instead of the bundled table.
+/// - `include_public_suffix_list`: Generate `Cctld`, `CCTLDS` and `Alpha2::cctlds()` using the
+///   country code top-level domains of the Public Suffix List bundled with `iso3166-parsers`.
+/// - `public_suffix_list`: The path to a copy of Mozilla's `public_suffix_list.dat`, relative to
+///   the crate's `src` directory, used instead of the bundled list.
+/// - `include_territory_info`: Generate `OfficialStatus`, `CountryLanguage` and
+///   `Alpha2::languages()` using the language populations bundled with `iso3166-parsers`.
+/// - `territory_info`: The path to a copy of CLDR's `territoryInfo.json`, relative to the crate's
+///   `src` directory, used instead of the bundled file.
+/// - `include_time_zones`: Generate `Alpha2::time_zones()` and `country_for_zone()` using the
+///   tzdata `zone.tab` bundled with `iso3166-parsers`.
+/// - `time_zones`: The path to a copy of tzdata's `zone.tab` or `zone1970.tab`, relative to the
+///   crate's `src` directory, used instead of the bundled file.
+/// - `include_geography`: Generate `Coordinates`, `BoundingBox`, `Capital`, `Alpha2::capital()`,
+///   `Alpha2::centroid()` and `Alpha2::bounding_box()` using the geographic metadata bundled with
+///   `iso3166-parsers`.
+/// - `geography`: The path to a geographic metadata CSV file, relative to the crate's `src`
+///   directory, used instead of the bundled table.
+/// - `include_neighbours`: Generate `Alpha2::land_neighbours()` and
+///   `Alpha2::maritime_neighbours()` using the neighbour table bundled with `iso3166-parsers`.
+/// - `neighbours`: The path to a neighbour CSV file, relative to the crate's `src` directory, used
+///   instead of the bundled table.
+/// - `include_iban_registry`: Generate `IbanFormat`, `IBAN_FORMATS` and `Alpha2::iban_format()`
+///   using the IBAN registry bundled with `iso3166-parsers`.
+/// - `iban_registry`: The path to a SWIFT IBAN Registry text file, relative to the crate's `src`
+///   directory, used instead of the bundled registry.
+/// - `data_source`: An optional description of where the data came from, defaults to the upstream
+///   repository URL.
 #[proc_macro]
 pub fn generate(input: TokenStream) -> TokenStream {
     lukes::generate(input.into()).into()
 }
+
+/// Derive conversions between an application-defined enum of countries and the `iso3166-static`
+/// types.
+///
+/// Each variant must be a unit variant, and is matched to a country by its name: either an alpha-2
+/// code (`DE`), an alpha-3 code (`DEU`), or the name of the `iso3166-static` variant (`Germany`).
+/// The `#[iso3166("DE")]` attribute can be used to give the alpha-2 or alpha-3 code explicitly.
+/// Unknown or user-assigned codes are rejected at compile time.
+///
+/// The derive generates infallible `From` conversions into `Alpha2`, `Alpha3` and `Numeric`,
+/// fallible `TryFrom` conversions back, and `FromStr`, which accepts alpha-2 and alpha-3 codes.
+/// Adding `#[iso3166(serde)]` to the enum also generates `serde` implementations using the alpha-2
+/// code, which requires the `serde` feature of `iso3166-static`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use iso3166_macros::Iso3166Subset;
+/// use iso3166_static::Alpha2;
+///
+/// #[derive(Clone, Copy, Debug, Iso3166Subset)]
+/// #[iso3166(serde)]
+/// enum Market {
+///     Germany,
+///     FR,
+///     #[iso3166("US")]
+///     America,
+/// }
+///
+/// assert_eq!(Alpha2::UnitedStatesOfAmerica, Alpha2::from(Market::America));
+/// ```
+#[proc_macro_derive(Iso3166Subset, attributes(iso3166))]
+pub fn derive_iso3166_subset(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    subset::derive(&input).into()
+}
+
+/// Create an `iso3166_static::Alpha2` value from a string literal, checked at compile time.
+///
+/// Multiple codes separated by `|` expand to an or-pattern, for use in `match` arms.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use iso3166_macros::alpha2;
+/// use iso3166_static::Alpha2;
+///
+/// assert_eq!(Alpha2::UnitedStatesOfAmerica, alpha2!("US"));
+///
+/// match alpha2!("CA") {
+///     alpha2!("CA" | "MX" | "US") => {}
+///     _ => unreachable!(),
+/// }
+/// ```
+#[proc_macro]
+pub fn alpha2(input: TokenStream) -> TokenStream {
+    literal::expand(Kind::Alpha2, input.into()).into()
+}
+
+/// Create an `iso3166_static::Alpha3` value from a string literal, checked at compile time.
+///
+/// Multiple codes separated by `|` expand to an or-pattern, for use in `match` arms.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use iso3166_macros::alpha3;
+/// use iso3166_static::Alpha3;
+///
+/// assert_eq!(Alpha3::UnitedStatesOfAmerica, alpha3!("USA"));
+/// ```
+#[proc_macro]
+pub fn alpha3(input: TokenStream) -> TokenStream {
+    literal::expand(Kind::Alpha3, input.into()).into()
+}
+
+/// Create an `iso3166_static::Numeric` value from an integer literal, checked at compile time.
+///
+/// Multiple codes separated by `|` expand to an or-pattern, for use in `match` arms.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use iso3166_macros::numeric;
+/// use iso3166_static::Numeric;
+///
+/// assert_eq!(Numeric::UnitedStatesOfAmerica, numeric!(840));
+/// ```
+#[proc_macro]
+pub fn numeric(input: TokenStream) -> TokenStream {
+    literal::expand(Kind::Numeric, input.into()).into()
+}
@@ -0,0 +1,140 @@
+//! Compile-time validated code literals.
+
+use crate::countries;
+use iso3166_codegen::{
+    Variant, is_user_assigned_alpha2, is_user_assigned_alpha3, is_user_assigned_numeric,
+};
+use proc_macro2::{Span, TokenStream};
+use syn::{Error, Lit, Result, Token, parse::Parser, punctuated::Punctuated};
+
+/// The kind of code being parsed.
+#[derive(Clone, Copy)]
+pub(crate) enum Kind {
+    Alpha2,
+    Alpha3,
+    Numeric,
+}
+
+impl Kind {
+    /// Resolve a single literal to the name of the variant it refers to.
+    fn variant(self, lit: &Lit) -> Result<String> {
+        let span = lit.span();
+        let lookup = |found: std::result::Result<Option<&Variant>, String>| {
+            found
+                .map(|variant| variant.map(|variant| variant.ident.clone()))
+                .map_err(|message| Error::new(span, message))
+        };
+
+        let (code, found, user) = match (self, lit) {
+            (Kind::Alpha2, Lit::Str(lit)) => {
+                let code = lit.value();
+                let found = lookup(countries::by_alpha2(&code))?;
+                let user = is_user_assigned_alpha2(&code);
+                (code, found, user)
+            }
+            (Kind::Alpha3, Lit::Str(lit)) => {
+                let code = lit.value();
+                let found = lookup(countries::by_alpha3(&code))?;
+                let user = is_user_assigned_alpha3(&code);
+                (code, found, user)
+            }
+            (Kind::Numeric, Lit::Int(lit)) => {
+                let value = lit.base10_parse::<u16>()?;
+                let found = lookup(countries::by_numeric(value))?;
+                (value.to_string(), found, is_user_assigned_numeric(value))
+            }
+            (Kind::Numeric, _) => {
+                return Err(Error::new(span, "Expected an integer literal, e.g. `840`"));
+            }
+            (_, _) => return Err(Error::new(span, "Expected a string literal, e.g. `\"US\"`")),
+        };
+
+        match (found, user) {
+            (Some(ident), _) => Ok(ident),
+            (None, true) => Ok(format!("User{code}")),
+            (None, false) => Err(Error::new(span, self.unknown(&code))),
+        }
+    }
+
+    fn unknown(self, code: &str) -> String {
+        let kind = match self {
+            Kind::Alpha2 => "alpha-2",
+            Kind::Alpha3 => "alpha-3",
+            Kind::Numeric => "numeric",
+        };
+
+        format!("`{code}` is not a known ISO 3166-1 {kind} code")
+    }
+
+    fn type_ident(self) -> syn::Ident {
+        let name = match self {
+            Kind::Alpha2 => "Alpha2",
+            Kind::Alpha3 => "Alpha3",
+            Kind::Numeric => "Numeric",
+        };
+
+        syn::Ident::new(name, Span::call_site())
+    }
+}
+
+fn try_expand(kind: Kind, tokens: TokenStream) -> Result<TokenStream> {
+    let lits = Punctuated::<Lit, Token![|]>::parse_separated_nonempty.parse2(tokens)?;
+    let ty = kind.type_ident();
+    let variants = lits
+        .iter()
+        .map(|lit| {
+            kind.variant(lit)
+                .map(|name| syn::Ident::new(&name, lit.span()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote::quote! {
+        #( ::iso3166_static::#ty::#variants )|*
+    })
+}
+
+pub(crate) fn expand(kind: Kind, tokens: TokenStream) -> TokenStream {
+    try_expand(kind, tokens).unwrap_or_else(Error::into_compile_error)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Kind, try_expand};
+
+    fn expand(kind: Kind, tokens: proc_macro2::TokenStream) -> Result<String, String> {
+        try_expand(kind, tokens)
+            .map(|tokens| tokens.to_string())
+            .map_err(|error| error.to_string())
+    }
+
+    #[yare::parameterized(
+        alpha2 = { Kind::Alpha2, quote::quote!("US"), ":: iso3166_static :: Alpha2 :: UnitedStatesOfAmerica" },
+        alpha2_user = { Kind::Alpha2, quote::quote!("XK"), ":: iso3166_static :: Alpha2 :: UserXK" },
+        alpha3 = { Kind::Alpha3, quote::quote!("DEU"), ":: iso3166_static :: Alpha3 :: Germany" },
+        alpha3_user = { Kind::Alpha3, quote::quote!("ZZZ"), ":: iso3166_static :: Alpha3 :: UserZZZ" },
+        numeric = { Kind::Numeric, quote::quote!(840), ":: iso3166_static :: Numeric :: UnitedStatesOfAmerica" },
+        numeric_user = { Kind::Numeric, quote::quote!(900), ":: iso3166_static :: Numeric :: User900" },
+        pattern = {
+            Kind::Alpha2,
+            quote::quote!("CA" | "MX"),
+            ":: iso3166_static :: Alpha2 :: Canada | :: iso3166_static :: Alpha2 :: Mexico"
+        },
+    )]
+    fn valid(kind: Kind, input: proc_macro2::TokenStream, expected: &str) {
+        assert_eq!(Ok(expected.to_owned()), expand(kind, input));
+    }
+
+    #[yare::parameterized(
+        alpha2_unknown = { Kind::Alpha2, quote::quote!("QB") },
+        alpha2_lowercase = { Kind::Alpha2, quote::quote!("us") },
+        alpha2_int = { Kind::Alpha2, quote::quote!(840) },
+        alpha3_unknown = { Kind::Alpha3, quote::quote!("BBB") },
+        numeric_unknown = { Kind::Numeric, quote::quote!(123) },
+        numeric_str = { Kind::Numeric, quote::quote!("840") },
+        numeric_overflow = { Kind::Numeric, quote::quote!(65536) },
+        empty = { Kind::Alpha2, quote::quote!() },
+    )]
+    fn invalid(kind: Kind, input: proc_macro2::TokenStream) {
+        assert!(expand(kind, input).is_err());
+    }
+}
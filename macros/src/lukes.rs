@@ -1,18 +1,136 @@
 //! Code generation from the Luke Duncalfe's JSON.
 
-use heck::ToPascalCase;
-use iso3166_parsers::lukes::Record;
+use iso3166_codegen::{Builder, Error as CodegenError, Source};
+use iso3166_parsers::{
+    cldr, e164, geo, groups, iban, iso_codes, iso4217,
+    lukes::Format,
+    m49::{Language, SNAPSHOT as M49_SNAPSHOT},
+    neighbours, psl, sovereignty, tzdata,
+};
 use proc_macro2::{Span, TokenStream};
-use std::{env, fs::File, path::PathBuf};
+use std::{env, fmt::Write as _, path::PathBuf};
 use syn::{
-    Error, Expr, ExprLit, Ident, Lit, LitByteStr, Meta, Result, Token, parse::Parser,
-    punctuated::Punctuated, token::Comma,
+    Error, Expr, ExprLit, Lit, Meta, Result, Token, parse::Parser, punctuated::Punctuated,
+    token::Comma,
 };
 
+/// A supplementary table, which is either included from the snapshot bundled with
+/// `iso3166-parsers` with `include_<name>`, or read from a path relative to the crate's `src`
+/// directory with `<name> = "path"`.
+struct Supplement {
+    name: &'static str,
+    snapshot: &'static str,
+    bytes: fn(Builder, &'static str) -> Builder,
+    file: fn(Builder, PathBuf) -> Builder,
+}
+
+const SUPPLEMENTS: &[Supplement] = &[
+    Supplement {
+        name: "iso_codes",
+        snapshot: iso_codes::SNAPSHOT,
+        bytes: |builder, bytes| builder.iso_codes(bytes),
+        file: |builder, path| builder.iso_codes_file(path),
+    },
+    Supplement {
+        name: "groups",
+        snapshot: groups::SNAPSHOT,
+        bytes: |builder, bytes| builder.groups(bytes),
+        file: |builder, path| builder.groups_file(path),
+    },
+    Supplement {
+        name: "sovereignty",
+        snapshot: sovereignty::SNAPSHOT,
+        bytes: |builder, bytes| builder.sovereignty(bytes),
+        file: |builder, path| builder.sovereignty_file(path),
+    },
+    Supplement {
+        name: "currencies",
+        snapshot: iso4217::LIST_ONE,
+        bytes: |builder, bytes| builder.currencies(bytes),
+        file: |builder, path| builder.currencies_file(path),
+    },
+    Supplement {
+        name: "withdrawn_currencies",
+        snapshot: iso4217::LIST_THREE,
+        bytes: |builder, bytes| builder.withdrawn_currencies(bytes),
+        file: |builder, path| builder.withdrawn_currencies_file(path),
+    },
+    Supplement {
+        name: "calling_codes",
+        snapshot: e164::SNAPSHOT,
+        bytes: |builder, bytes| builder.calling_codes(bytes),
+        file: |builder, path| builder.calling_codes_file(path),
+    },
+    Supplement {
+        name: "public_suffix_list",
+        snapshot: psl::SNAPSHOT,
+        bytes: |builder, bytes| builder.public_suffix_list(bytes),
+        file: |builder, path| builder.public_suffix_list_file(path),
+    },
+    Supplement {
+        name: "territory_info",
+        snapshot: cldr::TERRITORY_INFO,
+        bytes: |builder, bytes| builder.territory_info(bytes),
+        file: |builder, path| builder.territory_info_file(path),
+    },
+    Supplement {
+        name: "time_zones",
+        snapshot: tzdata::ZONE_TAB,
+        bytes: |builder, bytes| builder.time_zones(bytes),
+        file: |builder, path| builder.time_zones_file(path),
+    },
+    Supplement {
+        name: "geography",
+        snapshot: geo::SNAPSHOT,
+        bytes: |builder, bytes| builder.geography(bytes),
+        file: |builder, path| builder.geography_file(path),
+    },
+    Supplement {
+        name: "neighbours",
+        snapshot: neighbours::SNAPSHOT,
+        bytes: |builder, bytes| builder.neighbours(bytes),
+        file: |builder, path| builder.neighbours_file(path),
+    },
+    Supplement {
+        name: "iban_registry",
+        snapshot: iban::SNAPSHOT,
+        bytes: |builder, bytes| builder.iban_registry(bytes),
+        file: |builder, path| builder.iban_registry_file(path),
+    },
+];
+
+impl Supplement {
+    fn find(name: &str) -> Option<&'static Supplement> {
+        SUPPLEMENTS
+            .iter()
+            .find(|supplement| supplement.name == name)
+    }
+
+    /// List the attribute names of every supplement, each with the given prefix, for error
+    /// messages.
+    fn names(prefix: &str) -> String {
+        SUPPLEMENTS
+            .iter()
+            .fold(String::new(), |mut names, supplement| {
+                let _ = write!(names, ", `{prefix}{}`", supplement.name);
+                names
+            })
+    }
+}
+
 struct Config {
     lukes_path: PathBuf,
     lukes_span: Span,
-    _include_m49: bool,
+    lukes_format: Format,
+    lukes_format_span: Option<Span>,
+    data_source: Option<String>,
+    data_date: Option<String>,
+    data_date_span: Option<Span>,
+    include_m49: bool,
+    m49: Vec<(Language, PathBuf)>,
+    name_index: bool,
+    /// The supplementary tables to include, with their paths, or `None` to use the snapshot.
+    supplements: Vec<(&'static Supplement, Option<PathBuf>)>,
 }
 
 impl Config {
@@ -23,6 +141,14 @@ impl Config {
         let mut lukes_path = Option::<PathBuf>::None;
         let mut include_m49 = Option::<bool>::None;
         let mut lukes_span = Option::<Span>::None;
+        let mut lukes_format = Option::<Format>::None;
+        let mut lukes_format_span = Option::<Span>::None;
+        let mut data_source = Option::<String>::None;
+        let mut data_date = Option::<String>::None;
+        let mut data_date_span = Option::<Span>::None;
+        let mut m49 = Vec::<(Language, PathBuf)>::new();
+        let mut name_index = Option::<bool>::None;
+        let mut supplements = Vec::<(&'static Supplement, Option<PathBuf>)>::new();
 
         for arg in args {
             match arg {
@@ -45,9 +171,37 @@ impl Config {
 
                             include_m49 = Some(true);
                         }
+                        "name_index" => {
+                            if name_index.is_some() {
+                                return Err(Error::new_spanned(
+                                    tokens,
+                                    "`name_index` is set multiple times",
+                                ));
+                            }
+
+                            name_index = Some(true);
+                        }
+                        name if name
+                            .strip_prefix("include_")
+                            .and_then(Supplement::find)
+                            .is_some() =>
+                        {
+                            let supplement = Supplement::find(&name[8..]).expect("supplement");
+                            if supplements
+                                .iter()
+                                .any(|(existing, _)| existing.name == supplement.name)
+                            {
+                                let message =
+                                    format!("`{}` is set multiple times", supplement.name);
+                                return Err(Error::new_spanned(tokens, message));
+                            }
+
+                            supplements.push((supplement, None));
+                        }
                         name => {
+                            let supplements = Supplement::names("include_");
                             let message = format!(
-                                "Unknown attribute {name} is specified; expected one of: `include_m49`."
+                                "Unknown attribute {name} is specified; expected one of: `include_m49`, `name_index`{supplements}"
                             );
                             return Err(Error::new_spanned(tokens, message));
                         }
@@ -98,6 +252,70 @@ impl Config {
                                 }
                             }
                         }
+                        "lukes_format" => {
+                            if lukes_format.is_some() {
+                                return Err(Error::new_spanned(
+                                    tokens,
+                                    "`lukes_format` is set twice",
+                                ));
+                            }
+
+                            match lit {
+                                Lit::Str(lit_str) => {
+                                    let format = lit_str
+                                        .value()
+                                        .parse::<Format>()
+                                        .map_err(|error| Error::new(lit_str.span(), error))?;
+
+                                    lukes_format = Some(format);
+                                    lukes_format_span = Some(lit_str.span());
+                                }
+                                val => {
+                                    return Err(Error::new_spanned(
+                                        val,
+                                        "`lukes_format` must be a static string naming an upstream file",
+                                    ));
+                                }
+                            }
+                        }
+                        "data_source" => {
+                            if data_source.is_some() {
+                                return Err(Error::new_spanned(
+                                    tokens,
+                                    "`data_source` is set twice",
+                                ));
+                            }
+
+                            match lit {
+                                Lit::Str(lit_str) => {
+                                    data_source = Some(lit_str.value());
+                                }
+                                val => {
+                                    return Err(Error::new_spanned(
+                                        val,
+                                        "`data_source` must be a static string",
+                                    ));
+                                }
+                            }
+                        }
+                        "data_date" => {
+                            if data_date.is_some() {
+                                return Err(Error::new_spanned(tokens, "`data_date` is set twice"));
+                            }
+
+                            match lit {
+                                Lit::Str(lit_str) => {
+                                    data_date = Some(lit_str.value());
+                                    data_date_span = Some(lit_str.span());
+                                }
+                                val => {
+                                    return Err(Error::new_spanned(
+                                        val,
+                                        "`data_date` must be a static string in `YYYY-MM-DD` form",
+                                    ));
+                                }
+                            }
+                        }
                         "include_m49" => {
                             if include_m49.is_some() {
                                 return Err(Error::new_spanned(
@@ -118,9 +336,112 @@ impl Config {
                                 }
                             }
                         }
+                        "name_index" => {
+                            if name_index.is_some() {
+                                return Err(Error::new_spanned(
+                                    tokens,
+                                    "`name_index` is set multiple times",
+                                ));
+                            }
+
+                            match lit {
+                                Lit::Bool(lit_bool) => {
+                                    name_index = Some(lit_bool.value());
+                                }
+                                val => {
+                                    return Err(Error::new_spanned(
+                                        val,
+                                        "`name_index` must be a boolean when set.",
+                                    ));
+                                }
+                            }
+                        }
+                        name if name
+                            .strip_prefix("include_")
+                            .and_then(Supplement::find)
+                            .is_some() =>
+                        {
+                            let supplement = Supplement::find(&name[8..]).expect("supplement");
+                            if supplements
+                                .iter()
+                                .any(|(existing, _)| existing.name == supplement.name)
+                            {
+                                let message =
+                                    format!("`{}` is set multiple times", supplement.name);
+                                return Err(Error::new_spanned(tokens, message));
+                            }
+
+                            match lit {
+                                Lit::Bool(lit_bool) => {
+                                    if lit_bool.value() {
+                                        supplements.push((supplement, None));
+                                    }
+                                }
+                                val => {
+                                    let message = format!("`{name}` must be a boolean when set.");
+                                    return Err(Error::new_spanned(val, message));
+                                }
+                            }
+                        }
+                        name if Supplement::find(name).is_some() => {
+                            let supplement = Supplement::find(name).expect("supplement");
+                            if supplements
+                                .iter()
+                                .any(|(existing, _)| existing.name == supplement.name)
+                            {
+                                let message = format!("`{name}` is set multiple times");
+                                return Err(Error::new_spanned(tokens, message));
+                            }
+
+                            match lit {
+                                Lit::Str(lit_str) => {
+                                    let mut path = PathBuf::from(&manifest_dir);
+                                    path.push("src");
+                                    path.push(lit_str.value());
+
+                                    supplements.push((supplement, Some(path)));
+                                }
+                                val => {
+                                    let message = format!(
+                                        "`{name}` must be a static string containing the path to a data file"
+                                    );
+                                    return Err(Error::new_spanned(val, message));
+                                }
+                            }
+                        }
+                        name if name.starts_with("m49_") => {
+                            let language = Language::from_code(&name[4..]).ok_or_else(|| {
+                                Error::new_spanned(
+                                    &tokens.path,
+                                    "Unknown M49 language; expected one of: `m49_ar`, `m49_en`, `m49_es`, `m49_fr`, `m49_ru`, `m49_zh`",
+                                )
+                            })?;
+
+                            if m49.iter().any(|(existing, _)| *existing == language) {
+                                let message = format!("`{name}` is set twice");
+                                return Err(Error::new_spanned(tokens, message));
+                            }
+
+                            match lit {
+                                Lit::Str(lit_str) => {
+                                    let mut path = PathBuf::from(&manifest_dir);
+                                    path.push("src");
+                                    path.push(lit_str.value());
+
+                                    m49.push((language, path));
+                                }
+                                val => {
+                                    let message = format!(
+                                        "`{name}` must be a static string containing the path to an M49 CSV file"
+                                    );
+                                    return Err(Error::new_spanned(val, message));
+                                }
+                            }
+                        }
                         name => {
+                            let supplements = Supplement::names("");
                             let message = format!(
-                                "Unknown attribute {name} is specified; expected one of: `lukes_json`, `include_m49`",
+                                "Unknown attribute {name} is specified; expected one of: `lukes_json`, `lukes_format`, `data_source`, `data_date`, `m49_ar`, `m49_en`, `m49_es`, `m49_fr`, `m49_ru`, `m49_zh`{supplements}",
                             );
                             return Err(Error::new_spanned(tokens, message));
                         }
@@ -130,665 +451,169 @@ impl Config {
         }
 
         let lukes_path =
-            lukes_path.ok_or_else(|| Error::new_spanned(args, "`lukes_path` was not set"))?;
+            lukes_path.ok_or_else(|| Error::new_spanned(args, "`lukes_json` was not set"))?;
         let lukes_span =
-            lukes_span.ok_or_else(|| Error::new_spanned(args, "`lukes_span` was not set"))?;
+            lukes_span.ok_or_else(|| Error::new_spanned(args, "`lukes_json` was not set"))?;
         let include_m49 = include_m49.unwrap_or_default();
 
         Ok(Config {
             lukes_path,
             lukes_span,
-            _include_m49: include_m49,
+            lukes_format: lukes_format.unwrap_or_default(),
+            lukes_format_span,
+            data_source,
+            data_date,
+            data_date_span,
+            include_m49,
+            m49,
+            name_index: name_index.unwrap_or_default(),
+            supplements,
         })
     }
 }
 
-fn name_to_ident(name: &str) -> Ident {
-    let ident = name
-        .trim()
-        .to_pascal_case()
-        .replace("BoliviaPlurinationalStateOf", "Bolivia")
-        .replace("VirginIslandsBritish", "BritishVirginIslands")
-        .replace("TaiwanProvinceOfChina", "Taiwan")
-        .replace(
-            "CongoDemocraticRepublicOfThe",
-            "DemocraticRepublicOfTheCongo",
-        )
-        .replace("ÅlandIslands", "AlandIslands")
-        .replace("PalestineStateOf", "Palestine")
-        .replace("IranIslamicRepublicOf", "Iran")
-        .replace("CôteDIvoire", "CoteDIvoire")
-        .replace("KoreaDemocraticPeopleSRepublicOf", "NorthKorea")
-        .replace("KoreaRepublicOf", "SouthKorea")
-        .replace("LaoPeopleSDemocraticRepublic", "Laos")
-        .replace("MoldovaRepublicOf", "Moldova")
-        .replace("NetherlandsKingdomOfThe", "Netherlands")
-        .replace("Curaçao", "Curacao")
-        .replace("MicronesiaFederatedStatesOf", "Micronesia")
-        .replace("Réunion", "Reunion")
-        .replace("RussianFederation", "Russia")
-        .replace("SaintBarthélemy", "SaintBarthelemy")
-        .replace("Türkiye", "Turkey")
-        .replace(
-            "UnitedKingdomOfGreatBritainAndNorthernIreland",
-            "UnitedKingdom",
-        )
-        .replace("TanzaniaUnitedRepublicOf", "Tanzania")
-        .replace("VenezuelaBolivarianRepublicOf", "Venezuela")
-        .replace("SyrianArabRepublic", "Syria");
+fn try_generate(tokens: TokenStream) -> Result<TokenStream> {
+    let config = Punctuated::<Meta, Token![,]>::parse_terminated
+        .parse2(tokens)
+        .and_then(|args| Config::build(&args))?;
 
-    quote::format_ident!("{ident}")
-}
+    let path = config.lukes_path.to_str().ok_or_else(|| {
+        Error::new(
+            config.lukes_span,
+            "The data path must be valid UTF-8 to be tracked",
+        )
+    })?;
 
-#[allow(clippy::too_many_lines)]
-fn numeric(config: &Config, data: &[Record]) -> Result<TokenStream> {
-    let mut ident = Vec::new();
-    let mut code = Vec::new();
-    let mut doc = Vec::new();
-    let mut name = Vec::new();
-    let mut alpha2 = Vec::new();
-    let mut alpha3 = Vec::new();
-
-    let mut records = data
-        .iter()
-        .filter(|&record| record.country_code.is_some())
-        .collect::<Vec<_>>();
-    records.sort_by_cached_key(|&record| record.country_code.as_deref());
-
-    for record in records {
-        if let Some(cc) = record.country_code.as_deref()
-            && let Some(n) = record.name.as_deref()
-            && let Some(a2) = record.alpha_2.as_deref()
-            && let Some(a3) = record.alpha_3.as_deref()
-        {
-            let id = name_to_ident(n);
-            let c = cc
-                .parse::<u16>()
-                .map_err(|err| Error::new(config.lukes_span, err.to_string()))?;
-            let d = format!(" {n} ({a2}, {a3})");
-
-            ident.push(id);
-            code.push(c);
-            doc.push(d);
-            name.push(n);
-            alpha2.push(a2);
-            alpha3.push(a3);
-        }
+    let mut builder = Builder::new()
+        .source(Source::LukesFile(
+            config.lukes_path.clone(),
+            config.lukes_format,
+        ))
+        .examples(true);
+    if let Some(data_date) = &config.data_date {
+        builder = builder.data_date(data_date);
+    }
+    if let Some(data_source) = &config.data_source {
+        builder = builder.data_source(data_source);
+    }
+    if config.include_m49 {
+        builder = builder.m49(Language::English, M49_SNAPSHOT);
+    }
+    for (language, path) in &config.m49 {
+        builder = builder.m49_file(*language, path);
+    }
+    builder = builder.name_index(config.name_index);
+    for (supplement, path) in &config.supplements {
+        builder = match path {
+            Some(path) => (supplement.file)(builder, path.clone()),
+            None => (supplement.bytes)(builder, supplement.snapshot),
+        };
     }
 
-    let (user_ident, user_code, user_doc) = (900..999u16)
-        .map(|i| {
-            (
-                quote::format_ident!("User{i}"),
-                i,
-                format!("User-assigned {i}"),
-            )
-        })
-        .collect::<(Vec<Ident>, Vec<u16>, Vec<String>)>();
+    let generated = builder.generate().map_err(|error| match error {
+        CodegenError::InvalidDate(_) => Error::new(
+            config.data_date_span.unwrap_or(config.lukes_span),
+            "`data_date` must be a static string in `YYYY-MM-DD` form",
+        ),
+        error @ CodegenError::IncompleteFormat(_) => Error::new(
+            config.lukes_format_span.unwrap_or(config.lukes_span),
+            error.to_string(),
+        ),
+        error => Error::new(config.lukes_span, error.to_string()),
+    })?;
 
     let mut retval = quote::quote! {
-        /// ISO 3166-1 Numeric Country Codes.
-        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-        #[non_exhaustive]
-        #[repr(u16)]
-        pub enum Numeric {
-            #(
-                #[doc = #doc]
-                #ident = #code,
-            )*
-            #(
-                #[doc = #user_doc]
-                #user_ident = #user_code,
-            )*
-        }
+        // Proc-macros cannot tell cargo about the files they read, so we include the data here to
+        // ensure changes to it trigger a rebuild.
+        const _: &[u8] = include_bytes!(#path);
     };
-
-    retval.extend(quote::quote! {
-        impl Numeric {
-            /// Try to create a new numeric value from the given 16-bit integer.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UnknownCode`] when the code is unknown.
-            pub const fn from_u16(value: u16) -> Result<Self, Error> {
-                match value {
-                    #(
-                        #code => Ok(Self::#ident),
-                    )*
-                    #(
-                        #user_code => Ok(Self::#user_ident),
-                    )*
-
-                    _ => Err(Error::UnknownCode),
-                }
-            }
-
-            /// Try to create a new numeric code from the given alpha-2 code.
-            ///
-            /// Note that user-assigned alpha-2 codes do not have a direct numeric representation.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UserAssigned`] when attempting to translate to a User-assigned
-            ///   Alpha-2 code to a numeric code.
-            pub const fn from_alpha2(value: Alpha2) -> Result<Self, Error> {
-                match value {
-                    #(
-                        Alpha2::#ident => Ok(Self::#ident),
-                    )*
-                    _ => Err(Error::UserAssigned),
-                }
-            }
-
-            /// Try to create a new numeric code from the given alpha-2 code.
-            ///
-            /// Note that user-assigned alpha-2 codes do not have a direct numeric representation.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UserAssigned`] when attempting to translate to a User-assigned
-            ///   Alpha-3 code to a numeric code.
-            pub const fn from_alpha3(value: Alpha3) -> Result<Self, Error> {
-                match value {
-                    #(
-                        Alpha3::#ident => Ok(Self::#ident),
-                    )*
-                    _ => Err(Error::UserAssigned),
-                }
-            }
-
-            /// Determine whether a given enum value represents a user-assigned value.
-            ///
-            /// # Examples
-            ///
-            /// ```rust
-            /// use iso3166_static::Numeric;
-            ///
-            /// assert!(!Numeric::UnitedStatesOfAmerica.is_user_assigned());
-            /// assert!(Numeric::User900.is_user_assigned());
-            /// ```
-            pub const fn is_user_assigned(&self) -> bool {
-                *self as u16 >= 900 && *self as u16 <= 999
-            }
-        }
-
-        impl PartialEq<Alpha2> for Numeric {
-            fn eq(&self, other: &Alpha2) -> bool {
-                match self {
-                    #(
-                        Self::#ident => *other == Alpha2::#ident,
-                    )*
-                    _ => false,
-                }
-            }
-        }
-
-        impl PartialEq<Alpha3> for Numeric {
-            fn eq(&self, other: &Alpha3) -> bool {
-                match self {
-                    #(
-                        Self::#ident => *other == Alpha3::#ident,
-                    )*
-                    _ => false,
-                }
-            }
-        }
-    });
-
-    Ok(retval)
-}
-
-#[allow(clippy::too_many_lines)]
-fn alpha2(data: &[Record]) -> TokenStream {
-    let mut ident = Vec::new();
-    let mut doc = Vec::new();
-    let mut name = Vec::new();
-    let mut alpha2 = Vec::new();
-    let mut alpha2_bytes = Vec::new();
-
-    let mut records = data
-        .iter()
-        .filter(|&record| record.country_code.is_some())
-        .collect::<Vec<_>>();
-    records.sort_by_cached_key(|&record| record.country_code.as_deref());
-
-    for record in records {
-        if let Some(cc) = record.country_code.as_deref()
-            && let Some(n) = record.name.as_deref()
-            && let Some(a2) = record.alpha_2.as_deref()
-            && let Some(a3) = record.alpha_3.as_deref()
-        {
-            let id = name_to_ident(n);
-            let d = format!(" {n} ({cc}, {a2}, {a3})");
-            let bytes = LitByteStr::new(a2.as_bytes(), Span::mixed_site());
-
-            ident.push(id);
-            doc.push(d);
-            name.push(n);
-            alpha2.push(a2);
-            alpha2_bytes.push(bytes);
-        }
+    for (_, m49_path) in &config.m49 {
+        let m49_path = m49_path.to_str().ok_or_else(|| {
+            Error::new(
+                config.lukes_span,
+                "The M49 path must be valid UTF-8 to be tracked",
+            )
+        })?;
+        retval.extend(quote::quote! {
+            const _: &[u8] = include_bytes!(#m49_path);
+        });
     }
-
-    // User-generated Alpha-2
-    let mut user_doc = Vec::new();
-    let mut user_ident = Vec::new();
-    let mut user_alpha2 = Vec::new();
-    let mut user_alpha2_bytes = Vec::new();
-
-    for user in [
-        "AA", "QM", "QN", "QO", "QP", "QQ", "QR", "QS", "QT", "QU", "QV", "QW", "QX", "QY", "QZ",
-        "XA", "XB", "XC", "XD", "XE", "XF", "XG", "XH", "XI", "XJ", "XK", "XL", "XM", "XN", "XO",
-        "XP", "XQ", "XR", "XS", "XT", "XU", "XV", "XW", "XX", "XY", "XZ", "ZZ",
-    ] {
-        let d = format!(" User-assigned {user}");
-        let id = quote::format_ident!("User{user}");
-        let bytes = LitByteStr::new(user.as_bytes(), Span::mixed_site());
-
-        user_doc.push(d);
-        user_ident.push(id);
-        user_alpha2.push(user);
-        user_alpha2_bytes.push(bytes);
+    for (supplement, path) in &config.supplements {
+        let Some(path) = path else {
+            continue;
+        };
+        let path = path.to_str().ok_or_else(|| {
+            let message = format!(
+                "The {} path must be valid UTF-8 to be tracked",
+                supplement.name
+            );
+            Error::new(config.lukes_span, message)
+        })?;
+        retval.extend(quote::quote! {
+            const _: &[u8] = include_bytes!(#path);
+        });
     }
+    retval.extend(generated);
 
-    quote::quote! {
-        /// ISO 3166-1 Alpha-2 Country Codes.
-        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-        #[non_exhaustive]
-        pub enum Alpha2 {
-            #(
-                #[doc = #doc]
-                #ident,
-            )*
-
-            #(
-                #[doc = #user_doc]
-                #user_ident,
-            )*
-        }
-
-        impl Alpha2 {
-            /// Parse the given alpha-2 string slice into the enum value.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UnknownCode`] when the string value is not a valid code.
-            pub const fn from_str_slice(value: &str) -> Result<Self, Error> {
-                if !value.is_ascii() {
-                    return Err(Error::InvalidCharset);
-                }
-
-                if value.len() != 2 {
-                    return Err(Error::InvalidLength);
-                }
-
-                match value.as_bytes() {
-                    #(
-                        #alpha2_bytes => Ok(Self::#ident),
-                    )*
-
-                    #(
-                        #user_alpha2_bytes => Ok(Self::#user_ident),
-                    )*
-
-                    _ => Err(Error::UnknownCode),
-                }
-            }
-
-            /// Try to convert the given numeric value into the alpha-2 value.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UserAssigned`] when the numeric value is unassigned and cannot be
-            ///   converted.
-            pub const fn from_numeric(value: Numeric) -> Result<Self, Error> {
-                match value {
-                    #(
-                        Numeric::#ident => Ok(Self::#ident),
-                    )*
-                    _ => Err(Error::UserAssigned),
-                }
-            }
-
-            /// Try to convert the given alpha-3 value into the alpha-2 value.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UserAssigned`] when the numeric value is unassigned and cannot be
-            ///   converted.
-            pub const fn from_alpha3(value: Alpha3) -> Result<Self, Error> {
-                match value {
-                    #(
-                        Alpha3::#ident => Ok(Self::#ident),
-                    )*
-                    _ => Err(Error::UserAssigned),
-                }
-            }
-
-            /// Get the string representation of the given Alpha-2 code.
-            pub const fn as_str(&self) -> &'static str {
-                match self {
-                    #(
-                        Self::#ident => #alpha2,
-                    )*
-
-                    #(
-                        Self::#user_ident => #user_alpha2,
-                    )*
-                }
-            }
-
-            /// Determine whether a given enum value represents a user-assigned value.
-            ///
-            /// # Examples
-            ///
-            /// ```rust
-            /// use iso3166_static::Alpha2;
-            ///
-            /// assert!(!Alpha2::UnitedStatesOfAmerica.is_user_assigned());
-            /// assert!(Alpha2::UserXX.is_user_assigned());
-            /// ```
-            pub const fn is_user_assigned(&self) -> bool {
-                match self {
-                    #(
-                        Self::#user_ident => true,
-                    )*
-
-                    _ => false,
-                }
-            }
-        }
-
-        impl PartialEq<Numeric> for Alpha2 {
-            fn eq(&self, other: &Numeric) -> bool {
-                match self {
-                    #(
-                        Self::#ident => *other == Numeric::#ident,
-                    )*
-                    _ => false,
-                }
-            }
-        }
-
-        impl PartialEq<Alpha3> for Alpha2 {
-            fn eq(&self, other: &Alpha3) -> bool {
-                match self {
-                    #(
-                        Self::#ident => *other == Alpha3::#ident,
-                    )*
-                    _ => false,
-                }
-            }
-        }
-    }
+    Ok(retval)
 }
 
-fn make_user_alpha3(pos1: char, pos2: char, pos3: char) -> (Ident, String, String) {
-    let mut alpha3 = String::new();
-    alpha3.push(pos1);
-    alpha3.push(pos2);
-    alpha3.push(pos3);
+pub(crate) fn generate(tokens: TokenStream) -> TokenStream {
+    try_generate(tokens).unwrap_or_else(Error::into_compile_error)
+}
 
-    let ident = quote::format_ident!("User{alpha3}");
-    let doc = format!(" User-assigned {alpha3}");
+#[cfg(test)]
+mod test {
+    use super::{generate, try_generate};
+    use proc_macro2::TokenStream;
 
-    (ident, doc, alpha3)
-}
+    const ARGS: &str = r#"lukes_json = "../../parsers/src/lukes/2025-12-29.json""#;
 
-#[allow(clippy::too_many_lines)]
-fn alpha3(data: &[Record]) -> TokenStream {
-    let mut ident = Vec::new();
-    let mut doc = Vec::new();
-    let mut name = Vec::new();
-    let mut alpha3 = Vec::new();
-    let mut alpha3_bytes = Vec::new();
-
-    let mut records = data
-        .iter()
-        .filter(|&record| record.country_code.is_some())
-        .collect::<Vec<_>>();
-    records.sort_by_cached_key(|&record| record.country_code.as_deref());
-
-    for record in records {
-        if let Some(cc) = record.country_code.as_deref()
-            && let Some(n) = record.name.as_deref()
-            && let Some(a2) = record.alpha_2.as_deref()
-            && let Some(a3) = record.alpha_3.as_deref()
-        {
-            let id = name_to_ident(n);
-            let d = format!(" {n} ({cc}, {a2}, {a3})");
-            let bytes = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
-
-            ident.push(id);
-            doc.push(d);
-            name.push(n);
-            alpha3.push(a3);
-            alpha3_bytes.push(bytes);
-        }
+    fn error(args: &str) -> (String, usize) {
+        let tokens = args.parse::<TokenStream>().expect("valid tokens");
+        let error = try_generate(tokens).expect_err("invalid arguments");
+        (error.to_string(), error.span().start().column)
     }
 
-    // User-generated Alpha-2
-    let mut user_doc = Vec::new();
-    let mut user_ident = Vec::new();
-    let mut user_alpha3 = Vec::new();
-    let mut user_alpha3_bytes = Vec::new();
-
-    // AAA-AAZ
-    for code in 'A'..='Z' {
-        let (id, d, a3) = make_user_alpha3('A', 'A', code);
-        let a3b = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
-        user_ident.push(id);
-        user_doc.push(d);
-        user_alpha3.push(a3);
-        user_alpha3_bytes.push(a3b);
+    #[test]
+    fn missing_lukes_json() {
+        let (message, _) = error(r#"data_date = "2025-12-29""#);
+        assert_eq!("`lukes_json` was not set", message);
     }
 
-    // QMA-QZZ
-    for code in 'M'..='Z' {
-        for code2 in 'A'..='Z' {
-            let (id, d, a3) = make_user_alpha3('Q', code, code2);
-            let a3b = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
-            user_ident.push(id);
-            user_doc.push(d);
-            user_alpha3.push(a3);
-            user_alpha3_bytes.push(a3b);
-        }
-    }
+    #[test]
+    fn invalid_data_date() {
+        let args = format!(r#"{ARGS}, data_date = "2025-02-30""#);
+        let (message, column) = error(&args);
 
-    // XAA - XZZ
-    for code in 'A'..='Z' {
-        for code2 in 'A'..='Z' {
-            let (id, d, a3) = make_user_alpha3('X', code, code2);
-            let a3b = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
-            user_ident.push(id);
-            user_doc.push(d);
-            user_alpha3.push(a3);
-            user_alpha3_bytes.push(a3b);
-        }
+        assert_eq!(
+            "`data_date` must be a static string in `YYYY-MM-DD` form",
+            message
+        );
+        assert_eq!(args.find(r#""2025-02-30""#), Some(column));
     }
 
-    // ZZA-ZZZ
-    for code in 'A'..='Z' {
-        let (id, d, a3) = make_user_alpha3('Z', 'Z', code);
-        let a3b = LitByteStr::new(a3.as_bytes(), Span::mixed_site());
-        user_ident.push(id);
-        user_doc.push(d);
-        user_alpha3.push(a3);
-        user_alpha3_bytes.push(a3b);
+    #[yare::parameterized(
+        slim2 = { "slim-2.json" },
+        slim3 = { "slim-3.csv" },
+    )]
+    fn incomplete_lukes_format(format: &str) {
+        let args = format!(r#"{ARGS}, lukes_format = "{format}""#);
+        let (message, column) = error(&args);
+
+        assert!(message.contains(format), "{message}");
+        assert!(message.contains("use all.json"), "{message}");
+        assert_eq!(args.find(&format!(r#""{format}""#)), Some(column));
     }
 
-    quote::quote! {
-        /// ISO 3166-1 Alpha-3 Country Codes.
-        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-        #[non_exhaustive]
-        pub enum Alpha3 {
-            #(
-                #[doc = #doc]
-                #ident,
-            )*
-            #(
-                #[doc = #user_doc]
-                #user_ident,
-            )*
-        }
-
-        impl Alpha3 {
-            /// Parse the given alpha-3 string slice into the enum value.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UnknownCode`] when the string value is not a valid alpha-3 code.
-            pub const fn from_str_slice(value: &str) -> Result<Self, Error> {
-                if !value.is_ascii() {
-                    return Err(Error::InvalidCharset);
-                }
-
-                if value.len() != 3 {
-                    return Err(Error::InvalidLength);
-                }
-
-                match value.as_bytes() {
-                    #(
-                        #alpha3_bytes => Ok(Self::#ident),
-                    )*
-
-                    #(
-                        #user_alpha3_bytes => Ok(Self::#user_ident),
-                    )*
-
-                    _ => Err(Error::UnknownCode),
-                }
-            }
-
-            /// Try to convert the given numeric value into the alpha-3 value.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UserAssigned`] when the numeric value is unassigned and cannot be
-            ///   converted.
-            pub const fn from_numeric(value: Numeric) -> Result<Self, Error> {
-                match value {
-                    #(
-                        Numeric::#ident => Ok(Self::#ident),
-                    )*
-                    _ => Err(Error::UserAssigned),
-                }
-            }
-
-            /// Try to convert the given alpha-2 value into the alpha-3 value.
-            ///
-            /// # Errors
-            ///
-            /// - [`Error::UserAssigned`] when the numeric value is unassigned and cannot be
-            ///   converted.
-            pub const fn from_alpha2(value: Alpha2) -> Result<Self, Error> {
-                match value {
-                    #(
-                        Alpha2::#ident => Ok(Self::#ident),
-                    )*
-                    _ => Err(Error::UserAssigned),
-                }
-            }
-
-            /// Get the string representation of the given Alpha-3 code.
-            pub const fn as_str(&self) -> &'static str {
-                match self {
-                    #(
-                        Self::#ident => #alpha3,
-                    )*
-
-                    #(
-                        Self::#user_ident => #user_alpha3,
-                    )*
-                }
-            }
-
-            /// Determine whether a given enum value represents a user-assigned value.
-            ///
-            /// # Examples
-            ///
-            /// ```rust
-            /// use iso3166_static::Alpha3;
-            ///
-            /// assert!(!Alpha3::UnitedStatesOfAmerica.is_user_assigned());
-            /// assert!(Alpha3::UserZZZ.is_user_assigned());
-            /// ```
-            pub const fn is_user_assigned(&self) -> bool {
-                match self {
-                    #(
-                        Self::#user_ident => true,
-                    )*
-
-                    _ => false,
-                }
-            }
-        }
-
-        impl PartialEq<Numeric> for Alpha3 {
-            fn eq(&self, other: &Numeric) -> bool {
-                match self {
-                    #(
-                        Self::#ident => *other == Numeric::#ident,
-                    )*
-                    _ => false,
-                }
-            }
-        }
+    #[test]
+    fn compile_error() {
+        let tokens = format!(r#"{ARGS}, data_date = "29/12/2025""#)
+            .parse::<TokenStream>()
+            .expect("valid tokens");
+        let output = generate(tokens).to_string();
 
-        impl PartialEq<Alpha2> for Alpha3 {
-            fn eq(&self, other: &Alpha2) -> bool {
-                match self {
-                    #(
-                        Self::#ident => *other == Alpha2::#ident,
-                    )*
-                    _ => false,
-                }
-            }
-        }
+        assert!(output.starts_with(":: core :: compile_error !"), "{output}");
+        assert!(output.contains("`data_date`"), "{output}");
     }
 }
-
-fn try_generate(tokens: TokenStream) -> Result<TokenStream> {
-    let config = Punctuated::<Meta, Token![,]>::parse_terminated
-        .parse2(tokens)
-        .and_then(|args| Config::build(&args))?;
-
-    let f = File::open(&config.lukes_path).map_err(|error| {
-        let message = format!("Could not open JSON path: {error}");
-        Error::new(config.lukes_span, message)
-    })?;
-
-    let data = serde_json::from_reader::<_, Vec<Record>>(f).map_err(|error| {
-        let message = format!("Could not parse JSON path: {error}");
-        Error::new(config.lukes_span, message)
-    })?;
-
-    let mut retval = quote::quote! {
-        /// An enumeration of errors related to ISO 3166 codes.
-        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialOrd, PartialEq)]
-        pub enum Error {
-            /// The given value is not a known ISO 3166-1 code.
-            UnknownCode,
-            /// User-assigned codes cannot be converted between types.
-            UserAssigned,
-            /// The string length is not a viable code.
-            InvalidLength,
-            /// The string contains non-ascii characters.
-            InvalidCharset,
-        }
-    };
-
-    let numeric = numeric(&config, &data)?;
-    let alpha2 = alpha2(&data);
-    let alpha3 = alpha3(&data);
-
-    retval.extend(numeric);
-    retval.extend(alpha2);
-    retval.extend(alpha3);
-
-    Ok(retval)
-}
-
-pub(crate) fn generate(tokens: TokenStream) -> TokenStream {
-    try_generate(tokens).expect("Could not generate output")
-}
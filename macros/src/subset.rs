@@ -0,0 +1,290 @@
+//! Derive support for application-defined subsets of ISO 3166 countries.
+
+use crate::countries;
+use iso3166_codegen::{Variant, is_user_assigned_alpha2, is_user_assigned_alpha3};
+use proc_macro2::{Span, TokenStream};
+use std::collections::HashMap;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Result};
+
+/// The resolution of a single enum variant to an ISO 3166 country.
+struct Member<'a> {
+    variant: &'a Ident,
+    country: &'static Variant,
+}
+
+/// Find the code given in an `#[iso3166("..")]` attribute, if any.
+fn code_attr(attrs: &[Attribute]) -> Result<Option<LitStr>> {
+    let mut retval = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("iso3166")) {
+        if retval.is_some() {
+            return Err(Error::new_spanned(attr, "`iso3166` is set multiple times"));
+        }
+
+        retval = Some(attr.parse_args::<LitStr>()?);
+    }
+
+    Ok(retval)
+}
+
+/// Parse the enum-level `#[iso3166(serde)]` option.
+fn serde_attr(attrs: &[Attribute]) -> Result<bool> {
+    let mut retval = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("iso3166")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("serde") {
+                retval = true;
+                Ok(())
+            } else {
+                Err(meta.error("Unknown option; expected `serde`"))
+            }
+        })?;
+    }
+
+    Ok(retval)
+}
+
+/// Resolve a code (from an attribute or a variant name) to a country.
+fn resolve(code: &str, span: Span, by_name: bool) -> Result<&'static Variant> {
+    let is_code = code.bytes().all(|b| b.is_ascii_uppercase());
+    let found = match code.len() {
+        2 if is_code => countries::by_alpha2(code),
+        3 if is_code => countries::by_alpha3(code),
+        _ if by_name => countries::by_ident(code),
+        _ => Ok(None),
+    }
+    .map_err(|message| Error::new(span, message))?;
+
+    found.ok_or_else(|| {
+        let message = if is_user_assigned_alpha2(code) || is_user_assigned_alpha3(code) {
+            format!("`{code}` is a user-assigned code, which cannot be converted between types")
+        } else if by_name {
+            format!(
+                "`{code}` is not a known ISO 3166-1 alpha-2 code, alpha-3 code, or country name; \
+                 use `#[iso3166(\"..\")]` to specify the code"
+            )
+        } else {
+            format!("`{code}` is not a known ISO 3166-1 alpha-2 or alpha-3 code")
+        };
+
+        Error::new(span, message)
+    })
+}
+
+#[allow(clippy::too_many_lines)]
+fn try_derive(input: &DeriveInput) -> Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "`Iso3166Subset` can only be derived for enums",
+        ));
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`Iso3166Subset` cannot be derived for generic enums",
+        ));
+    }
+
+    let serde = serde_attr(&input.attrs)?;
+    let mut members = Vec::new();
+    let mut seen = HashMap::<&str, &Ident>::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "`Iso3166Subset` variants cannot have fields",
+            ));
+        }
+
+        let country = match code_attr(&variant.attrs)? {
+            Some(lit) => resolve(&lit.value(), lit.span(), false)?,
+            None => resolve(&variant.ident.to_string(), variant.ident.span(), true)?,
+        };
+
+        if let Some(previous) = seen.insert(&country.alpha2, &variant.ident) {
+            let message = format!("`{}` is already used by `{previous}`", country.alpha2);
+            return Err(Error::new_spanned(&variant.ident, message));
+        }
+
+        members.push(Member {
+            variant: &variant.ident,
+            country,
+        });
+    }
+
+    let name = &input.ident;
+    let variant = members.iter().map(|m| m.variant).collect::<Vec<_>>();
+    let country = members
+        .iter()
+        .map(|m| quote::format_ident!("{}", m.country.ident))
+        .collect::<Vec<_>>();
+
+    let mut retval = quote::quote! {
+        impl ::core::convert::From<#name> for ::iso3166_static::Alpha2 {
+            fn from(value: #name) -> Self {
+                match value {
+                    #( #name::#variant => Self::#country, )*
+                }
+            }
+        }
+
+        impl ::core::convert::From<#name> for ::iso3166_static::Alpha3 {
+            fn from(value: #name) -> Self {
+                match value {
+                    #( #name::#variant => Self::#country, )*
+                }
+            }
+        }
+
+        impl ::core::convert::From<#name> for ::iso3166_static::Numeric {
+            fn from(value: #name) -> Self {
+                match value {
+                    #( #name::#variant => Self::#country, )*
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<::iso3166_static::Alpha2> for #name {
+            type Error = ::iso3166_static::Error;
+
+            fn try_from(value: ::iso3166_static::Alpha2) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #( ::iso3166_static::Alpha2::#country => ::core::result::Result::Ok(Self::#variant), )*
+                    _ => ::core::result::Result::Err(::iso3166_static::Error::UnknownCode),
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<::iso3166_static::Alpha3> for #name {
+            type Error = ::iso3166_static::Error;
+
+            fn try_from(value: ::iso3166_static::Alpha3) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #( ::iso3166_static::Alpha3::#country => ::core::result::Result::Ok(Self::#variant), )*
+                    _ => ::core::result::Result::Err(::iso3166_static::Error::UnknownCode),
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<::iso3166_static::Numeric> for #name {
+            type Error = ::iso3166_static::Error;
+
+            fn try_from(value: ::iso3166_static::Numeric) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #( ::iso3166_static::Numeric::#country => ::core::result::Result::Ok(Self::#variant), )*
+                    _ => ::core::result::Result::Err(::iso3166_static::Error::UnknownCode),
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for #name {
+            type Err = ::iso3166_static::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                if s.len() == 3 {
+                    ::iso3166_static::Alpha3::from_str_slice(s).and_then(Self::try_from)
+                } else {
+                    ::iso3166_static::Alpha2::from_str_slice(s).and_then(Self::try_from)
+                }
+            }
+        }
+    };
+
+    if serde {
+        retval.extend(quote::quote! {
+            impl ::serde::Serialize for #name {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {
+                    let value = match self {
+                        #( #name::#variant => ::iso3166_static::Alpha2::#country, )*
+                    };
+
+                    ::serde::Serialize::serialize(&value, serializer)
+                }
+            }
+
+            impl<'de> ::serde::Deserialize<'de> for #name {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {
+                    let value = <::iso3166_static::Alpha2 as ::serde::Deserialize>::deserialize(
+                        deserializer,
+                    )?;
+
+                    Self::try_from(value).map_err(::serde::de::Error::custom)
+                }
+            }
+        });
+    }
+
+    Ok(retval)
+}
+
+pub(crate) fn derive(input: &DeriveInput) -> TokenStream {
+    try_derive(input).unwrap_or_else(Error::into_compile_error)
+}
+
+#[cfg(test)]
+mod test {
+    use super::try_derive;
+    use syn::DeriveInput;
+
+    fn derive(input: proc_macro2::TokenStream) -> Result<String, String> {
+        let input = syn::parse2::<DeriveInput>(input).expect("valid input");
+        try_derive(&input)
+            .map(|tokens| tokens.to_string())
+            .map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn names_codes_and_attributes() {
+        let output = derive(quote::quote! {
+            enum Market {
+                Germany,
+                FR,
+                GBR,
+                #[iso3166("US")]
+                America,
+            }
+        })
+        .expect("derived");
+
+        assert!(output.contains("Market :: Germany => Self :: Germany"));
+        assert!(output.contains("Market :: FR => Self :: France"));
+        assert!(output.contains("Market :: GBR => Self :: UnitedKingdom"));
+        assert!(output.contains("Market :: America => Self :: UnitedStatesOfAmerica"));
+        assert!(!output.contains("serde"));
+    }
+
+    #[test]
+    fn serde() {
+        let output = derive(quote::quote! {
+            #[iso3166(serde)]
+            enum Market {
+                Germany,
+            }
+        })
+        .expect("derived");
+
+        assert!(output.contains(":: serde :: Serialize for Market"));
+    }
+
+    #[yare::parameterized(
+        unknown_name = { quote::quote! { enum Market { Atlantis } } },
+        unknown_code = { quote::quote! { enum Market { #[iso3166("QB")] Atlantis } } },
+        user_assigned = { quote::quote! { enum Market { #[iso3166("XK")] Kosovo } } },
+        duplicate = { quote::quote! { enum Market { Germany, DE } } },
+        fields = { quote::quote! { enum Market { Germany(u8) } } },
+        not_enum = { quote::quote! { struct Market; } },
+    )]
+    fn invalid(input: proc_macro2::TokenStream) {
+        assert!(derive(input).is_err());
+    }
+}
@@ -0,0 +1,251 @@
+//! Country boundaries as [GeoJSON](https://datatracker.ietf.org/doc/html/rfc7946), such as
+//! [Natural Earth](https://www.naturalearthdata.com/)'s Admin 0 countries
+//!
+//! The file is a `FeatureCollection` with a `Polygon` or `MultiPolygon` feature per country, whose
+//! positions are longitude and latitude in decimal degrees. The first ring of each polygon is its
+//! outline and any further rings are holes, such as the enclave of Lesotho in South Africa. The
+//! alpha-2 code is read from the first of the [`CODE_PROPERTIES`] which holds one, so features
+//! which Natural Earth gives as `-99`, such as disputed territories, are left out.
+
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Read,
+};
+
+/// Coarse boundaries of most countries, as of [`SNAPSHOT_DATE`].
+///
+/// This is Natural Earth's 1:110m Admin 0 countries, which are in the public domain, as converted
+/// to `GeoJSON` by the [world.geo.json](https://github.com/johan/world.geo.json) project, with the
+/// alpha-2 code of each country in `ISO_A2`, positions rounded to four decimal places and one
+/// feature per line. Kosovo, Northern Cyprus and Somaliland are given as `-99`. At this scale the
+/// borders and coasts crossed by the `iso3166-static` boundary tests are within 11 km of the real
+/// line, but small countries and islands, such as Singapore, are left out. For more detail, read
+/// Natural Earth's 1:50m Admin 0 countries, version 5.1.2, instead.
+pub const SNAPSHOT: &str = include_str!("boundaries/boundaries.geojson");
+
+/// The date [`SNAPSHOT`] was last converted and measured against the borders and coasts it
+/// outlines.
+pub const SNAPSHOT_DATE: &str = "2026-10-19";
+
+/// The feature properties which may hold the alpha-2 code, in order of preference.
+///
+/// Natural Earth's `ISO_A2_EH` fills in codes which `ISO_A2` gives as `-99`, such as France and
+/// Norway, whose `ISO_A2` is withheld because of their overseas territories.
+pub const CODE_PROPERTIES: &[&str] = &["ISO_A2_EH", "ISO_A2", "iso_a2"];
+
+/// A position as longitude and latitude in decimal degrees, the `GeoJSON` order.
+pub type Position = [f64; 2];
+
+/// A polygon of a country's boundary.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Polygon {
+    /// The outline of the polygon, which `GeoJSON` closes by repeating the first position
+    pub exterior: Vec<Position>,
+
+    /// The holes cut out of the polygon
+    pub holes: Vec<Vec<Position>>,
+}
+
+/// The boundary of a country.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Boundary {
+    /// The alpha-2 code of the country
+    pub alpha2: String,
+
+    /// The polygons which make up the country, e.g. its mainland and islands
+    pub polygons: Vec<Polygon>,
+}
+
+/// An error reading a `GeoJSON` file.
+#[derive(Debug)]
+pub struct ReadError {
+    inner: Option<serde_json::Error>,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        match self.inner.as_ref().map(serde_json::Error::line) {
+            None | Some(0) => None,
+            Some(line) => u64::try_from(line).ok(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ReadError {
+    fn from(inner: serde_json::Error) -> Self {
+        Self { inner: Some(inner) }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.inner {
+            Some(inner) => inner.fmt(f),
+            None => f.write_str("a position has fewer than two coordinates"),
+        }
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.inner.as_ref().map(|inner| inner as _)
+    }
+}
+
+#[derive(Deserialize)]
+struct FeatureCollection {
+    features: Vec<Feature>,
+}
+
+#[derive(Deserialize)]
+struct Feature {
+    #[serde(default)]
+    properties: Option<BTreeMap<String, serde_json::Value>>,
+    geometry: Option<Geometry>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum Geometry {
+    Polygon {
+        coordinates: Vec<Vec<Vec<f64>>>,
+    },
+    MultiPolygon {
+        coordinates: Vec<Vec<Vec<Vec<f64>>>>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// Find the alpha-2 code in a feature's properties.
+fn alpha2(properties: &BTreeMap<String, serde_json::Value>) -> Option<String> {
+    CODE_PROPERTIES
+        .iter()
+        .filter_map(|property| properties.get(*property)?.as_str())
+        .find(|code| code.len() == 2 && code.bytes().all(|b| b.is_ascii_uppercase()))
+        .map(str::to_owned)
+}
+
+/// Convert `GeoJSON` rings to a polygon, dropping any altitude.
+fn polygon(rings: Vec<Vec<Vec<f64>>>) -> Result<Polygon, ReadError> {
+    let mut rings = rings.into_iter().map(|ring| {
+        ring.into_iter()
+            .map(|position| match position[..] {
+                [longitude, latitude, ..] => Ok([longitude, latitude]),
+                _ => Err(ReadError { inner: None }),
+            })
+            .collect::<Result<Vec<_>, _>>()
+    });
+
+    Ok(Polygon {
+        exterior: rings.next().transpose()?.unwrap_or_default(),
+        holes: rings.collect::<Result<_, _>>()?,
+    })
+}
+
+/// Read the country boundaries of a `GeoJSON` `FeatureCollection`, in file order.
+///
+/// Features without an alpha-2 code or a `Polygon` or `MultiPolygon` geometry are skipped.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file is not a valid `GeoJSON` `FeatureCollection`, or a position has
+///   fewer than two coordinates.
+pub fn read<R: Read>(reader: R) -> Result<Vec<Boundary>, ReadError> {
+    let collection = serde_json::from_reader::<_, FeatureCollection>(reader)?;
+    let mut retval = Vec::new();
+
+    for feature in collection.features {
+        let Some(alpha2) = feature.properties.as_ref().and_then(alpha2) else {
+            continue;
+        };
+
+        let polygons = match feature.geometry {
+            Some(Geometry::Polygon { coordinates }) => vec![polygon(coordinates)?],
+            Some(Geometry::MultiPolygon { coordinates }) => coordinates
+                .into_iter()
+                .map(polygon)
+                .collect::<Result<_, _>>()?,
+            Some(Geometry::Other) | None => continue,
+        };
+
+        retval.push(Boundary { alpha2, polygons });
+    }
+
+    Ok(retval)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SNAPSHOT, read};
+
+    #[test]
+    fn snapshot() {
+        let boundaries = read(SNAPSHOT.as_bytes()).expect("valid boundaries");
+        assert_eq!(177, boundaries.len());
+
+        let south_africa = boundaries
+            .iter()
+            .find(|boundary| boundary.alpha2 == "ZA")
+            .expect("South Africa");
+        assert_eq!(1, south_africa.polygons[0].holes.len());
+        assert!(
+            boundaries
+                .iter()
+                .flat_map(|boundary| &boundary.polygons)
+                .all(|polygon| polygon.exterior.len() >= 4
+                    && polygon.exterior.first() == polygon.exterior.last())
+        );
+    }
+
+    #[yare::parameterized(
+        extended = { r#"{"ISO_A2":"-99","ISO_A2_EH":"FR"}"#, Some("FR") },
+        fallback = { r#"{"ISO_A2_EH":"-99","ISO_A2":"FR"}"#, Some("FR") },
+        lowercase = { r#"{"iso_a2":"FR"}"#, Some("FR") },
+        unassigned = { r#"{"ISO_A2":"-99"}"#, None },
+        missing = { "null", None },
+    )]
+    fn codes(properties: &str, expected: Option<&str>) {
+        let input = format!(
+            r#"{{"type":"FeatureCollection","features":[{{"type":"Feature",
+            "properties":{properties},
+            "geometry":{{"type":"Polygon","coordinates":[[[0,0],[1,0,5],[1,1],[0,0]]]}}}}]}}"#
+        );
+        let boundaries = read(input.as_bytes()).expect("valid boundaries");
+
+        assert_eq!(
+            expected,
+            boundaries.first().map(|boundary| boundary.alpha2.as_str())
+        );
+        if expected.is_some() {
+            assert_eq!([1.0, 0.0], boundaries[0].polygons[0].exterior[1]);
+        }
+    }
+
+    #[test]
+    fn skipped_geometry() {
+        let input = r#"{"type":"FeatureCollection","features":[
+            {"type":"Feature","properties":{"ISO_A2":"FR"},
+            "geometry":{"type":"Point","coordinates":[2,48]}},
+            {"type":"Feature","properties":{"ISO_A2":"DE"},"geometry":null}]}"#;
+        assert_eq!(0, read(input.as_bytes()).expect("valid boundaries").len());
+    }
+
+    #[yare::parameterized(
+        position = {
+            r#"{"type":"FeatureCollection","features":[{"type":"Feature",
+            "properties":{"ISO_A2":"FR"},"geometry":{"type":"Polygon","coordinates":[[[0]]]}}]}"#,
+            None,
+        },
+        json = { "{\n\"features\": 1}", Some(2) },
+    )]
+    fn invalid(input: &str, line: Option<u64>) {
+        let error = read(input.as_bytes()).expect_err("invalid");
+        assert_eq!(line, error.line());
+    }
+}
@@ -0,0 +1,330 @@
+//! Unicode [CLDR](https://cldr.unicode.org/) display names, as distributed in the
+//! [`cldr-json`](https://github.com/unicode-org/cldr-json) packages
+//!
+//! Each locale's `territories.json` and `languages.json` files in the `cldr-localenames-full`
+//! package contain a map from codes to display names, where alternate forms are given with an
+//! `-alt-` suffix, e.g. `GB-alt-short`.
+//!
+//! The `supplemental/territoryInfo.json` file in the `cldr-core` package contains the share of
+//! each territory's population which speaks a language, and whether the language has official
+//! status there.
+
+use serde::{Deserialize, de::Deserializer};
+use std::{
+    collections::BTreeMap,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Read,
+};
+
+/// The language populations of every territory, as of [`TERRITORY_INFO_DATE`].
+///
+/// This is a curated file in the format of CLDR's `territoryInfo.json`, which lists the official
+/// languages of each territory and the languages spoken by a notable share of its population,
+/// rather than a copy of a CLDR release.
+pub const TERRITORY_INFO: &str = include_str!("cldr/territoryInfo.json");
+
+/// The date [`TERRITORY_INFO`] was last checked against CLDR's `territoryInfo.json`.
+pub const TERRITORY_INFO_DATE: &str = "2025-12-29";
+
+/// The display name of a territory or language in a locale.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DisplayName {
+    /// The standard name
+    pub name: String,
+    /// The short form, e.g. `UK`, if CLDR has one
+    pub short: Option<String>,
+    /// The variant form, e.g. `Congo (Republic)`, if CLDR has one
+    pub variant: Option<String>,
+}
+
+/// The display names from a locale's `territories.json` or `languages.json`.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DisplayNames {
+    /// The locale the names are in, e.g. `de` or `zh-Hant`
+    pub locale: String,
+    /// The display names, keyed by territory or language code
+    pub names: BTreeMap<String, DisplayName>,
+}
+
+/// The official status of a language in a territory.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case")]
+pub enum OfficialStatus {
+    /// The language is official throughout the territory.
+    Official,
+    /// The language is used as if it were official, without being designated as such.
+    DeFactoOfficial,
+    /// The language is official in a region of the territory.
+    OfficialRegional,
+    /// The language is official for a minority of the territory's population.
+    OfficialMinority,
+}
+
+/// A language spoken in a territory.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct LanguagePopulation {
+    /// The language code, e.g. `fr`, or `zh_Hant` when CLDR qualifies it with a script
+    pub language: String,
+    /// The percentage of the territory's population which speaks the language
+    pub population_percent: f64,
+    /// The official status of the language in the territory, if it has one
+    pub official_status: Option<OfficialStatus>,
+}
+
+/// The languages spoken in a territory.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct TerritoryInfo {
+    /// The territory code, e.g. `CA`
+    pub territory: String,
+    /// The languages spoken in the territory, ordered by code
+    pub languages: Vec<LanguagePopulation>,
+}
+
+/// An error reading a CLDR file.
+#[derive(Debug)]
+pub struct ReadError {
+    inner: Option<serde_json::Error>,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        match self.inner.as_ref().map(serde_json::Error::line) {
+            None | Some(0) => None,
+            Some(line) => u64::try_from(line).ok(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ReadError {
+    fn from(inner: serde_json::Error) -> Self {
+        Self { inner: Some(inner) }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.inner {
+            Some(inner) => inner.fmt(f),
+            None => f.write_str("the file does not contain the expected CLDR data"),
+        }
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.inner.as_ref().map(|inner| inner as _)
+    }
+}
+
+#[derive(Deserialize)]
+struct File {
+    main: BTreeMap<String, Locale>,
+}
+
+#[derive(Deserialize)]
+struct Locale {
+    #[serde(rename = "localeDisplayNames")]
+    display_names: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[derive(Deserialize)]
+struct SupplementalFile {
+    supplemental: Supplemental,
+}
+
+#[derive(Deserialize)]
+struct Supplemental {
+    #[serde(rename = "territoryInfo")]
+    territory_info: Option<BTreeMap<String, Territory>>,
+}
+
+#[derive(Deserialize)]
+struct Territory {
+    #[serde(rename = "languagePopulation", default)]
+    language_population: BTreeMap<String, Population>,
+}
+
+#[derive(Deserialize)]
+struct Population {
+    #[serde(
+        rename = "_populationPercent",
+        deserialize_with = "deserialize_percent"
+    )]
+    population_percent: f64,
+    #[serde(rename = "_officialStatus")]
+    official_status: Option<OfficialStatus>,
+}
+
+fn deserialize_percent<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
+/// Read the `supplemental/territoryInfo.json` file of the `cldr-core` package, in territory
+/// order.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file is not valid CLDR JSON, or has no territory information.
+pub fn read_territory_info<R: Read>(reader: R) -> Result<Vec<TerritoryInfo>, ReadError> {
+    let file = serde_json::from_reader::<_, SupplementalFile>(reader)?;
+    let territories = file
+        .supplemental
+        .territory_info
+        .ok_or(ReadError { inner: None })?;
+
+    Ok(territories
+        .into_iter()
+        .map(|(territory, info)| TerritoryInfo {
+            territory,
+            languages: info
+                .language_population
+                .into_iter()
+                .map(|(language, population)| LanguagePopulation {
+                    language,
+                    population_percent: population.population_percent,
+                    official_status: population.official_status,
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+/// Read a locale's `territories.json`.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file is not valid CLDR JSON, or has no territory names.
+pub fn read_territories<R: Read>(reader: R) -> Result<DisplayNames, ReadError> {
+    read(reader, "territories")
+}
+
+/// Read a locale's `languages.json`.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file is not valid CLDR JSON, or has no language names.
+pub fn read_languages<R: Read>(reader: R) -> Result<DisplayNames, ReadError> {
+    read(reader, "languages")
+}
+
+fn read<R: Read>(reader: R, kind: &str) -> Result<DisplayNames, ReadError> {
+    let file = serde_json::from_reader::<_, File>(reader)?;
+    let (locale, mut data) = file
+        .main
+        .into_iter()
+        .next()
+        .ok_or(ReadError { inner: None })?;
+    let entries = data
+        .display_names
+        .remove(kind)
+        .ok_or(ReadError { inner: None })?;
+
+    let mut names = BTreeMap::<String, DisplayName>::new();
+    for (key, value) in entries {
+        let (code, alt) = match key.split_once("-alt-") {
+            Some((code, alt)) => (code.to_owned(), Some(alt)),
+            None => (key, None),
+        };
+
+        let name = names.entry(code).or_default();
+        match alt {
+            None => name.name = value,
+            Some("short") => name.short = Some(value),
+            Some("variant") => name.variant = Some(value),
+            // Other alternates, e.g. `menu` or `long` for languages, are not kept.
+            Some(_) => {}
+        }
+    }
+
+    // Drop codes which only have alternate forms.
+    names.retain(|_, name| !name.name.is_empty());
+
+    Ok(DisplayNames { locale, names })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        OfficialStatus, TERRITORY_INFO, read_languages, read_territories, read_territory_info,
+    };
+
+    /// An excerpt of the German `territories.json` from `cldr-localenames-full`.
+    const DE_TERRITORIES: &str = include_str!("cldr/de/territories.json");
+
+    #[test]
+    fn territories() {
+        let names = read_territories(DE_TERRITORIES.as_bytes()).expect("valid territories");
+
+        assert_eq!("de", names.locale);
+
+        let germany = &names.names["DE"];
+        assert_eq!("Deutschland", germany.name);
+        assert_eq!(None, germany.short);
+
+        let uk = &names.names["GB"];
+        assert_eq!("Vereinigtes Königreich", uk.name);
+        assert_eq!(Some("VK"), uk.short.as_deref());
+
+        let congo = &names.names["CG"];
+        assert_eq!("Kongo-Brazzaville", congo.name);
+        assert_eq!(Some("Kongo (Republik)"), congo.variant.as_deref());
+
+        assert_eq!("Welt", names.names["001"].name);
+    }
+
+    #[test]
+    fn languages() {
+        let input = r#"{"main":{"fr":{"identity":{"language":"fr"},"localeDisplayNames":{"languages":{
+            "de":"allemand","en-GB":"anglais britannique","en-GB-alt-short":"anglais (R.-U.)"}}}}}"#;
+        let names = read_languages(input.as_bytes()).expect("valid languages");
+
+        assert_eq!("fr", names.locale);
+        assert_eq!("allemand", names.names["de"].name);
+        assert_eq!(
+            Some("anglais (R.-U.)"),
+            names.names["en-GB"].short.as_deref()
+        );
+    }
+
+    #[test]
+    fn territory_info() {
+        let info = read_territory_info(TERRITORY_INFO.as_bytes()).expect("valid territory info");
+
+        assert_eq!(249, info.len());
+        let canada = info
+            .iter()
+            .find(|info| info.territory == "CA")
+            .expect("Canada");
+        let french = canada
+            .languages
+            .iter()
+            .find(|language| language.language == "fr")
+            .expect("French");
+        assert_eq!(Some(OfficialStatus::Official), french.official_status);
+        assert!(french.population_percent > 20.0);
+
+        let antarctica = info.iter().find(|info| info.territory == "AQ");
+        assert_eq!(Some(0), antarctica.map(|info| info.languages.len()));
+    }
+
+    #[yare::parameterized(
+        status = { r#"{"supplemental":{"territoryInfo":{"CA":{"languagePopulation":{"fr":{"_populationPercent":"29","_officialStatus":"national"}}}}}}"# },
+        percent = { r#"{"supplemental":{"territoryInfo":{"CA":{"languagePopulation":{"fr":{"_populationPercent":"many"}}}}}}"# },
+        missing = { r#"{"supplemental":{"version":{}}}"# },
+    )]
+    fn territory_info_invalid(input: &str) {
+        assert!(read_territory_info(input.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn wrong_kind() {
+        assert!(read_languages(DE_TERRITORIES.as_bytes()).is_err());
+
+        let error = read_territories("{\n\"main\": 1}".as_bytes()).expect_err("invalid");
+        assert_eq!(Some(2), error.line());
+    }
+}
@@ -0,0 +1,558 @@
+//! International calling codes from ITU-T Recommendation E.164
+//!
+//! The ITU assigns each country a calling code, but some codes are shared, most notably `+1` by
+//! the countries of the North American Numbering Plan (NANP), where the country is identified by
+//! the area code. The data is a CSV file with an `alpha-2` column, a `country-code` column
+//! containing the calling code without the leading `+`, and a `prefixes` column containing the
+//! space-separated national prefixes which identify the country within a shared code, such as the
+//! NANP area codes, or nothing if the country has no specific prefixes. A country with several
+//! calling codes has a row for each.
+//!
+//! ## Sources
+//!
+//! The bundled [`SNAPSHOT`] is generated with [`read_metadata()`] from [`PHONE_NUMBER_METADATA`],
+//! an extract of the `PhoneNumberMetadata.xml` of Google's
+//! [libphonenumber](https://github.com/google/libphonenumber), version
+//! [`PHONE_NUMBER_METADATA_VERSION`], which is licensed under the Apache License 2.0.
+//! libphonenumber compiles the ITU's *List of ITU-T Recommendation E.164 assigned country codes*,
+//! the area code assignments of the North American Numbering Plan Administrator (NANPA) and the
+//! national numbering plans of countries which share a calling code into one file; neither the
+//! ITU nor NANPA publish their lists in a form which can be parsed reliably. As a result, the
+//! table leaves out the countries which libphonenumber does not cover, namely Antarctica, South
+//! Georgia and the South Sandwich Islands and Pitcairn, and the `+379` code assigned to Vatican
+//! City but not in use.
+
+pub use crate::table::ReadError;
+
+mod pattern;
+
+use crate::table;
+use csv::{Terminator, WriterBuilder};
+use quick_xml::events::Event;
+use serde::{Deserialize, Serialize, de::Deserializer};
+use std::{
+    borrow::Cow,
+    collections::BTreeSet,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{self, Read, Write},
+};
+
+/// The calling codes of every country in libphonenumber, as generated from
+/// [`PHONE_NUMBER_METADATA`] by [`read_metadata()`].
+///
+/// See the [module documentation](self) for the countries which are left out.
+pub const SNAPSHOT: &str = include_str!("e164/e164.csv");
+
+/// An extract of libphonenumber's `PhoneNumberMetadata.xml`, as of
+/// [`PHONE_NUMBER_METADATA_VERSION`], keeping only what [`read_metadata()`] reads: the calling
+/// code, main country and leading digits of each territory, and the patterns of its fixed line
+/// and mobile numbers.
+pub const PHONE_NUMBER_METADATA: &str = include_str!("e164/PhoneNumberMetadata.xml");
+
+/// The version of libphonenumber which [`PHONE_NUMBER_METADATA`] was extracted from.
+pub const PHONE_NUMBER_METADATA_VERSION: &str = "9.0.21";
+
+/// The libphonenumber territories which ISO 3166-1 includes in another country, with the alpha-2
+/// code of that country.
+const PARTS: &[(&str, &str)] = &[("AC", "SH"), ("TA", "SH")];
+
+/// The libphonenumber region code of non-geographical entities, such as satellite networks.
+const NON_GEOGRAPHICAL: &str = "001";
+
+/// The number of digits in the area codes of the North American Numbering Plan, which shares the
+/// calling code `1`.
+const AREA_CODE_DIGITS: usize = 3;
+
+fn deserialize_prefixes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(value.split_whitespace().map(str::to_owned).collect())
+}
+
+/// A record in the calling code table.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Record {
+    /// The alpha-2 code of the country
+    #[serde(rename = "alpha-2")]
+    pub alpha2: String,
+
+    /// The calling code, without the leading `+`
+    #[serde(rename = "country-code")]
+    pub country_code: String,
+
+    /// The national prefixes which identify the country within the calling code, if it is shared
+    #[serde(deserialize_with = "deserialize_prefixes")]
+    pub prefixes: Vec<String>,
+}
+
+impl Record {
+    /// Get the full prefixes of E.164 numbers in the country, without the leading `+`.
+    ///
+    /// This is the calling code alone for countries with no specific prefixes.
+    pub fn full_prefixes(&self) -> impl Iterator<Item = String> + '_ {
+        let own = self.prefixes.is_empty().then(|| self.country_code.clone());
+
+        own.into_iter().chain(
+            self.prefixes
+                .iter()
+                .map(|prefix| format!("{}{prefix}", self.country_code)),
+        )
+    }
+}
+
+/// Read the records of a calling code CSV file.
+pub fn read<R: Read>(reader: R) -> impl Iterator<Item = Result<Record, ReadError>> {
+    table::read(reader)
+}
+
+/// Write records as a calling code CSV file, in the format read by [`read()`].
+///
+/// # Errors
+///
+/// - [`io::Error`] if writing fails.
+pub fn write<'a, W: Write>(
+    records: impl IntoIterator<Item = &'a Record>,
+    mut writer: W,
+) -> io::Result<()> {
+    let mut csv = WriterBuilder::new()
+        .terminator(Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+
+    csv.write_record(["alpha-2", "country-code", "prefixes"])?;
+    for record in records {
+        csv.write_record([
+            record.alpha2.as_str(),
+            record.country_code.as_str(),
+            record.prefixes.join(" ").as_str(),
+        ])?;
+    }
+
+    writer.write_all(&csv.into_inner().map_err(csv::IntoInnerError::into_error)?)
+}
+
+/// The underlying cause of a [`MetadataError`].
+#[derive(Debug)]
+enum MetadataErrorKind {
+    Xml(quick_xml::Error),
+    MissingAttribute(&'static str),
+    Pattern(String, String),
+    Ambiguous(String),
+}
+
+/// An error reading libphonenumber's `PhoneNumberMetadata.xml`.
+#[derive(Debug)]
+pub struct MetadataError {
+    line: Option<u64>,
+    inner: MetadataErrorKind,
+}
+
+impl MetadataError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    fn new(inner: MetadataErrorKind, bytes: &[u8], position: u64) -> Self {
+        let end = usize::try_from(position)
+            .unwrap_or(usize::MAX)
+            .min(bytes.len());
+        let line = u64::try_from(bytes[..end].split(|&b| b == b'\n').count()).ok();
+
+        Self { line, inner }
+    }
+
+    fn xml(inner: impl Into<quick_xml::Error>, bytes: &[u8], position: u64) -> Self {
+        Self::new(MetadataErrorKind::Xml(inner.into()), bytes, position)
+    }
+}
+
+impl Display for MetadataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+
+        match &self.inner {
+            MetadataErrorKind::Xml(inner) => inner.fmt(f),
+            MetadataErrorKind::MissingAttribute(name) => {
+                write!(f, "territory has no `{name}` attribute")
+            }
+            MetadataErrorKind::Pattern(id, pattern) => {
+                write!(f, "unsupported pattern `{pattern}` for territory {id}")
+            }
+            MetadataErrorKind::Ambiguous(id) => {
+                write!(
+                    f,
+                    "no numbers tell territory {id} apart within its calling code"
+                )
+            }
+        }
+    }
+}
+
+impl StdError for MetadataError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.inner {
+            MetadataErrorKind::Xml(inner) => Some(inner),
+            MetadataErrorKind::MissingAttribute(_)
+            | MetadataErrorKind::Pattern(..)
+            | MetadataErrorKind::Ambiguous(_) => None,
+        }
+    }
+}
+
+/// A territory in libphonenumber's metadata.
+#[derive(Debug, Default)]
+struct Territory {
+    id: String,
+    country_code: String,
+    main: bool,
+    leading_digits: Option<String>,
+    patterns: Vec<String>,
+    /// The position of the `<territory>` element in the file, for errors
+    position: u64,
+}
+
+impl Territory {
+    /// Expand the territory's `leadingDigits`, or the patterns of its numbers without them, into
+    /// prefixes.
+    fn prefixes(&self, bytes: &[u8]) -> Result<BTreeSet<String>, MetadataError> {
+        let Some(leading_digits) = &self.leading_digits else {
+            let max = if self.country_code == "1" {
+                AREA_CODE_DIGITS
+            } else {
+                usize::MAX
+            };
+
+            let mut retval = BTreeSet::new();
+            for pattern in &self.patterns {
+                retval.extend(self.expand(pattern, max, bytes)?);
+            }
+            return Ok(retval);
+        };
+
+        self.expand(leading_digits, usize::MAX, bytes)
+    }
+
+    fn expand(
+        &self,
+        pattern: &str,
+        max: usize,
+        bytes: &[u8],
+    ) -> Result<BTreeSet<String>, MetadataError> {
+        pattern::prefixes(pattern, max).ok_or_else(|| {
+            let kind = MetadataErrorKind::Pattern(self.id.clone(), pattern.to_owned());
+            MetadataError::new(kind, bytes, self.position)
+        })
+    }
+
+    /// The alpha-2 code of the country the territory is in.
+    fn country(&self) -> &str {
+        PARTS
+            .iter()
+            .find(|(part, _)| *part == self.id)
+            .map_or(self.id.as_str(), |(_, country)| country)
+    }
+}
+
+/// Read the calling code of each country from libphonenumber's `PhoneNumberMetadata.xml`, such as
+/// [`PHONE_NUMBER_METADATA`].
+///
+/// Countries which share a calling code are told apart as libphonenumber does. The main country
+/// for the code has no prefixes, and the prefixes of the others are expanded from their
+/// `leadingDigits` attribute or, without one, from the patterns of their fixed line and mobile
+/// numbers, which for members of the North American Numbering Plan are cut to their three-digit
+/// area codes. libphonenumber tries the main country first and then the others in the order of
+/// the file, so a prefix which an earlier territory's numbers also start with is left out.
+/// Territories which ISO 3166-1 includes in another country, such as Ascension Island in Saint
+/// Helena, are listed under that country, and non-geographical entities are left out.
+/// The records are sorted by alpha-2 code and calling code.
+///
+/// # Errors
+///
+/// - [`MetadataError`] if the file is not valid XML, a territory has no `id` or `countryCode`, or
+///   a pattern uses regular expression syntax which cannot be expanded into prefixes.
+pub fn read_metadata<R: Read>(mut reader: R) -> Result<Vec<Record>, MetadataError> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|error| MetadataError::xml(error, &bytes, 0))?;
+
+    let territories = read_territories(&bytes)?;
+    let shared = |territory: &Territory| {
+        territories.iter().any(|other| {
+            other.country_code == territory.country_code && other.country() != territory.country()
+        })
+    };
+    let derived = territories
+        .iter()
+        .map(|territory| {
+            if shared(territory) {
+                territory.prefixes(&bytes)
+            } else {
+                Ok(BTreeSet::new())
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // libphonenumber tries the main country for a code first, and then the others in the order of
+    // the file, so a prefix goes to the first territory whose numbers start with it.
+    let mut order = (0..territories.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&index| !territories[index].main);
+
+    let mut claimed = Vec::<(&Territory, &BTreeSet<String>)>::new();
+    let mut retval = Vec::<Record>::new();
+    for index in order {
+        let (territory, own) = (&territories[index], &derived[index]);
+        let alpha2 = territory.country();
+        let main = territories.iter().any(|other| {
+            other.country_code == territory.country_code && other.country() == alpha2 && other.main
+        });
+
+        let mut prefixes = BTreeSet::new();
+        if shared(territory) && !main {
+            prefixes = own
+                .iter()
+                .filter(|prefix| {
+                    !claimed.iter().any(|(other, theirs)| {
+                        other.country_code == territory.country_code
+                            && other.country() != alpha2
+                            && theirs
+                                .iter()
+                                .any(|their| prefix.starts_with(their.as_str()))
+                    })
+                })
+                .cloned()
+                .collect();
+
+            if prefixes.is_empty() {
+                let kind = MetadataErrorKind::Ambiguous(territory.id.clone());
+                return Err(MetadataError::new(kind, &bytes, territory.position));
+            }
+        }
+        claimed.push((territory, own));
+
+        match retval
+            .iter_mut()
+            .find(|record| record.alpha2 == alpha2 && record.country_code == territory.country_code)
+        {
+            Some(record) if main => record.prefixes.clear(),
+            Some(record) => {
+                record.prefixes.extend(prefixes);
+                record.prefixes.sort();
+                record.prefixes.dedup();
+            }
+            None => retval.push(Record {
+                alpha2: alpha2.to_owned(),
+                country_code: territory.country_code.clone(),
+                prefixes: prefixes.into_iter().collect(),
+            }),
+        }
+    }
+
+    retval.sort();
+    Ok(retval)
+}
+
+/// Read the geographical territories of `PhoneNumberMetadata.xml`.
+fn read_territories(bytes: &[u8]) -> Result<Vec<Territory>, MetadataError> {
+    let mut xml = quick_xml::Reader::from_reader(bytes);
+    let mut retval = Vec::new();
+    let mut territory = Option::<Territory>::None;
+    let mut number_type = false;
+    let mut text = String::new();
+
+    loop {
+        let position = xml.buffer_position();
+        let event = xml
+            .read_event()
+            .map_err(|error| MetadataError::xml(error, bytes, xml.error_position()))?;
+
+        match event {
+            Event::Start(start) => {
+                match start.name().as_ref() {
+                    b"territory" => {
+                        let attribute = |name: &'static str| {
+                            start
+                                .try_get_attribute(name)
+                                .map_err(|error| MetadataError::xml(error, bytes, position))?
+                                .map(|attribute| {
+                                    attribute
+                                        .unescape_value()
+                                        .map(Cow::into_owned)
+                                        .map_err(|error| MetadataError::xml(error, bytes, position))
+                                })
+                                .transpose()
+                        };
+                        let required = |name: &'static str| {
+                            attribute(name)?.ok_or_else(|| {
+                                let kind = MetadataErrorKind::MissingAttribute(name);
+                                MetadataError::new(kind, bytes, position)
+                            })
+                        };
+
+                        territory = Some(Territory {
+                            id: required("id")?,
+                            country_code: required("countryCode")?,
+                            main: attribute("mainCountryForCode")?.is_some_and(|v| v == "true"),
+                            leading_digits: attribute("leadingDigits")?,
+                            patterns: Vec::new(),
+                            position,
+                        });
+                    }
+                    b"fixedLine" | b"mobile" => number_type = true,
+                    _ => {}
+                }
+
+                text.clear();
+            }
+            Event::Text(value) => text.push_str(
+                &value
+                    .decode()
+                    .map_err(|error| MetadataError::xml(error, bytes, position))?,
+            ),
+            Event::End(end) => match end.name().as_ref() {
+                b"territory" => retval.extend(
+                    territory
+                        .take()
+                        .filter(|territory| territory.id != NON_GEOGRAPHICAL),
+                ),
+                b"fixedLine" | b"mobile" => number_type = false,
+                b"nationalNumberPattern" if number_type => {
+                    if let Some(territory) = &mut territory {
+                        territory.patterns.push(text.trim().to_owned());
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(retval)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PHONE_NUMBER_METADATA, Record, SNAPSHOT, read, read_metadata, write};
+
+    fn snapshot() -> Vec<Record> {
+        read(SNAPSHOT.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records")
+    }
+
+    #[yare::parameterized(
+        united_states = { "US", &["1"] },
+        canada = { "CA", &["1204", "1226"] },
+        dominican_republic = { "DO", &["1809", "1829", "1849"] },
+        guernsey = { "GG", &["4414812", "447781"] },
+        kazakhstan = { "KZ", &["733622", "77"] },
+        saint_helena = { "SH", &["247", "290"] },
+        christmas_island = { "CX", &["6189164"] },
+        vatican = { "VA", &["3906698"] },
+    )]
+    fn full_prefixes(alpha2: &str, expected: &[&str]) {
+        let prefixes = snapshot()
+            .iter()
+            .filter(|record| record.alpha2 == alpha2)
+            .flat_map(Record::full_prefixes)
+            .collect::<Vec<_>>();
+
+        for prefix in expected {
+            assert!(prefixes.iter().any(|p| p == prefix), "{alpha2} {prefix}");
+        }
+    }
+
+    #[test]
+    fn consistent() {
+        let records = snapshot();
+
+        assert_eq!(244, records.len());
+        for record in &records {
+            assert!(
+                record
+                    .full_prefixes()
+                    .all(|prefix| prefix.bytes().all(|b| b.is_ascii_digit())),
+                "{}",
+                record.alpha2
+            );
+        }
+    }
+
+    #[test]
+    fn generated() {
+        let records = read_metadata(PHONE_NUMBER_METADATA.as_bytes()).expect("valid metadata");
+        let mut csv = Vec::new();
+        write(&records, &mut csv).expect("written");
+
+        assert_eq!(SNAPSHOT, String::from_utf8(csv).expect("UTF-8"));
+    }
+
+    const METADATA: &str = r#"<phoneNumberMetadata>
+  <territories>
+    <territory id="AC" countryCode="247">
+      <fixedLine><nationalNumberPattern>6[2-467]\d{3}</nationalNumberPattern></fixedLine>
+    </territory>
+    <territory id="GB" countryCode="44" mainCountryForCode="true">
+      <fixedLine><nationalNumberPattern>11\d{8}</nationalNumberPattern></fixedLine>
+      <mobile><nationalNumberPattern>7[1-4]\d{8}</nationalNumberPattern></mobile>
+    </territory>
+    <territory id="GG" countryCode="44">
+      <fixedLine><nationalNumberPattern>1481[25-9]\d{5}</nationalNumberPattern></fixedLine>
+      <mobile><nationalNumberPattern>7781\d{6}</nationalNumberPattern></mobile>
+    </territory>
+    <territory id="IM" countryCode="44" leadingDigits="74576|(?:16|7[56])24">
+      <fixedLine><nationalNumberPattern>1624\d{6}</nationalNumberPattern></fixedLine>
+    </territory>
+    <territory id="001" countryCode="882">
+      <mobile><nationalNumberPattern>\d{9}</nationalNumberPattern></mobile>
+    </territory>
+  </territories>
+</phoneNumberMetadata>"#;
+
+    #[test]
+    fn metadata() {
+        let records = read_metadata(METADATA.as_bytes()).expect("valid metadata");
+        let record = |alpha2: &str| {
+            records
+                .iter()
+                .find(|record| record.alpha2 == alpha2)
+                .expect("record")
+        };
+
+        assert_eq!(4, records.len());
+        assert_eq!("247", record("SH").country_code);
+        assert_eq!(0, record("SH").prefixes.len());
+        assert_eq!(0, record("GB").prefixes.len());
+        assert_eq!(
+            ["14812", "14815", "14816", "14817", "14818", "14819", "7781"],
+            *record("GG").prefixes
+        );
+        assert_eq!(["1624", "7524", "7624"], *record("IM").prefixes);
+    }
+
+    #[test]
+    fn metadata_errors() {
+        let error = read_metadata(METADATA.replace("7[1-4]", "7[^0]").as_bytes())
+            .expect_err("unsupported pattern");
+        assert_eq!(Some(6), error.line());
+        assert!(
+            error.to_string().contains("`7[^0]\\d{8}` for territory GB"),
+            "{error}"
+        );
+
+        let error = read_metadata(METADATA.replace(r#" countryCode="247""#, "").as_bytes())
+            .expect_err("missing attribute");
+        assert_eq!(Some(3), error.line());
+        assert!(error.to_string().contains("`countryCode`"), "{error}");
+
+        let metadata = METADATA.replace("1481[25-9]", "11").replace("7781", "71");
+        let error = read_metadata(metadata.as_bytes()).expect_err("ambiguous territory");
+        assert!(error.to_string().contains("territory GG"), "{error}");
+    }
+}
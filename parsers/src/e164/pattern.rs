@@ -0,0 +1,268 @@
+//! Expanding the number patterns of libphonenumber's metadata into the prefixes they match
+//!
+//! The patterns use a small subset of regular expression syntax: digits, `\d`, character classes
+//! of digits, groups, alternation and quantifiers. A pattern is expanded digit by digit until it
+//! reaches `\d`, an unbounded quantifier or the given maximum length, so every number the pattern
+//! matches starts with one of the prefixes.
+
+use std::collections::BTreeSet;
+
+/// A node of a parsed pattern.
+#[derive(Debug)]
+enum Node {
+    /// One of the given ASCII digits
+    Digits(Vec<u8>),
+    /// Any digit, where expansion stops
+    Any,
+    /// One of several sequences
+    Group(Vec<Vec<Node>>),
+    /// A node repeated at least `min` and at most `max` times, or any number of times after `min`
+    /// when `max` is `None`
+    Repeat(Box<Node>, usize, Option<usize>),
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matched = self.peek() == Some(byte);
+        if matched {
+            self.position += 1;
+        }
+        matched
+    }
+
+    fn alternation(&mut self) -> Option<Vec<Vec<Node>>> {
+        let mut alternatives = vec![self.sequence()?];
+        while self.eat(b'|') {
+            alternatives.push(self.sequence()?);
+        }
+        Some(alternatives)
+    }
+
+    fn sequence(&mut self) -> Option<Vec<Node>> {
+        let mut nodes = Vec::new();
+        while self.peek().is_some_and(|byte| byte != b'|' && byte != b')') {
+            let atom = self.atom()?;
+            nodes.push(self.quantifier(atom)?);
+        }
+        Some(nodes)
+    }
+
+    fn atom(&mut self) -> Option<Node> {
+        match self.next()? {
+            b'(' => {
+                if self.eat(b'?') && !self.eat(b':') {
+                    return None;
+                }
+                let alternatives = self.alternation()?;
+                self.eat(b')').then_some(Node::Group(alternatives))
+            }
+            b'[' => self.class(),
+            b'\\' => (self.next()? == b'd').then_some(Node::Any),
+            digit @ b'0'..=b'9' => Some(Node::Digits(vec![digit])),
+            _ => None,
+        }
+    }
+
+    fn class(&mut self) -> Option<Node> {
+        let mut digits = BTreeSet::new();
+        loop {
+            match self.next()? {
+                b']' => break,
+                first @ b'0'..=b'9' => {
+                    let last = if self.eat(b'-') {
+                        self.next().filter(u8::is_ascii_digit)?
+                    } else {
+                        first
+                    };
+                    digits.extend(first..=last);
+                }
+                _ => return None,
+            }
+        }
+
+        Some(if digits.len() == 10 {
+            Node::Any
+        } else {
+            Node::Digits(digits.into_iter().collect())
+        })
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        let start = self.position;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    fn quantifier(&mut self, atom: Node) -> Option<Node> {
+        let (min, max) = match self.peek() {
+            Some(b'?') => (0, Some(1)),
+            Some(b'*') => (0, None),
+            Some(b'+') => (1, None),
+            Some(b'{') => {
+                self.position += 1;
+                let min = self.number()?;
+                let max = if self.eat(b',') {
+                    if self.peek() == Some(b'}') {
+                        None
+                    } else {
+                        Some(self.number()?)
+                    }
+                } else {
+                    Some(min)
+                };
+                return self
+                    .eat(b'}')
+                    .then(|| Node::Repeat(Box::new(atom), min, max));
+            }
+            _ => return Some(atom),
+        };
+
+        self.position += 1;
+        Some(Node::Repeat(Box::new(atom), min, max))
+    }
+}
+
+/// Expand a node after each of the given prefixes, each with whether it is finished because
+/// expansion stopped there.
+fn expand(node: &Node, prefixes: Vec<(String, bool)>, max: usize) -> Vec<(String, bool)> {
+    let mut retval = Vec::new();
+
+    for (prefix, finished) in prefixes {
+        if finished || prefix.len() >= max {
+            retval.push((prefix, true));
+            continue;
+        }
+
+        match node {
+            Node::Digits(digits) => retval.extend(
+                digits
+                    .iter()
+                    .map(|&digit| (format!("{prefix}{}", char::from(digit)), false)),
+            ),
+            Node::Any => retval.push((prefix, true)),
+            Node::Group(alternatives) => {
+                for sequence in alternatives {
+                    let start = vec![(prefix.clone(), false)];
+                    retval.extend(
+                        sequence
+                            .iter()
+                            .fold(start, |prefixes, node| expand(node, prefixes, max)),
+                    );
+                }
+            }
+            Node::Repeat(inner, min, repeat_max) => {
+                let mut current = vec![(prefix, false)];
+                for _ in 0..*min {
+                    current = expand(inner, current, max);
+                }
+
+                match repeat_max {
+                    None => retval.extend(current.into_iter().map(|(prefix, _)| (prefix, true))),
+                    Some(repeat_max) => {
+                        for _ in *min..*repeat_max {
+                            let next = expand(inner, current.clone(), max);
+                            retval.append(&mut current);
+                            current = next;
+                        }
+                        retval.append(&mut current);
+                    }
+                }
+            }
+        }
+    }
+
+    retval
+}
+
+/// Expand a pattern into the shortest prefixes, of at most `max` digits, which every number it
+/// matches starts with, or `None` if the pattern uses syntax other than the subset described in
+/// the [module documentation](self).
+pub(super) fn prefixes(pattern: &str, max: usize) -> Option<BTreeSet<String>> {
+    let pattern = pattern.split_whitespace().collect::<String>();
+    let mut parser = Parser {
+        bytes: pattern.as_bytes(),
+        position: 0,
+    };
+    let alternatives = parser.alternation()?;
+    if parser.position != parser.bytes.len() {
+        return None;
+    }
+
+    let expanded = expand(
+        &Node::Group(alternatives),
+        vec![(String::new(), false)],
+        max,
+    )
+    .into_iter()
+    .map(|(prefix, _)| prefix)
+    .collect::<BTreeSet<_>>();
+
+    Some(
+        expanded
+            .iter()
+            .filter(|prefix| {
+                !expanded
+                    .iter()
+                    .any(|other| other.len() < prefix.len() && prefix.starts_with(other.as_str()))
+            })
+            .cloned()
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::prefixes;
+
+    #[yare::parameterized(
+        literal = { "06698", usize::MAX, &["06698"] },
+        class = { "528[89]", usize::MAX, &["5288", "5289"] },
+        alternation = { "74576|(?:16|7[56])24", usize::MAX, &["1624", "74576", "7524", "7624"] },
+        stops_at_any_digit = { "1481[25-9]\\d{5}", 4, &["1481"] },
+        nested = {
+            "7(?:(?:781|839)\\d|911[17])\\d{5}",
+            usize::MAX,
+            &["7781", "7839", "79111", "79117"],
+        },
+        optional = { "8(?:00)?1", usize::MAX, &["8001", "81"] },
+        shortest = { "7|733622", usize::MAX, &["7"] },
+        all_digits = { "2[0-9]{3}", usize::MAX, &["2"] },
+        whitespace = { "(?:\n  2(?:04|[23]6)\n)[2-9]\\d{6}", 3, &["204", "226", "236"] },
+    )]
+    fn expand(pattern: &str, max: usize, expected: &[&str]) {
+        let prefixes = prefixes(pattern, max).expect("supported pattern");
+
+        assert_eq!(expected, prefixes.iter().collect::<Vec<_>>());
+    }
+
+    #[yare::parameterized(
+        lookahead = { "(?=1)2" },
+        letter = { "1a" },
+        negated_class = { "[^1]" },
+        unclosed_group = { "(?:12" },
+        unmatched_paren = { "12)" },
+    )]
+    fn unsupported(pattern: &str) {
+        assert_eq!(None, prefixes(pattern, usize::MAX));
+    }
+}
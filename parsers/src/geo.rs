@@ -0,0 +1,175 @@
+//! Geographic metadata: capitals, centroids and bounding boxes
+//!
+//! The data is a CSV file with an `alpha-2` column, the `capital` city and its
+//! `capital-latitude` and `capital-longitude`, which are empty for territories without a
+//! capital, the `latitude` and `longitude` of a representative centroid, and the `south`,
+//! `west`, `north` and `east` edges of a bounding box, all in decimal degrees. A bounding box
+//! whose `west` edge is greater than its `east` edge crosses the antimeridian, as with Fiji and
+//! Russia. The boxes cover the territory of each code, leaving out territories which have codes of
+//! their own, so metropolitan France does not include French Guiana.
+//!
+//! ## Sources
+//!
+//! The bundled [`SNAPSHOT`] is not an extract of a published dataset. It was compiled by hand for
+//! this crate, with coordinates rounded to two decimal places, and is distributed under the same
+//! Apache-2.0 licence as the crate, so it can be redistributed without attribution. Capitals follow
+//! each country's own designation, which does not imply an opinion on disputed claims. A table
+//! generated from another dataset, such as the centroids and bounding boxes of Natural Earth's
+//! Admin 0 countries, can be read in its place if it is first converted to the columns above.
+
+pub use crate::table::ReadError;
+
+use crate::table;
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+
+/// A curated table of the geographic metadata of every country, as of [`SNAPSHOT_DATE`].
+///
+/// See the [module documentation](self) for where the table comes from.
+pub const SNAPSHOT: &str = include_str!("geo/geo.csv");
+
+/// The date [`SNAPSHOT`] was last reviewed.
+pub const SNAPSHOT_DATE: &str = "2025-12-29";
+
+/// A record in the geographic metadata table.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct Record {
+    /// The alpha-2 code of the country
+    #[serde(rename = "alpha-2")]
+    pub alpha2: String,
+
+    /// The English name of the capital city, if the country has one
+    pub capital: Option<String>,
+
+    /// The latitude of the capital city, in decimal degrees north
+    #[serde(rename = "capital-latitude")]
+    pub capital_latitude: Option<f64>,
+
+    /// The longitude of the capital city, in decimal degrees east
+    #[serde(rename = "capital-longitude")]
+    pub capital_longitude: Option<f64>,
+
+    /// The latitude of the country's representative centroid, in decimal degrees north
+    pub latitude: f64,
+
+    /// The longitude of the country's representative centroid, in decimal degrees east
+    pub longitude: f64,
+
+    /// The southern edge of the country's bounding box
+    pub south: f64,
+
+    /// The western edge of the country's bounding box
+    pub west: f64,
+
+    /// The northern edge of the country's bounding box
+    pub north: f64,
+
+    /// The eastern edge of the country's bounding box, which is less than `west` if the box
+    /// crosses the antimeridian
+    pub east: f64,
+}
+
+impl Record {
+    /// Whether the bounding box crosses the antimeridian.
+    #[must_use]
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// Whether every coordinate is within range, the capital has both coordinates, and the
+    /// centroid and capital are within the bounding box.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        let latitude = |value: f64| (-90.0..=90.0).contains(&value);
+        let longitude = |value: f64| (-180.0..=180.0).contains(&value);
+        let within = |lat: f64, lon: f64| {
+            let east_west = if self.crosses_antimeridian() {
+                lon >= self.west || lon <= self.east
+            } else {
+                (self.west..=self.east).contains(&lon)
+            };
+            (self.south..=self.north).contains(&lat) && east_west
+        };
+
+        let capital = match (&self.capital, self.capital_latitude, self.capital_longitude) {
+            (Some(_), Some(lat), Some(lon)) => latitude(lat) && longitude(lon) && within(lat, lon),
+            (None, None, None) => true,
+            _ => false,
+        };
+
+        capital
+            && latitude(self.south)
+            && latitude(self.north)
+            && self.south <= self.north
+            && longitude(self.west)
+            && longitude(self.east)
+            && within(self.latitude, self.longitude)
+    }
+}
+
+/// Read the records of a geographic metadata CSV file.
+pub fn read<R: Read>(reader: R) -> impl Iterator<Item = Result<Record, ReadError>> {
+    table::read(reader)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Record, SNAPSHOT, read};
+
+    fn snapshot() -> Vec<Record> {
+        read(SNAPSHOT.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records")
+    }
+
+    fn record(alpha2: &str) -> Record {
+        snapshot()
+            .into_iter()
+            .find(|record| record.alpha2 == alpha2)
+            .expect("record")
+    }
+
+    #[yare::parameterized(
+        fiji = { "FJ", true },
+        russia = { "RU", true },
+        kiribati = { "KI", true },
+        new_zealand = { "NZ", true },
+        germany = { "DE", false },
+        antarctica = { "AQ", false },
+    )]
+    fn crosses_antimeridian(alpha2: &str, expected: bool) {
+        assert_eq!(expected, record(alpha2).crosses_antimeridian());
+    }
+
+    #[test]
+    fn consistent() {
+        let records = snapshot();
+
+        assert_eq!(249, records.len());
+        for record in &records {
+            assert!(record.is_valid(), "{}", record.alpha2);
+        }
+    }
+
+    #[test]
+    fn capital() {
+        let germany = record("DE");
+        assert_eq!(Some("Berlin"), germany.capital.as_deref());
+        assert_eq!(Some(52.52), germany.capital_latitude);
+
+        let antarctica = record("AQ");
+        assert_eq!(None, antarctica.capital);
+        assert_eq!(None, antarctica.capital_longitude);
+    }
+
+    #[test]
+    fn invalid() {
+        let mut fiji = record("FJ");
+        fiji.latitude = 0.0;
+        assert!(!fiji.is_valid());
+
+        let mut germany = record("DE");
+        germany.capital_latitude = None;
+        assert!(!germany.is_valid());
+    }
+}
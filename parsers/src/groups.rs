@@ -0,0 +1,244 @@
+//! Membership of countries in international groupings, such as the European Union
+//!
+//! The data is a JSON array of groups, each with a short `code`, the `variant` name used for it
+//! in generated code, its English `name`, and its `members`. Each membership has the alpha-2 code
+//! of the country, the date it `joined`, and the last day of membership if the country has `left`,
+//! all in `YYYY-MM-DD` form. A country which left and rejoined a group has one membership for each
+//! period.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Read,
+};
+
+/// A curated table of groups and their membership history, as of [`SNAPSHOT_DATE`].
+pub const SNAPSHOT: &str = include_str!("groups/groups.json");
+
+/// The date [`SNAPSHOT`] was last updated.
+pub const SNAPSHOT_DATE: &str = "2025-12-29";
+
+/// A period of membership of a country in a group.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Membership {
+    /// The alpha-2 code of the member
+    #[serde(rename = "alpha-2")]
+    pub alpha2: String,
+
+    /// The date the country joined the group
+    pub joined: String,
+
+    /// The last day the country was a member, if it has left
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub left: Option<String>,
+}
+
+/// A group of countries.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Group {
+    /// A short code for the group, e.g. `EU`
+    pub code: String,
+
+    /// The name of the enum variant for the group, e.g. `EuropeanUnion`
+    pub variant: String,
+
+    /// The English name of the group
+    pub name: String,
+
+    /// The membership history of the group
+    pub members: Vec<Membership>,
+}
+
+impl Group {
+    /// Determine whether the given country was a member of the group on the given
+    /// `YYYY-MM-DD` date.
+    ///
+    /// Returns `None` if the date, or a date in the group's membership of the country, is not a
+    /// valid `YYYY-MM-DD` date.
+    #[must_use]
+    pub fn is_member_on(&self, alpha2: &str, date: &str) -> Option<bool> {
+        let date = parse_date(date)?;
+
+        let mut member = false;
+        for membership in self.members.iter().filter(|member| member.alpha2 == alpha2) {
+            let joined = parse_date(&membership.joined)?;
+            let left = match membership.left.as_deref() {
+                Some(left) => Some(parse_date(left)?),
+                None => None,
+            };
+            member |= joined <= date && left.is_none_or(|left| date <= left);
+        }
+
+        Some(member)
+    }
+}
+
+/// Parse a date in `YYYY-MM-DD` form into its year, month and day, checking that the day exists.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_parsers::groups::parse_date;
+///
+/// assert_eq!(Some((2020, 1, 31)), parse_date("2020-01-31"));
+/// assert_eq!(Some((2024, 2, 29)), parse_date("2024-02-29"));
+/// assert_eq!(None, parse_date("2023-02-29"));
+/// assert_eq!(None, parse_date("2020-2-1"));
+/// assert_eq!(None, parse_date("2020/01/31"));
+/// ```
+#[must_use]
+pub fn parse_date(value: &str) -> Option<(u16, u8, u8)> {
+    let bytes = value.as_bytes();
+    if bytes.len() != 10
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !bytes
+            .iter()
+            .enumerate()
+            .all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let year = value[0..4].parse::<u16>().ok()?;
+    let month = value[5..7].parse::<u8>().ok()?;
+    let day = value[8..10].parse::<u8>().ok()?;
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+
+    (1..=days).contains(&day).then_some((year, month, day))
+}
+
+/// An error reading a groups JSON file.
+#[derive(Debug)]
+pub struct ReadError {
+    inner: serde_json::Error,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        match self.inner.line() {
+            0 => None,
+            line => u64::try_from(line).ok(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ReadError {
+    fn from(inner: serde_json::Error) -> Self {
+        Self { inner }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.inner.fmt(f)
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+/// Read the groups in a groups JSON file.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file is not a valid groups file.
+pub fn read<R: Read>(reader: R) -> Result<Vec<Group>, ReadError> {
+    Ok(serde_json::from_reader(reader)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Group, SNAPSHOT, parse_date, read};
+
+    fn group(code: &str) -> Group {
+        read(SNAPSHOT.as_bytes())
+            .expect("valid groups")
+            .into_iter()
+            .find(|group| group.code == code)
+            .expect("group")
+    }
+
+    #[yare::parameterized(
+        uk_before_brexit = { "EU", "GB", "2020-01-31", true },
+        uk_after_brexit = { "EU", "GB", "2020-02-01", false },
+        uk_before_joining = { "EU", "GB", "1972-12-31", false },
+        croatia_before = { "EU", "HR", "2013-06-30", false },
+        croatia_after = { "EU", "HR", "2013-07-01", true },
+        greenland = { "EU", "GL", "1990-01-01", false },
+        norway_eea = { "EEA", "NO", "2025-01-01", true },
+        norway_eu = { "EU", "NO", "2025-01-01", false },
+        switzerland_schengen = { "SCHENGEN", "CH", "2025-01-01", true },
+        croatia_euro = { "EUROZONE", "HR", "2023-01-01", true },
+        finland_nato = { "NATO", "FI", "2023-04-03", false },
+    )]
+    fn membership(code: &str, alpha2: &str, date: &str, expected: bool) {
+        assert_eq!(Some(expected), group(code).is_member_on(alpha2, date));
+    }
+
+    #[yare::parameterized(
+        unpadded = { "2020-2-1" },
+        slashes = { "2020/01/31" },
+        month = { "2020-13-01" },
+        day = { "2021-02-29" },
+        empty = { "" },
+    )]
+    fn membership_invalid_date(date: &str) {
+        assert_eq!(None, group("EU").is_member_on("GB", date));
+    }
+
+    #[test]
+    fn current_sizes() {
+        for (code, size) in [
+            ("EU", 27),
+            ("EEA", 30),
+            ("EFTA", 4),
+            ("EUROZONE", 21),
+            ("OECD", 38),
+            ("G7", 7),
+            ("NATO", 32),
+        ] {
+            let group = group(code);
+            let current = group
+                .members
+                .iter()
+                .filter(|member| member.left.is_none())
+                .count();
+            assert_eq!(size, current, "{code}");
+        }
+    }
+
+    #[test]
+    fn snapshot_dates() {
+        for group in read(SNAPSHOT.as_bytes()).expect("valid groups") {
+            for member in &group.members {
+                assert!(parse_date(&member.joined).is_some(), "{member:?}");
+                assert!(
+                    member
+                        .left
+                        .as_deref()
+                        .is_none_or(|left| parse_date(left).is_some())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn read_error_line() {
+        let error = read("[\n{\"code\": 1}\n]".as_bytes()).expect_err("invalid");
+        assert_eq!(Some(2), error.line());
+    }
+}
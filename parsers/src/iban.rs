@@ -0,0 +1,411 @@
+//! IBAN formats from the SWIFT [IBAN Registry](https://www.swift.com/standards/data-standards/iban-international-bank-account-number)
+//!
+//! The registry's text release is a tab-separated table with a row per data element and a column
+//! per country, the first column holding the name of the element. The BBAN structure is a
+//! sequence of segments such as `4!n`, a fixed length followed by `n` for digits, `a` for upper
+//! case letters or `c` for alphanumeric characters, and the positions of the bank and branch
+//! identifiers are one-based inclusive ranges such as `1-4`, or `N/A`. Rows which are not needed,
+//! such as the contact details, are ignored.
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{self, BufRead, BufReader, Read},
+    ops::Range,
+};
+
+/// The IBAN formats of every country in the registry, as of [`SNAPSHOT_DATE`].
+///
+/// This is a curated file in the format of the registry's text release, with the rows needed to
+/// validate IBANs, rather than a copy of a SWIFT release. Its examples follow the registry's, with
+/// check digits recomputed where needed so that each is valid.
+pub const SNAPSHOT: &str = include_str!("iban/registry.txt");
+
+/// The date [`SNAPSHOT`] was last checked against the IBAN Registry.
+pub const SNAPSHOT_DATE: &str = "2025-12-29";
+
+/// The kind of characters in a segment of a BBAN.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Characters {
+    /// Digits, given as `n`
+    Digits,
+    /// Upper case letters, given as `a`
+    Letters,
+    /// Letters and digits, given as `c`
+    Alphanumeric,
+}
+
+impl Characters {
+    /// Check whether a character is of this kind.
+    #[must_use]
+    pub fn matches(self, c: char) -> bool {
+        match self {
+            Characters::Digits => c.is_ascii_digit(),
+            Characters::Letters => c.is_ascii_uppercase(),
+            Characters::Alphanumeric => c.is_ascii_alphanumeric(),
+        }
+    }
+}
+
+/// A fixed-length segment of a BBAN, e.g. `4!n`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Segment {
+    /// The number of characters in the segment
+    pub length: usize,
+
+    /// The kind of characters in the segment
+    pub characters: Characters,
+}
+
+/// The IBAN format of a country.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Entry {
+    /// The alpha-2 code which IBANs of the country start with
+    pub alpha2: String,
+
+    /// The name of the country in the registry, e.g. `Netherlands (The)`
+    pub name: String,
+
+    /// The alpha-2 codes of the territories which use the country's IBANs, given in parentheses
+    /// in the registry, e.g. `AX` for Finland
+    pub territories: Vec<String>,
+
+    /// Whether the country is in the Single Euro Payments Area
+    pub sepa: bool,
+
+    /// The segments of the BBAN
+    pub bban: Vec<Segment>,
+
+    /// The length of the BBAN, which should be the total length of its segments
+    pub bban_length: usize,
+
+    /// The zero-based range of the bank identifier within the BBAN, if it has one
+    pub bank: Option<Range<usize>>,
+
+    /// The zero-based range of the branch identifier within the BBAN, if it has one
+    pub branch: Option<Range<usize>>,
+
+    /// The length of IBANs, which should be four more than the BBAN
+    pub iban_length: usize,
+
+    /// An example IBAN in electronic format, e.g. `DE89370400440532013000`
+    pub example: String,
+}
+
+impl Entry {
+    /// Check whether an IBAN in electronic format matches the country's format and has valid
+    /// check digits.
+    #[must_use]
+    pub fn is_valid(&self, iban: &str) -> bool {
+        if iban.len() != self.iban_length || !iban.starts_with(&self.alpha2) {
+            return false;
+        }
+
+        let mut bban = iban.chars().skip(4);
+        let structured = self.bban.iter().all(|segment| {
+            (0..segment.length).all(|_| bban.next().is_some_and(|c| segment.characters.matches(c)))
+        });
+
+        structured && bban.next().is_none() && checksum(iban) == Some(1)
+    }
+}
+
+/// Compute the ISO 7064 MOD 97-10 remainder of an IBAN, after moving its first four characters
+/// to the end and replacing letters with two digits, `A` as `10` to `Z` as `35`.
+///
+/// Returns `None` if the IBAN is shorter than four characters or contains anything but ASCII
+/// letters and digits. A valid IBAN has a remainder of `1`.
+#[must_use]
+pub fn checksum(iban: &str) -> Option<u32> {
+    if iban.len() < 4 {
+        return None;
+    }
+
+    let (head, tail) = iban.split_at(4);
+    tail.chars()
+        .chain(head.chars())
+        .try_fold(0, |remainder, c| {
+            let value = c.to_digit(36)?;
+            let shift = if value < 10 { 10 } else { 100 };
+            Some((remainder * shift + value) % 97)
+        })
+}
+
+/// The underlying cause of a [`ReadError`].
+#[derive(Debug)]
+enum ReadErrorKind {
+    Io(io::Error),
+    Row(&'static str),
+    Value(&'static str, String),
+}
+
+/// An error reading an IBAN Registry file.
+#[derive(Debug)]
+pub struct ReadError {
+    line: Option<u64>,
+    inner: ReadErrorKind,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    fn new(inner: ReadErrorKind, index: Option<usize>) -> Self {
+        Self {
+            line: index.and_then(|index| u64::try_from(index + 1).ok()),
+            inner,
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+
+        match &self.inner {
+            ReadErrorKind::Io(inner) => inner.fmt(f),
+            ReadErrorKind::Row(row) => write!(f, "missing {row} row"),
+            ReadErrorKind::Value(row, value) => write!(f, "invalid {row} `{value}`"),
+        }
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.inner {
+            ReadErrorKind::Io(inner) => Some(inner),
+            ReadErrorKind::Row(_) | ReadErrorKind::Value(..) => None,
+        }
+    }
+}
+
+const COUNTRY_CODE: &str = "IBAN prefix country code (ISO 3166)";
+const NAME: &str = "Name of country";
+const TERRITORIES: &str = "Country code includes other countries/territories";
+const SEPA: &str = "SEPA country";
+const BBAN_STRUCTURE: &str = "BBAN structure";
+const BBAN_LENGTH: &str = "BBAN length";
+const BANK_POSITION: &str = "Bank identifier position within the BBAN";
+const BRANCH_POSITION: &str = "Branch identifier position within the BBAN";
+const IBAN_LENGTH: &str = "IBAN length";
+const EXAMPLE: &str = "IBAN electronic format example";
+
+/// The rows of the registry, keyed by data element.
+struct Rows(Vec<(usize, String, Vec<String>)>);
+
+impl Rows {
+    /// Get a value of a required row.
+    fn get(&self, row: &'static str, column: usize) -> Result<(usize, &str), ReadError> {
+        let (index, _, values) = self
+            .0
+            .iter()
+            .find(|(_, name, _)| name == row)
+            .ok_or_else(|| ReadError::new(ReadErrorKind::Row(row), None))?;
+
+        Ok((*index, values.get(column).map_or("", |value| value.trim())))
+    }
+
+    /// Parse a value of a required row.
+    fn parse<T>(
+        &self,
+        row: &'static str,
+        column: usize,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, ReadError> {
+        let (index, value) = self.get(row, column)?;
+        parse(value)
+            .ok_or_else(|| ReadError::new(ReadErrorKind::Value(row, value.to_owned()), Some(index)))
+    }
+
+    /// Parse a value of a required row, which is empty or `N/A` when it does not apply.
+    fn parse_optional<T>(
+        &self,
+        row: &'static str,
+        column: usize,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<Option<T>, ReadError> {
+        self.parse(row, column, |value| match value {
+            "" | "N/A" => Some(None),
+            value => parse(value).map(Some),
+        })
+    }
+}
+
+/// Parse a BBAN structure such as `4!n4!n12!c`.
+fn structure(value: &str) -> Option<Vec<Segment>> {
+    let mut retval = Vec::new();
+    let mut rest = value;
+
+    while !rest.is_empty() {
+        let (length, tail) = rest.split_once('!')?;
+        let mut tail = tail.chars();
+        let characters = match tail.next()? {
+            'n' => Characters::Digits,
+            'a' => Characters::Letters,
+            'c' => Characters::Alphanumeric,
+            _ => return None,
+        };
+
+        retval.push(Segment {
+            length: length.parse().ok().filter(|length| *length > 0)?,
+            characters,
+        });
+        rest = tail.as_str();
+    }
+
+    (!retval.is_empty()).then_some(retval)
+}
+
+/// Parse a one-based inclusive position such as `1-4` into a zero-based range.
+fn position(value: &str) -> Option<Range<usize>> {
+    let (start, end) = value.split_once('-')?;
+    let start = start
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|start| *start > 0)?;
+    let end = end
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|end| *end >= start)?;
+    Some(start - 1..end)
+}
+
+/// Find the alpha-2 codes given in parentheses, e.g. `Åland Islands (AX)`.
+fn territories(value: &str) -> Vec<String> {
+    value
+        .split('(')
+        .skip(1)
+        .filter_map(|part| part.split_once(')'))
+        .map(|(code, _)| code)
+        .filter(|code| code.len() == 2 && code.bytes().all(|b| b.is_ascii_uppercase()))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Read the countries of an IBAN Registry text file, in column order.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file could not be read, a required row is missing, or a value could
+///   not be parsed.
+pub fn read<R: Read>(reader: R) -> Result<Vec<Entry>, ReadError> {
+    let mut rows = Vec::new();
+    for (index, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line.map_err(|error| ReadError::new(ReadErrorKind::Io(error), Some(index)))?;
+        let mut values = line.trim_end_matches('\r').split('\t').map(str::to_owned);
+        if let Some(name) = values.next() {
+            rows.push((index, name.trim().to_owned(), values.collect()));
+        }
+    }
+
+    let rows = Rows(rows);
+    let (_, _, codes) = rows
+        .0
+        .iter()
+        .find(|(_, name, _)| name == COUNTRY_CODE)
+        .ok_or_else(|| ReadError::new(ReadErrorKind::Row(COUNTRY_CODE), None))?;
+
+    (0..codes.len())
+        .map(|column| {
+            Ok(Entry {
+                alpha2: rows.parse(COUNTRY_CODE, column, |value| {
+                    (value.len() == 2 && value.bytes().all(|b| b.is_ascii_uppercase()))
+                        .then(|| value.to_owned())
+                })?,
+                name: rows.get(NAME, column)?.1.to_owned(),
+                territories: territories(rows.get(TERRITORIES, column)?.1),
+                sepa: rows.parse(SEPA, column, |value| match value {
+                    "Yes" => Some(true),
+                    "No" => Some(false),
+                    _ => None,
+                })?,
+                bban: rows.parse(BBAN_STRUCTURE, column, structure)?,
+                bban_length: rows.parse(BBAN_LENGTH, column, |value| value.parse().ok())?,
+                bank: rows.parse_optional(BANK_POSITION, column, position)?,
+                branch: rows.parse_optional(BRANCH_POSITION, column, position)?,
+                iban_length: rows.parse(IBAN_LENGTH, column, |value| value.parse().ok())?,
+                example: rows.get(EXAMPLE, column)?.1.to_owned(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Characters, SNAPSHOT, Segment, checksum, read};
+
+    #[test]
+    fn snapshot() {
+        let entries = read(SNAPSHOT.as_bytes()).expect("valid registry");
+        assert_eq!(89, entries.len());
+
+        for entry in &entries {
+            let length = entry
+                .bban
+                .iter()
+                .map(|segment| segment.length)
+                .sum::<usize>();
+            assert_eq!(entry.bban_length, length, "{}", entry.alpha2);
+            assert_eq!(entry.iban_length, length + 4, "{}", entry.alpha2);
+            assert!(entry.is_valid(&entry.example), "{}", entry.alpha2);
+        }
+
+        let germany = entries
+            .iter()
+            .find(|entry| entry.alpha2 == "DE")
+            .expect("Germany");
+        assert_eq!("DE89370400440532013000", germany.example);
+        assert_eq!(Some(0..8), germany.bank);
+        assert_eq!(None, germany.branch);
+        assert!(germany.sepa);
+
+        let italy = entries
+            .iter()
+            .find(|entry| entry.alpha2 == "IT")
+            .expect("Italy");
+        assert_eq!(
+            Segment {
+                length: 1,
+                characters: Characters::Letters
+            },
+            italy.bban[0]
+        );
+        assert_eq!(Some(1..6), italy.bank);
+        assert_eq!(Some(6..11), italy.branch);
+
+        let united_kingdom = entries
+            .iter()
+            .find(|entry| entry.alpha2 == "GB")
+            .expect("United Kingdom");
+        assert_eq!(["IM", "JE", "GG"], united_kingdom.territories.as_slice());
+    }
+
+    #[yare::parameterized(
+        valid = { "GB29NWBK60161331926819", Some(1) },
+        letters = { "FR1420041010050500013M02606", Some(1) },
+        transposed = { "GB29NWBK60161331926891", Some(5) },
+        short = { "GB2", None },
+        space = { "GB29 NWBK", None },
+    )]
+    fn checksums(iban: &str, expected: Option<u32>) {
+        assert_eq!(expected, checksum(iban));
+    }
+
+    #[yare::parameterized(
+        missing_row = { "Name of country\tGermany\n", None },
+        country_code = { "IBAN prefix country code (ISO 3166)\tde\n", Some(1) },
+        structure = { &SNAPSHOT.replacen("\t4!n4!n12!c\t", "\t4n4!n12!c\t", 1), Some(7) },
+        position = { &SNAPSHOT.replacen("\t1-4\t", "\t4-1\t", 1), Some(9) },
+    )]
+    fn invalid(input: &str, line: Option<u64>) {
+        let error = read(input.as_bytes()).expect_err("invalid");
+        assert_eq!(line, error.line());
+    }
+}
@@ -0,0 +1,339 @@
+//! The ISO 4217 currency lists, as published by SIX
+//!
+//! SIX, the maintenance agency for ISO 4217, publishes the current currencies as `list-one.xml`,
+//! and the currencies which have been withdrawn as `list-three.xml`. Each `<CcyNtry>` (or
+//! `<HstrcCcyNtry>`) element lists the upper-cased English name of a country in `<CtryNm>`, and
+//! one currency used there, so a country with several currencies has several entries. Entries
+//! are also listed for entities which are not countries, such as the European Union, and for
+//! countries with no universal currency, which have no code.
+
+use quick_xml::{
+    escape::{EscapeError, resolve_predefined_entity},
+    events::Event,
+};
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Read,
+};
+
+/// The current currencies, in the format of SIX's `list-one.xml`, as of [`SNAPSHOT_DATE`].
+pub const LIST_ONE: &str = include_str!("iso4217/list-one.xml");
+
+/// The withdrawn currencies, in the format of SIX's `list-three.xml`, as of [`SNAPSHOT_DATE`].
+pub const LIST_THREE: &str = include_str!("iso4217/list-three.xml");
+
+/// The date [`LIST_ONE`] and [`LIST_THREE`] were last updated.
+pub const SNAPSHOT_DATE: &str = "2025-12-29";
+
+/// An entry in one of the currency lists.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Entry {
+    /// The upper-cased English name of the country or entity, e.g. `GERMANY`
+    pub country: String,
+
+    /// The English name of the currency, e.g. `Euro`
+    pub name: String,
+
+    /// The alphabetic code of the currency, which is missing for countries with no universal
+    /// currency
+    pub code: Option<String>,
+
+    /// The numeric code of the currency
+    pub numeric: Option<u16>,
+
+    /// The number of digits after the decimal separator, which is missing when not applicable,
+    /// e.g. for gold, and in the list of withdrawn currencies
+    pub minor_units: Option<u8>,
+
+    /// Whether the currency is a fund, e.g. the Mexican Unidad de Inversion
+    pub fund: bool,
+
+    /// When the currency was withdrawn from the country, in `YYYY-MM` or `YYYY-MM to YYYY-MM`
+    /// form, for entries in the list of withdrawn currencies
+    pub withdrawn: Option<String>,
+}
+
+/// The underlying cause of a [`ReadError`].
+#[derive(Debug)]
+enum ReadErrorKind {
+    Xml(quick_xml::Error),
+    Value(&'static str, String),
+}
+
+/// An error reading an ISO 4217 currency list.
+#[derive(Debug)]
+pub struct ReadError {
+    line: Option<u64>,
+    inner: ReadErrorKind,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    fn new(inner: ReadErrorKind, bytes: &[u8], position: u64) -> Self {
+        let end = usize::try_from(position)
+            .unwrap_or(usize::MAX)
+            .min(bytes.len());
+        let line = u64::try_from(bytes[..end].split(|&b| b == b'\n').count()).ok();
+
+        Self { line, inner }
+    }
+
+    fn xml(inner: impl Into<quick_xml::Error>, bytes: &[u8], position: u64) -> Self {
+        Self::new(ReadErrorKind::Xml(inner.into()), bytes, position)
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+
+        match &self.inner {
+            ReadErrorKind::Xml(inner) => inner.fmt(f),
+            ReadErrorKind::Value(element, value) => write!(f, "invalid {element} `{value}`"),
+        }
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.inner {
+            ReadErrorKind::Xml(inner) => Some(inner),
+            ReadErrorKind::Value(..) => None,
+        }
+    }
+}
+
+/// Read the entries of either `list-one.xml` or `list-three.xml`.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file is not valid XML, or a code or number of minor units is invalid.
+pub fn read<R: Read>(mut reader: R) -> Result<Vec<Entry>, ReadError> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|error| ReadError::xml(error, &bytes, 0))?;
+
+    let mut xml = quick_xml::Reader::from_reader(bytes.as_slice());
+    let mut retval = Vec::new();
+    let mut entry = Option::<Entry>::None;
+    let mut text = String::new();
+
+    loop {
+        let position = xml.buffer_position();
+        let event = xml
+            .read_event()
+            .map_err(|error| ReadError::xml(error, &bytes, xml.error_position()))?;
+
+        match event {
+            Event::Start(start) => {
+                match start.name().as_ref() {
+                    b"CcyNtry" | b"HstrcCcyNtry" => entry = Some(Entry::default()),
+                    b"CcyNm" => {
+                        if let Some(entry) = &mut entry {
+                            entry.fund = start
+                                .try_get_attribute("IsFund")
+                                .map_err(|error| ReadError::xml(error, &bytes, position))?
+                                .is_some_and(|attribute| {
+                                    attribute.value.eq_ignore_ascii_case(b"true")
+                                });
+                        }
+                    }
+                    _ => {}
+                }
+
+                text.clear();
+            }
+            Event::Text(value) => text.push_str(
+                &value
+                    .decode()
+                    .map_err(|error| ReadError::xml(error, &bytes, position))?,
+            ),
+            Event::GeneralRef(reference) => {
+                if let Some(c) = reference
+                    .resolve_char_ref()
+                    .map_err(|error| ReadError::xml(error, &bytes, position))?
+                {
+                    text.push(c);
+                } else {
+                    let name = reference
+                        .decode()
+                        .map_err(|error| ReadError::xml(error, &bytes, position))?;
+                    let value = resolve_predefined_entity(&name).ok_or_else(|| {
+                        let error =
+                            EscapeError::UnrecognizedEntity(0..name.len(), name.to_string());
+                        ReadError::xml(error, &bytes, position)
+                    })?;
+                    text.push_str(value);
+                }
+            }
+            Event::End(end) => {
+                let Some(current) = &mut entry else {
+                    continue;
+                };
+
+                let value = text.trim().to_owned();
+                match end.name().as_ref() {
+                    b"CcyNtry" | b"HstrcCcyNtry" => retval.extend(entry.take()),
+                    b"CtryNm" => current.country = value,
+                    b"CcyNm" => current.name = value,
+                    b"Ccy" => current.code = Some(value),
+                    b"CcyNbr" => {
+                        current.numeric = Some(value.parse().map_err(|_| {
+                            ReadError::new(ReadErrorKind::Value("CcyNbr", value), &bytes, position)
+                        })?);
+                    }
+                    b"CcyMnrUnts" if value == "N.A." => {}
+                    b"CcyMnrUnts" => {
+                        current.minor_units = Some(value.parse().map_err(|_| {
+                            let kind = ReadErrorKind::Value("CcyMnrUnts", value);
+                            ReadError::new(kind, &bytes, position)
+                        })?);
+                    }
+                    b"WthdrwlDt" => current.withdrawn = Some(value),
+                    _ => {}
+                }
+
+                text.clear();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(retval)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Entry, LIST_ONE, LIST_THREE, read};
+
+    const SIX: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<ISO_4217 Pblshd="2024-06-25">
+    <CcyTbl>
+        <CcyNtry>
+            <CtryNm>ANTARCTICA</CtryNm>
+            <CcyNm>No universal currency</CcyNm>
+        </CcyNtry>
+        <CcyNtry>
+            <CtryNm>BOLIVIA (PLURINATIONAL STATE OF)</CtryNm>
+            <CcyNm IsFund="true">Mvdol</CcyNm>
+            <Ccy>BOV</Ccy>
+            <CcyNbr>984</CcyNbr>
+            <CcyMnrUnts>2</CcyMnrUnts>
+        </CcyNtry>
+        <CcyNtry>
+            <CtryNm>ZZ08_Gold</CtryNm>
+            <CcyNm>Gold</CcyNm>
+            <Ccy>XAU</Ccy>
+            <CcyNbr>959</CcyNbr>
+            <CcyMnrUnts>N.A.</CcyMnrUnts>
+        </CcyNtry>
+        <CcyNtry>
+            <CtryNm>BOSNIA &amp; HERZEGOVINA</CtryNm>
+            <CcyNm>Convertible Mark</CcyNm>
+            <Ccy>BAM</Ccy>
+            <CcyNbr>977</CcyNbr>
+            <CcyMnrUnts>2</CcyMnrUnts>
+        </CcyNtry>
+    </CcyTbl>
+</ISO_4217>"#;
+
+    fn entries(list: &str, country: &str) -> Vec<Entry> {
+        read(list.as_bytes())
+            .expect("valid list")
+            .into_iter()
+            .filter(|entry| entry.country == country)
+            .collect()
+    }
+
+    #[test]
+    fn six_format() {
+        let entries = read(SIX.as_bytes()).expect("valid list");
+
+        assert_eq!(
+            vec![
+                Entry {
+                    country: "ANTARCTICA".into(),
+                    name: "No universal currency".into(),
+                    ..Entry::default()
+                },
+                Entry {
+                    country: "BOLIVIA (PLURINATIONAL STATE OF)".into(),
+                    name: "Mvdol".into(),
+                    code: Some("BOV".into()),
+                    numeric: Some(984),
+                    minor_units: Some(2),
+                    fund: true,
+                    withdrawn: None,
+                },
+                Entry {
+                    country: "ZZ08_Gold".into(),
+                    name: "Gold".into(),
+                    code: Some("XAU".into()),
+                    numeric: Some(959),
+                    minor_units: None,
+                    fund: false,
+                    withdrawn: None,
+                },
+                Entry {
+                    country: "BOSNIA & HERZEGOVINA".into(),
+                    name: "Convertible Mark".into(),
+                    code: Some("BAM".into()),
+                    numeric: Some(977),
+                    minor_units: Some(2),
+                    fund: false,
+                    withdrawn: None,
+                },
+            ],
+            entries
+        );
+    }
+
+    #[yare::parameterized(
+        switzerland = { "SWITZERLAND", &["CHE", "CHF", "CHW"] },
+        bhutan = { "BHUTAN", &["BTN", "INR"] },
+        germany = { "GERMANY", &["EUR"] },
+        antarctica = { "ANTARCTICA", &[] },
+    )]
+    fn list_one(country: &str, codes: &[&str]) {
+        let entries = entries(LIST_ONE, country);
+
+        assert_ne!(0, entries.len());
+        assert_eq!(
+            codes,
+            entries
+                .iter()
+                .filter_map(|entry| entry.code.as_deref())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn list_three() {
+        let entries = entries(LIST_THREE, "GERMANY");
+
+        assert_eq!(1, entries.len());
+        assert_eq!(Some("DEM"), entries[0].code.as_deref());
+        assert_eq!(Some(276), entries[0].numeric);
+        assert_eq!(Some("2002-03"), entries[0].withdrawn.as_deref());
+    }
+
+    #[test]
+    fn read_error_line() {
+        let error =
+            read("<CcyTbl>\n<CcyNtry>\n<CcyNbr>ABC</CcyNbr>\n</CcyNtry>\n</CcyTbl>".as_bytes())
+                .expect_err("invalid");
+
+        assert_eq!(Some(3), error.line());
+        assert_eq!("line 3: invalid CcyNbr `ABC`", error.to_string());
+    }
+}
@@ -0,0 +1,144 @@
+//! Serde support for the [`iso-codes`](https://salsa.debian.org/iso-codes-team/iso-codes) JSON
+//! files maintained by Debian
+//!
+//! Unlike the other sources, `iso_3166-1.json` contains the ISO formal ("official") names of most
+//! countries, and common names for some of those whose short name is inverted, e.g.
+//! `Korea, Republic of`. The `iso-codes` data is licensed under the LGPL-2.1 or later.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Read,
+};
+
+/// A snapshot of `iso_3166-1.json` from `iso-codes` [`SNAPSHOT_VERSION`].
+pub const SNAPSHOT: &str = include_str!("iso_codes/4.15.0-3166-1.json");
+
+/// The `iso-codes` release [`SNAPSHOT`] was taken from.
+pub const SNAPSHOT_VERSION: &str = "4.15.0";
+
+/// A record in `iso_3166-1.json`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Record {
+    /// The alpha-2 code
+    pub alpha_2: String,
+
+    /// The alpha-3 code
+    pub alpha_3: String,
+
+    /// The numeric code, as three digits
+    pub numeric: String,
+
+    /// The English short name
+    pub name: String,
+
+    /// The English formal name, if it differs from the short name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub official_name: Option<String>,
+
+    /// The commonly used name, if it differs from the short name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_name: Option<String>,
+
+    /// The flag emoji
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flag: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct File {
+    #[serde(rename = "3166-1")]
+    records: Vec<Record>,
+}
+
+/// An error reading an `iso-codes` JSON file.
+#[derive(Debug)]
+pub struct ReadError {
+    inner: serde_json::Error,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        match self.inner.line() {
+            0 => None,
+            line => u64::try_from(line).ok(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ReadError {
+    fn from(inner: serde_json::Error) -> Self {
+        Self { inner }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.inner.fmt(f)
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+/// Read the records of `iso_3166-1.json`.
+///
+/// The file is parsed in full before the first record is returned, so a syntax error anywhere in
+/// the file is returned as the only item.
+pub fn read_3166_1<R: Read>(reader: R) -> impl Iterator<Item = Result<Record, ReadError>> {
+    let (records, error) = match serde_json::from_reader::<_, File>(reader) {
+        Ok(file) => (file.records, None),
+        Err(error) => (Vec::new(), Some(ReadError::from(error))),
+    };
+
+    error
+        .into_iter()
+        .map(Err)
+        .chain(records.into_iter().map(Ok))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SNAPSHOT, read_3166_1};
+
+    #[test]
+    fn snapshot() {
+        let records = read_3166_1(SNAPSHOT.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records");
+
+        assert_eq!(249, records.len());
+
+        let korea = records
+            .iter()
+            .find(|record| record.alpha_2 == "KR")
+            .expect("Korea");
+        assert_eq!("Korea, Republic of", korea.name);
+        assert_eq!(Some("South Korea"), korea.common_name.as_deref());
+        assert_eq!(None, korea.official_name);
+
+        let usa = records
+            .iter()
+            .find(|record| record.numeric == "840")
+            .expect("USA");
+        assert_eq!(
+            Some("United States of America"),
+            usa.official_name.as_deref()
+        );
+    }
+
+    #[test]
+    fn read_error_line() {
+        let error = read_3166_1("{\n\"3166-1\": [\n{\"name\": 1}]}".as_bytes())
+            .find_map(Result::err)
+            .expect("invalid record");
+
+        assert_eq!(Some(3), error.line());
+    }
+}
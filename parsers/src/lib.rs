@@ -2,5 +2,18 @@
 
 #![doc = include_str!("../README.md")]
 
+pub mod boundaries;
+pub mod cldr;
+pub mod e164;
+pub mod geo;
+pub mod groups;
+pub mod iban;
+pub mod iso4217;
+pub mod iso_codes;
 pub mod lukes;
 pub mod m49;
+pub mod neighbours;
+pub mod psl;
+pub mod sovereignty;
+mod table;
+pub mod tzdata;
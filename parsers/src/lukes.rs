@@ -1,8 +1,24 @@
-//! Serde support for Luke Duncalfe's combined JSON
+//! Serde support for Luke Duncalfe's combined JSON, and its CSV, XML and slim variants
 
+use quick_xml::events::Event;
 use serde::{Deserialize, Serialize};
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{self, Read, Write},
+    str::FromStr,
+};
+
+/// A snapshot of Luke Duncalfe's `all.json`, retrieved on [`SNAPSHOT_DATE`].
+pub const SNAPSHOT: &str = include_str!("lukes/2025-12-29.json");
+
+/// The date [`SNAPSHOT`] was retrieved from upstream.
+pub const SNAPSHOT_DATE: &str = "2025-12-29";
 
 /// A record in the `all.json`.
+///
+/// Upstream's hyphenated keys, such as `alpha-2`, are accepted when deserializing, while the
+/// record serializes with its field names. [`write()`] writes records with the upstream keys.
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct Record {
     /// The name of the country record
@@ -26,9 +42,9 @@ pub struct Record {
 
     /// The M49 region name
     pub region: Option<String>,
-    #[serde(alias = "sub-region")]
 
     /// The M49 sub-region name
+    #[serde(alias = "sub-region")]
     pub sub_region: Option<String>,
 
     /// The M49 intermediate-region name
@@ -48,16 +64,942 @@ pub struct Record {
     pub intermediate_region_code: Option<String>,
 }
 
+/// A [`Record`] as written to the `all.json`, with the upstream keys.
+#[derive(Serialize)]
+struct UpstreamRecord<'a> {
+    name: Option<&'a str>,
+    #[serde(rename = "alpha-2")]
+    alpha_2: Option<&'a str>,
+    #[serde(rename = "alpha-3")]
+    alpha_3: Option<&'a str>,
+    #[serde(rename = "country-code")]
+    country_code: Option<&'a str>,
+    #[serde(rename = "iso_3166-2")]
+    iso_3166_2: Option<&'a str>,
+    region: Option<&'a str>,
+    #[serde(rename = "sub-region")]
+    sub_region: Option<&'a str>,
+    #[serde(rename = "intermediate-region")]
+    intermediate_region: Option<&'a str>,
+    #[serde(rename = "region-code")]
+    region_code: Option<&'a str>,
+    #[serde(rename = "sub-region-code")]
+    sub_region_code: Option<&'a str>,
+    #[serde(rename = "intermediate-region-code")]
+    intermediate_region_code: Option<&'a str>,
+}
+
+impl<'a> From<&'a Record> for UpstreamRecord<'a> {
+    fn from(value: &'a Record) -> Self {
+        Self {
+            name: value.name.as_deref(),
+            alpha_2: value.alpha_2.as_deref(),
+            alpha_3: value.alpha_3.as_deref(),
+            country_code: value.country_code.as_deref(),
+            iso_3166_2: value.iso_3166_2.as_deref(),
+            region: value.region.as_deref(),
+            sub_region: value.sub_region.as_deref(),
+            intermediate_region: value.intermediate_region.as_deref(),
+            region_code: value.region_code.as_deref(),
+            sub_region_code: value.sub_region_code.as_deref(),
+            intermediate_region_code: value.intermediate_region_code.as_deref(),
+        }
+    }
+}
+
+impl Record {
+    /// Set the field with the given upstream key, ignoring unknown keys.
+    fn set(&mut self, key: &[u8], value: String) {
+        let field = match key {
+            b"name" => &mut self.name,
+            b"alpha-2" => &mut self.alpha_2,
+            b"alpha-3" => &mut self.alpha_3,
+            b"country-code" => &mut self.country_code,
+            b"iso_3166-2" => &mut self.iso_3166_2,
+            b"region" => &mut self.region,
+            b"sub-region" => &mut self.sub_region,
+            b"intermediate-region" => &mut self.intermediate_region,
+            b"region-code" => &mut self.region_code,
+            b"sub-region-code" => &mut self.sub_region_code,
+            b"intermediate-region-code" => &mut self.intermediate_region_code,
+            _ => return,
+        };
+
+        *field = Some(value);
+    }
+}
+
+/// A record in the `slim-2.json`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Slim2Record {
+    /// The name of the country record
+    pub name: Option<String>,
+
+    /// The Alpha2 Code
+    #[serde(rename = "alpha-2", alias = "alpha_2")]
+    pub alpha_2: Option<String>,
+
+    /// The numeric code
+    #[serde(rename = "country-code", alias = "country_code")]
+    pub country_code: Option<String>,
+}
+
+impl From<&Record> for Slim2Record {
+    fn from(value: &Record) -> Self {
+        Self {
+            name: value.name.clone(),
+            alpha_2: value.alpha_2.clone(),
+            country_code: value.country_code.clone(),
+        }
+    }
+}
+
+/// A record in the `slim-3.json`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Slim3Record {
+    /// The name of the country record
+    pub name: Option<String>,
+
+    /// The Alpha3 code
+    #[serde(rename = "alpha-3", alias = "alpha_3")]
+    pub alpha_3: Option<String>,
+
+    /// The numeric code
+    #[serde(rename = "country-code", alias = "country_code")]
+    pub country_code: Option<String>,
+}
+
+impl From<&Record> for Slim3Record {
+    fn from(value: &Record) -> Self {
+        Self {
+            name: value.name.clone(),
+            alpha_3: value.alpha_3.clone(),
+            country_code: value.country_code.clone(),
+        }
+    }
+}
+
+/// The distributions of Luke Duncalfe's data set, named after their upstream files.
+///
+/// The `slim-2` and `slim-3` distributions only contain the name, numeric code, and one of the
+/// alpha codes. Their records read into a [`Record`] with the remaining fields unset.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Format {
+    /// `all.json`
+    #[default]
+    AllJson,
+    /// `all.csv`
+    AllCsv,
+    /// `all.xml`
+    AllXml,
+    /// `slim-2.json`
+    Slim2Json,
+    /// `slim-2.csv`
+    Slim2Csv,
+    /// `slim-2.xml`
+    Slim2Xml,
+    /// `slim-3.json`
+    Slim3Json,
+    /// `slim-3.csv`
+    Slim3Csv,
+    /// `slim-3.xml`
+    Slim3Xml,
+}
+
+impl Format {
+    /// Every format, in upstream order.
+    pub const ALL: [Format; 9] = [
+        Format::AllJson,
+        Format::AllCsv,
+        Format::AllXml,
+        Format::Slim2Json,
+        Format::Slim2Csv,
+        Format::Slim2Xml,
+        Format::Slim3Json,
+        Format::Slim3Csv,
+        Format::Slim3Xml,
+    ];
+
+    /// The name of the upstream file in this format.
+    #[must_use]
+    pub const fn file_name(self) -> &'static str {
+        match self {
+            Format::AllJson => "all.json",
+            Format::AllCsv => "all.csv",
+            Format::AllXml => "all.xml",
+            Format::Slim2Json => "slim-2.json",
+            Format::Slim2Csv => "slim-2.csv",
+            Format::Slim2Xml => "slim-2.xml",
+            Format::Slim3Json => "slim-3.json",
+            Format::Slim3Csv => "slim-3.csv",
+            Format::Slim3Xml => "slim-3.xml",
+        }
+    }
+
+    /// Whether records in this format contain an alpha-2 code.
+    #[must_use]
+    pub const fn has_alpha2(self) -> bool {
+        !matches!(
+            self,
+            Format::Slim3Json | Format::Slim3Csv | Format::Slim3Xml
+        )
+    }
+
+    /// Whether records in this format contain an alpha-3 code.
+    #[must_use]
+    pub const fn has_alpha3(self) -> bool {
+        !matches!(
+            self,
+            Format::Slim2Json | Format::Slim2Csv | Format::Slim2Xml
+        )
+    }
+
+    /// Whether records in this format contain the ISO 3166-2 reference and M49 regions.
+    #[must_use]
+    pub const fn has_regions(self) -> bool {
+        matches!(self, Format::AllJson | Format::AllCsv | Format::AllXml)
+    }
+
+    const fn encoding(self) -> Encoding {
+        match self {
+            Format::AllJson | Format::Slim2Json | Format::Slim3Json => Encoding::Json,
+            Format::AllCsv | Format::Slim2Csv | Format::Slim3Csv => Encoding::Csv,
+            Format::AllXml | Format::Slim2Xml | Format::Slim3Xml => Encoding::Xml,
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.file_name())
+    }
+}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Format::ALL
+            .into_iter()
+            .find(|format| format.file_name() == s)
+            .ok_or(ParseFormatError)
+    }
+}
+
+/// An error parsing a [`Format`] from an upstream file name.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParseFormatError;
+
+impl Display for ParseFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("unknown format; expected one of: ")?;
+
+        for (index, format) in Format::ALL.into_iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "`{format}`")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StdError for ParseFormatError {}
+
+/// The file encodings upstream distributes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Encoding {
+    Json,
+    Csv,
+    Xml,
+}
+
+/// The underlying cause of a [`ReadError`].
+#[derive(Debug)]
+enum ReadErrorKind {
+    Json(serde_json::Error),
+    Csv(csv::Error),
+    Xml(quick_xml::Error),
+}
+
+/// An error reading one of Luke Duncalfe's files.
+#[derive(Debug)]
+pub struct ReadError {
+    line: Option<u64>,
+    inner: ReadErrorKind,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    fn xml(inner: quick_xml::Error, bytes: &[u8], position: u64) -> Self {
+        let end = usize::try_from(position)
+            .unwrap_or(usize::MAX)
+            .min(bytes.len());
+        let line = u64::try_from(bytes[..end].split(|&b| b == b'\n').count()).ok();
+
+        Self {
+            line,
+            inner: ReadErrorKind::Xml(inner),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ReadError {
+    fn from(inner: serde_json::Error) -> Self {
+        let line = match inner.line() {
+            0 => None,
+            line => u64::try_from(line).ok(),
+        };
+
+        Self {
+            line,
+            inner: ReadErrorKind::Json(inner),
+        }
+    }
+}
+
+impl From<csv::Error> for ReadError {
+    fn from(inner: csv::Error) -> Self {
+        let line = inner.position().map(csv::Position::line);
+
+        Self {
+            line,
+            inner: ReadErrorKind::Csv(inner),
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (&self.inner, self.line) {
+            // serde_json already includes the line and column
+            (ReadErrorKind::Json(inner), _) => inner.fmt(f),
+            (ReadErrorKind::Csv(inner), Some(line)) => write!(f, "line {line}: {inner}"),
+            (ReadErrorKind::Xml(inner), Some(line)) => write!(f, "line {line}: {inner}"),
+            (ReadErrorKind::Csv(inner), None) => inner.fmt(f),
+            (ReadErrorKind::Xml(inner), None) => inner.fmt(f),
+        }
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.inner {
+            ReadErrorKind::Json(inner) => Some(inner),
+            ReadErrorKind::Csv(inner) => Some(inner),
+            ReadErrorKind::Xml(inner) => Some(inner),
+        }
+    }
+}
+
+/// Read the records of Luke Duncalfe's `all.json`.
+///
+/// The JSON array is parsed in full before the first record is returned, so a syntax error
+/// anywhere in the file is returned as the only item.
+pub fn read<R: Read>(reader: R) -> impl Iterator<Item = Result<Record, ReadError>> {
+    read_format(Format::AllJson, reader)
+}
+
+/// Read the records of any of Luke Duncalfe's files.
+///
+/// JSON and XML files are parsed in full before the first record is returned, so a syntax error
+/// anywhere in the file is returned as the only item. CSV files return an error for each invalid
+/// row.
+pub fn read_format<R: Read>(
+    format: Format,
+    reader: R,
+) -> impl Iterator<Item = Result<Record, ReadError>> {
+    let results = match format.encoding() {
+        Encoding::Json => single_error(serde_json::from_reader(reader).map_err(ReadError::from)),
+        Encoding::Csv => csv::Reader::from_reader(reader)
+            .into_deserialize::<Record>()
+            .map(|result| result.map_err(ReadError::from))
+            .collect(),
+        Encoding::Xml => single_error(read_xml(reader)),
+    };
+
+    results.into_iter()
+}
+
+/// Flatten the result of parsing a whole file into per-record results.
+fn single_error(result: Result<Vec<Record>, ReadError>) -> Vec<Result<Record, ReadError>> {
+    match result {
+        Ok(records) => records.into_iter().map(Ok).collect(),
+        Err(error) => vec![Err(error)],
+    }
+}
+
+/// Read the `<country>` elements of an XML file, whose attributes are the record's fields.
+fn read_xml<R: Read>(mut reader: R) -> Result<Vec<Record>, ReadError> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|error| ReadError::xml(error.into(), &bytes, 0))?;
+
+    let mut xml = quick_xml::Reader::from_reader(bytes.as_slice());
+    let mut retval = Vec::new();
+
+    loop {
+        let event = xml
+            .read_event()
+            .map_err(|error| ReadError::xml(error, &bytes, xml.error_position()))?;
+
+        let element = match event {
+            Event::Start(element) | Event::Empty(element) => element,
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        if element.name().as_ref() != b"country" {
+            continue;
+        }
+
+        let mut record = Record::default();
+        for attribute in element.attributes() {
+            let position = xml.buffer_position();
+            let attribute =
+                attribute.map_err(|error| ReadError::xml(error.into(), &bytes, position))?;
+            let value = attribute
+                .decode_and_unescape_value(xml.decoder())
+                .map_err(|error| ReadError::xml(error, &bytes, position))?;
+
+            record.set(attribute.key.as_ref(), value.into_owned());
+        }
+
+        retval.push(record);
+    }
+
+    Ok(retval)
+}
+
+/// Write records as Luke Duncalfe's `all.json`.
+///
+/// The output is compact JSON with hyphenated keys in upstream order, without a trailing newline,
+/// so reading upstream's file with [`read()`] and writing the records back out reproduces the file
+/// byte-for-byte.
+///
+/// # Errors
+///
+/// - [`io::Error`] if the records could not be written.
+pub fn write<'a, W: Write>(
+    records: impl IntoIterator<Item = &'a Record>,
+    writer: W,
+) -> io::Result<()> {
+    write_json(
+        records
+            .into_iter()
+            .map(UpstreamRecord::from)
+            .collect::<Vec<_>>(),
+        writer,
+    )
+}
+
+/// Write records as Luke Duncalfe's `slim-2.json`, containing only the name, alpha-2 and numeric
+/// codes.
+///
+/// # Errors
+///
+/// - [`io::Error`] if the records could not be written.
+pub fn write_slim2<'a, W: Write>(
+    records: impl IntoIterator<Item = &'a Record>,
+    writer: W,
+) -> io::Result<()> {
+    write_json(
+        records
+            .into_iter()
+            .map(Slim2Record::from)
+            .collect::<Vec<_>>(),
+        writer,
+    )
+}
+
+/// Write records as Luke Duncalfe's `slim-3.json`, containing only the name, alpha-3 and numeric
+/// codes.
+///
+/// # Errors
+///
+/// - [`io::Error`] if the records could not be written.
+pub fn write_slim3<'a, W: Write>(
+    records: impl IntoIterator<Item = &'a Record>,
+    writer: W,
+) -> io::Result<()> {
+    write_json(
+        records
+            .into_iter()
+            .map(Slim3Record::from)
+            .collect::<Vec<_>>(),
+        writer,
+    )
+}
+
+fn write_json<T: Serialize, W: Write>(value: T, writer: W) -> io::Result<()> {
+    Ok(serde_json::to_writer(writer, &value)?)
+}
+
+/// A field of a [`Record`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Field {
+    /// The `name` field.
+    Name,
+    /// The `alpha-2` field.
+    Alpha2,
+    /// The `alpha-3` field.
+    Alpha3,
+    /// The `country-code` field.
+    CountryCode,
+    /// The `iso_3166-2` field.
+    Iso3166_2,
+    /// The `region` and `region-code` fields.
+    Region,
+    /// The `sub-region` and `sub-region-code` fields.
+    SubRegion,
+    /// The `intermediate-region` and `intermediate-region-code` fields.
+    IntermediateRegion,
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            Field::Name => "name",
+            Field::Alpha2 => "alpha-2",
+            Field::Alpha3 => "alpha-3",
+            Field::CountryCode => "country-code",
+            Field::Iso3166_2 => "iso_3166-2",
+            Field::Region => "region",
+            Field::SubRegion => "sub-region",
+            Field::IntermediateRegion => "intermediate-region",
+        })
+    }
+}
+
+/// The ways in which a field of a [`Record`] can be invalid.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The field is missing or empty.
+    Missing,
+    /// The field does not contain a valid code.
+    InvalidCode,
+    /// Only one of a region's name and code is present.
+    Incomplete,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match self {
+            ErrorKind::Missing => "missing or empty",
+            ErrorKind::InvalidCode => "not a valid code",
+            ErrorKind::Incomplete => "has only one of a name and code",
+        })
+    }
+}
+
+/// An error validating a [`Record`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Error {
+    /// The index of the record within its file, if known.
+    pub index: Option<usize>,
+    /// The invalid field.
+    pub field: Field,
+    /// Why the field is invalid.
+    pub kind: ErrorKind,
+}
+
+impl Error {
+    fn new(field: Field, kind: ErrorKind) -> Self {
+        Self {
+            index: None,
+            field,
+            kind,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(index) = self.index {
+            write!(f, "record {index}: ")?;
+        }
+
+        write!(f, "field `{}` is {}", self.field, self.kind)
+    }
+}
+
+impl StdError for Error {}
+
+/// An M49 region, sub-region, or intermediate region of a [`Country`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Region {
+    /// The M49 numeric code.
+    pub code: u16,
+    /// The English name.
+    pub name: String,
+}
+
+impl Region {
+    fn parse(
+        field: Field,
+        name: Option<String>,
+        code: Option<String>,
+    ) -> Result<Option<Self>, Error> {
+        match (non_empty(name), non_empty(code)) {
+            (None, None) => Ok(None),
+            (Some(name), Some(code)) => Ok(Some(Self {
+                code: parse_numeric(field, &code)?,
+                name,
+            })),
+            _ => Err(Error::new(field, ErrorKind::Incomplete)),
+        }
+    }
+}
+
+/// A validated [`Record`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Country {
+    /// The English short name.
+    pub name: String,
+    /// The alpha-2 code, as uppercase ASCII.
+    pub alpha2: [u8; 2],
+    /// The alpha-3 code, as uppercase ASCII.
+    pub alpha3: [u8; 3],
+    /// The numeric code.
+    pub numeric: u16,
+    /// The ISO 3166-2 reference, e.g. `ISO 3166-2:US`.
+    pub iso_3166_2: String,
+    /// The M49 region, if any.
+    pub region: Option<Region>,
+    /// The M49 sub-region, if any.
+    pub sub_region: Option<Region>,
+    /// The M49 intermediate region, if any.
+    pub intermediate_region: Option<Region>,
+}
+
+impl Country {
+    /// Get the alpha-2 code as a string slice.
+    #[must_use]
+    pub fn alpha2_str(&self) -> &str {
+        str::from_utf8(&self.alpha2).unwrap_or_default()
+    }
+
+    /// Get the alpha-3 code as a string slice.
+    #[must_use]
+    pub fn alpha3_str(&self) -> &str {
+        str::from_utf8(&self.alpha3).unwrap_or_default()
+    }
+}
+
+impl TryFrom<Record> for Country {
+    type Error = Error;
+
+    fn try_from(value: Record) -> Result<Self, Self::Error> {
+        let name = non_empty(value.name).ok_or(Error::new(Field::Name, ErrorKind::Missing))?;
+        let alpha2 = parse_alpha(Field::Alpha2, value.alpha_2)?;
+        let alpha3 = parse_alpha(Field::Alpha3, value.alpha_3)?;
+        let numeric = non_empty(value.country_code)
+            .ok_or(Error::new(Field::CountryCode, ErrorKind::Missing))
+            .and_then(|code| parse_numeric(Field::CountryCode, &code))?;
+        let iso_3166_2 =
+            non_empty(value.iso_3166_2).ok_or(Error::new(Field::Iso3166_2, ErrorKind::Missing))?;
+        let region = Region::parse(Field::Region, value.region, value.region_code)?;
+        let sub_region = Region::parse(Field::SubRegion, value.sub_region, value.sub_region_code)?;
+        let intermediate_region = Region::parse(
+            Field::IntermediateRegion,
+            value.intermediate_region,
+            value.intermediate_region_code,
+        )?;
+
+        Ok(Self {
+            name,
+            alpha2,
+            alpha3,
+            numeric,
+            iso_3166_2,
+            region,
+            sub_region,
+            intermediate_region,
+        })
+    }
+}
+
+/// Validate a list of records, such as the contents of `all.json`.
+///
+/// # Errors
+///
+/// - [`Error`] for the first invalid record, with its index set.
+pub fn validate(records: impl IntoIterator<Item = Record>) -> Result<Vec<Country>, Error> {
+    records
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| {
+            Country::try_from(record).map_err(|error| Error {
+                index: Some(index),
+                ..error
+            })
+        })
+        .collect()
+}
+
+/// Treat empty strings, which `all.json` uses for missing values, as missing.
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+fn parse_alpha<const N: usize>(field: Field, value: Option<String>) -> Result<[u8; N], Error> {
+    let value = non_empty(value).ok_or(Error::new(field, ErrorKind::Missing))?;
+
+    <[u8; N]>::try_from(value.as_bytes())
+        .ok()
+        .filter(|code| code.iter().all(u8::is_ascii_uppercase))
+        .ok_or(Error::new(field, ErrorKind::InvalidCode))
+}
+
+fn parse_numeric(field: Field, value: &str) -> Result<u16, Error> {
+    if value.len() != 3 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::new(field, ErrorKind::InvalidCode));
+    }
+
+    value
+        .parse::<u16>()
+        .map_err(|_| Error::new(field, ErrorKind::InvalidCode))
+}
+
 #[cfg(test)]
 mod test {
-    use super::Record;
+    use super::{
+        Country, Error, ErrorKind, Field, Format, Record, Region, SNAPSHOT, Slim2Record,
+        Slim3Record, read, read_format, validate, write, write_slim2, write_slim3,
+    };
+
+    const ALL_CSV: &str = "\
+name,alpha-2,alpha-3,country-code,iso_3166-2,region,sub-region,intermediate-region,region-code,sub-region-code,intermediate-region-code
+Antarctica,AQ,ATA,010,ISO 3166-2:AQ,,,,,,
+\"Bonaire, Sint Eustatius and Saba\",BQ,BES,535,ISO 3166-2:BQ,Americas,Latin America and the Caribbean,Caribbean,019,419,029
+Côte d'Ivoire,CI,CIV,384,ISO 3166-2:CI,Africa,Sub-Saharan Africa,Western Africa,002,202,011
+";
 
-    const ALL_JSON: &str = include_str!("lukes/2025-12-29.json");
+    const ALL_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<countries>
+  <country name="Antarctica" alpha-2="AQ" alpha-3="ATA" country-code="010" iso_3166-2="ISO 3166-2:AQ" region="" sub-region="" intermediate-region="" region-code="" sub-region-code="" intermediate-region-code=""/>
+  <country name="Bonaire, Sint Eustatius and Saba" alpha-2="BQ" alpha-3="BES" country-code="535" iso_3166-2="ISO 3166-2:BQ" region="Americas" sub-region="Latin America and the Caribbean" intermediate-region="Caribbean" region-code="019" sub-region-code="419" intermediate-region-code="029"/>
+  <country name="C&#244;te d&apos;Ivoire" alpha-2="CI" alpha-3="CIV" country-code="384" iso_3166-2="ISO 3166-2:CI" region="Africa" sub-region="Sub-Saharan Africa" intermediate-region="Western Africa" region-code="002" sub-region-code="202" intermediate-region-code="011"/>
+</countries>
+"#;
+
+    fn records() -> Vec<Record> {
+        read(SNAPSHOT.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid json")
+    }
 
     #[test]
     fn all_json() {
-        let records = serde_json::from_str::<Vec<Record>>(ALL_JSON).expect("valid json");
+        assert_eq!(249, records().len());
+    }
+
+    #[test]
+    fn write_round_trip() {
+        let mut output = Vec::new();
+        write(&records(), &mut output).expect("written");
+
+        assert_eq!(SNAPSHOT, String::from_utf8(output).expect("utf-8"));
+    }
+
+    #[test]
+    fn record_serde() {
+        let record = &records()[0];
+        let json = serde_json::to_string(record).expect("serialized");
+        assert!(json.starts_with(r#"{"name":"Afghanistan","alpha_2":"AF","alpha_3":"AFG""#));
+
+        let record = serde_json::from_str::<Record>(&json).expect("deserialized");
+        assert_eq!(Some("AF"), record.alpha_2.as_deref());
+    }
+
+    #[test]
+    fn write_slim() {
+        let records = records();
+
+        let mut slim2 = Vec::new();
+        write_slim2(&records, &mut slim2).expect("written");
+        let slim2 = String::from_utf8(slim2).expect("utf-8");
+        assert!(slim2.starts_with(
+            r#"[{"name":"Afghanistan","alpha-2":"AF","country-code":"004"},{"name":"Åland Islands""#
+        ));
+
+        let mut slim3 = Vec::new();
+        write_slim3(&records, &mut slim3).expect("written");
+        let slim3 = String::from_utf8(slim3).expect("utf-8");
+        assert!(
+            slim3.starts_with(r#"[{"name":"Afghanistan","alpha-3":"AFG","country-code":"004"}"#)
+        );
+
+        let slim2 = serde_json::from_str::<Vec<Slim2Record>>(&slim2).expect("valid json");
+        let slim3 = serde_json::from_str::<Vec<Slim3Record>>(&slim3).expect("valid json");
+        assert_eq!(249, slim2.len());
+        assert_eq!(249, slim3.len());
+        assert_eq!(Some("ZW"), slim2[248].alpha_2.as_deref());
+        assert_eq!(Some("ZWE"), slim3[248].alpha_3.as_deref());
+    }
+
+    #[yare::parameterized(
+        csv = { Format::AllCsv, ALL_CSV },
+        xml = { Format::AllXml, ALL_XML },
+    )]
+    fn read_all(format: Format, input: &str) {
+        let records = read_format(format, input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records");
+        let countries = validate(records).expect("valid countries");
+
+        assert_eq!(3, countries.len());
+        assert_eq!(None, countries[0].region);
+        assert_eq!("Bonaire, Sint Eustatius and Saba", countries[1].name);
+        assert_eq!(
+            Some(Region {
+                code: 29,
+                name: "Caribbean".to_owned()
+            }),
+            countries[1].intermediate_region
+        );
+        assert_eq!("Côte d'Ivoire", countries[2].name);
+        assert_eq!(384, countries[2].numeric);
+    }
+
+    #[yare::parameterized(
+        slim2_json = { Format::Slim2Json, r#"[{"name":"Germany","alpha-2":"DE","country-code":"276"}]"# },
+        slim2_csv = { Format::Slim2Csv, "name,alpha-2,country-code\nGermany,DE,276\n" },
+        slim2_xml = { Format::Slim2Xml, r#"<countries><country name="Germany" alpha-2="DE" country-code="276"/></countries>"# },
+        slim3_json = { Format::Slim3Json, r#"[{"name":"Germany","alpha-3":"DEU","country-code":"276"}]"# },
+        slim3_csv = { Format::Slim3Csv, "name,alpha-3,country-code\nGermany,DEU,276\n" },
+        slim3_xml = { Format::Slim3Xml, r#"<countries><country name="Germany" alpha-3="DEU" country-code="276"/></countries>"# },
+    )]
+    fn read_slim(format: Format, input: &str) {
+        let records = read_format(format, input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records");
+
+        assert_eq!(1, records.len());
+        let record = &records[0];
+        assert_eq!(Some("Germany"), record.name.as_deref());
+        assert_eq!(Some("276"), record.country_code.as_deref());
+        assert_eq!(format.has_alpha2(), record.alpha_2.is_some());
+        assert_eq!(format.has_alpha3(), record.alpha_3.is_some());
+        assert_eq!(None, record.iso_3166_2);
+    }
+
+    #[test]
+    fn format_names() {
+        for format in Format::ALL {
+            assert_eq!(Ok(format), format.file_name().parse());
+        }
+
+        assert!("all.yaml".parse::<Format>().is_err());
+    }
+
+    #[yare::parameterized(
+        csv = { Format::AllCsv, "name,alpha-2\nGermany,DE\nFrance,FR,extra\n", 3 },
+        xml = { Format::AllXml, "<countries>\n<country name=\"Germany\"/>\n<country name=\"France/>\n</countries>", 3 },
+    )]
+    fn read_format_error_line(format: Format, input: &str, line: u64) {
+        let error = read_format(format, input.as_bytes())
+            .find_map(Result::err)
+            .expect("invalid input");
+
+        assert_eq!(Some(line), error.line());
+        assert!(error.to_string().starts_with(&format!("line {line}: ")));
+    }
+
+    #[test]
+    fn read_error_line() {
+        let input = "[\n{\"name\": \"Germany\"},\n{\"name\": 276}\n]";
+        let results = read(input.as_bytes()).collect::<Vec<_>>();
+
+        assert_eq!(1, results.len());
+        let error = results[0].as_ref().expect_err("invalid record");
+        assert_eq!(Some(3), error.line());
+    }
+
+    #[test]
+    fn all_json_validates() {
+        let records = records();
+        let countries = validate(records).expect("valid records");
+
+        assert_eq!(249, countries.len());
+
+        let usa = countries
+            .iter()
+            .find(|country| country.numeric == 840)
+            .expect("USA");
+        assert_eq!(b"US", &usa.alpha2);
+        assert_eq!("USA", usa.alpha3_str());
+        assert_eq!(
+            Some(Region {
+                code: 19,
+                name: "Americas".to_owned()
+            }),
+            usa.region
+        );
+        assert_eq!(None, usa.intermediate_region);
+
+        let antarctica = countries
+            .iter()
+            .find(|country| country.alpha2_str() == "AQ")
+            .expect("Antarctica");
+        assert_eq!(None, antarctica.region);
+    }
+
+    fn record() -> Record {
+        Record {
+            name: Some("Germany".to_owned()),
+            alpha_2: Some("DE".to_owned()),
+            alpha_3: Some("DEU".to_owned()),
+            country_code: Some("276".to_owned()),
+            iso_3166_2: Some("ISO 3166-2:DE".to_owned()),
+            region: Some("Europe".to_owned()),
+            sub_region: Some("Western Europe".to_owned()),
+            intermediate_region: Some(String::new()),
+            region_code: Some("150".to_owned()),
+            sub_region_code: Some("155".to_owned()),
+            intermediate_region_code: Some(String::new()),
+        }
+    }
+
+    #[yare::parameterized(
+        empty_name = { Record { name: Some(String::new()), ..record() }, Field::Name, ErrorKind::Missing },
+        long_alpha2 = { Record { alpha_2: Some("DEU".to_owned()), ..record() }, Field::Alpha2, ErrorKind::InvalidCode },
+        lower_alpha3 = { Record { alpha_3: Some("deu".to_owned()), ..record() }, Field::Alpha3, ErrorKind::InvalidCode },
+        missing_code = { Record { country_code: None, ..record() }, Field::CountryCode, ErrorKind::Missing },
+        bad_code = { Record { country_code: Some("27a".to_owned()), ..record() }, Field::CountryCode, ErrorKind::InvalidCode },
+        region = { Record { region_code: Some(String::new()), ..record() }, Field::Region, ErrorKind::Incomplete },
+        sub_region = { Record { sub_region_code: Some("15".to_owned()), ..record() }, Field::SubRegion, ErrorKind::InvalidCode },
+    )]
+    fn invalid(input: Record, field: Field, kind: ErrorKind) {
+        let expected = Error {
+            index: None,
+            field,
+            kind,
+        };
+
+        assert_eq!(Err(expected), Country::try_from(input));
+    }
+
+    #[test]
+    fn index() {
+        let records = vec![record(), Record::default()];
+        let error = validate(records).expect_err("invalid record");
 
-        assert_eq!(249, records.len());
+        assert_eq!(Some(1), error.index);
+        assert_eq!(
+            "record 1: field `name` is missing or empty",
+            error.to_string()
+        );
     }
 }
@@ -4,7 +4,44 @@
 //! source for the ISO 3166-1 and 3166-2 standards. These standards do not, however, cost CHF300
 //! (Swiss Francs) to download.
 
+pub use crate::table::ReadError;
+
+use crate::table;
+use csv::{Terminator, WriterBuilder};
 use serde::{Deserialize, Serialize, de::Deserializer};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{self, Read, Write},
+};
+
+/// A snapshot of the UN's English M49 CSV file, retrieved on [`SNAPSHOT_DATE`].
+pub const SNAPSHOT: &str = include_str!("m49/2025-09-05.csv");
+
+/// The date [`SNAPSHOT`] was retrieved from the UN.
+pub const SNAPSHOT_DATE: &str = "2025-09-05";
+
+/// The UTF-8 byte-order mark which begins the UN's CSV files.
+const BOM: &str = "\u{feff}";
+
+/// The header row of the UN's English CSV file.
+const HEADERS: [&str; 15] = [
+    "Global Code",
+    "Global Name",
+    "Region Code",
+    "Region Name",
+    "Sub-region Code",
+    "Sub-region Name",
+    "Intermediate Region Code",
+    "Intermediate Region Name",
+    "Country or Area",
+    "M49 Code",
+    "ISO-alpha2 Code",
+    "ISO-alpha3 Code",
+    "Least Developed Countries (LDC)",
+    "Land Locked Developing Countries (LLDC)",
+    "Small Island Developing States (SIDS)",
+];
 
 /// A M49 CSV Record.
 #[derive(Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -79,6 +116,185 @@ pub struct Record {
     pub small_island_developing_state: bool,
 }
 
+/// The six official languages of the United Nations, in which the M49 table is published.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Language {
+    /// Arabic (`ar`)
+    Arabic,
+    /// Chinese (`zh`)
+    Chinese,
+    /// English (`en`)
+    English,
+    /// French (`fr`)
+    French,
+    /// Russian (`ru`)
+    Russian,
+    /// Spanish (`es`)
+    Spanish,
+}
+
+impl Language {
+    /// Every language, in the UN's (alphabetical) order.
+    pub const ALL: [Language; 6] = [
+        Language::Arabic,
+        Language::Chinese,
+        Language::English,
+        Language::French,
+        Language::Russian,
+        Language::Spanish,
+    ];
+
+    /// The ISO 639-1 code of the language.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Language::Arabic => "ar",
+            Language::Chinese => "zh",
+            Language::English => "en",
+            Language::French => "fr",
+            Language::Russian => "ru",
+            Language::Spanish => "es",
+        }
+    }
+
+    /// Find the language with the given ISO 639-1 code.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code() == code)
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.code())
+    }
+}
+
+/// The names of a country or area in each of the UN's languages.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Names {
+    names: [Option<String>; 6],
+}
+
+impl Names {
+    /// Get the name in the given language, if it was present in the merged tables.
+    #[must_use]
+    pub fn get(&self, language: Language) -> Option<&str> {
+        self.names[language.index()].as_deref()
+    }
+
+    /// Set the name in the given language.
+    pub fn set(&mut self, language: Language, name: String) {
+        self.names[language.index()] = Some(name);
+    }
+}
+
+/// Merge M49 tables in different languages into the names of each country or area, keyed by M49
+/// code.
+///
+/// Later tables in the same language replace the names from earlier ones.
+pub fn merge(
+    tables: impl IntoIterator<Item = (Language, impl IntoIterator<Item = Record>)>,
+) -> BTreeMap<u16, Names> {
+    let mut retval = BTreeMap::<u16, Names>::new();
+
+    for (language, records) in tables {
+        for record in records {
+            retval
+                .entry(record.m49_code)
+                .or_default()
+                .set(language, record.country_or_area);
+        }
+    }
+
+    retval
+}
+
+/// Read the records of an M49 CSV file, as distributed by the UN.
+///
+/// The file is `;`-delimited, with a header row, and may begin with a UTF-8 byte-order mark. The
+/// UN publishes the same columns in each of its six [`Language`]s, so columns are read by
+/// position, and the header row may be in any of them.
+pub fn read<R: Read>(reader: R) -> impl Iterator<Item = Result<Record, ReadError>> {
+    table::read_by_position(reader, b';')
+}
+
+/// Write records as an M49 CSV file, in the same layout the UN distributes.
+///
+/// The output begins with a UTF-8 byte-order mark and the English header row, uses `;` as the
+/// delimiter and `\n` line endings, has no trailing newline, writes codes as three zero-padded
+/// digits, and marks the LDC, LLDC and SIDS columns with `x`. Reading the UN's English file with
+/// [`read()`] and writing the records back out reproduces the file byte-for-byte.
+///
+/// The header row is always in English, since a [`Record`] does not know the language of its
+/// names. Records read from another language's file are written with their names unchanged under
+/// the English headers, which [`read()`] reads back to the same records, but the file differs from
+/// the UN's in its header row.
+///
+/// # Errors
+///
+/// - [`io::Error`] if the records could not be written.
+pub fn write<'a, W: Write>(
+    records: impl IntoIterator<Item = &'a Record>,
+    mut writer: W,
+) -> io::Result<()> {
+    let mut csv = WriterBuilder::new()
+        .delimiter(b';')
+        .terminator(Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+
+    csv.write_record(HEADERS)?;
+
+    for record in records {
+        csv.write_record(record.fields())?;
+    }
+
+    let mut bytes = csv.into_inner().map_err(csv::IntoInnerError::into_error)?;
+    // The UN's files do not end with a newline.
+    bytes.pop();
+
+    writer.write_all(BOM.as_bytes())?;
+    writer.write_all(&bytes)
+}
+
+impl Record {
+    /// The fields of this record, formatted as they appear in the UN's CSV files.
+    fn fields(&self) -> [String; 15] {
+        fn code(value: Option<u16>) -> String {
+            value.map(|code| format!("{code:03}")).unwrap_or_default()
+        }
+
+        fn flag(value: bool) -> String {
+            if value { "x" } else { "" }.to_owned()
+        }
+
+        [
+            code(Some(self.global_code)),
+            self.global_name.clone(),
+            code(self.region_code),
+            self.region_name.clone().unwrap_or_default(),
+            code(self.subregion_code),
+            self.subregion_name.clone().unwrap_or_default(),
+            code(self.intermediate_region_code),
+            self.intermediate_region_name.clone().unwrap_or_default(),
+            self.country_or_area.clone(),
+            code(Some(self.m49_code)),
+            self.alpha2.clone(),
+            self.alpha3.clone(),
+            flag(self.least_developed_country),
+            flag(self.landlocked_developing_country),
+            flag(self.small_island_developing_state),
+        ]
+    }
+}
+
 fn deserialize_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: Deserializer<'de>,
@@ -90,23 +306,27 @@ where
 
 #[cfg(test)]
 mod test {
-    use super::Record;
-    use csv::ReaderBuilder;
+    use super::{Language, SNAPSHOT as M49_2021_2, merge, read, write};
+
+    /// The first rows of the French M49 table.
+    const M49_FR: &str = "\u{feff}\
+Code global;Nom global;Code région;Nom région;Code sous-région;Nom sous-région;Code région intermédiaire;Nom région intermédiaire;Pays ou zone;Code M49;Code ISO-alpha2;Code ISO-alpha3;Pays les moins avancés (PMA);Pays en développement sans littoral (PDSL);Petits États insulaires en développement (PEID)
+001;Monde;002;Afrique;015;Afrique septentrionale;;;Algérie;012;DZ;DZA;;;
+001;Monde;002;Afrique;015;Afrique septentrionale;;;Égypte;818;EG;EGY;;;";
 
-    /// The M49 dataset as of the second update from 2021, retrieved Sep. 5, 2025.
-    const M49_2021_2: &str = include_str!("m49/2025-09-05.csv");
+    /// The first rows of the Chinese M49 table.
+    const M49_ZH: &str = "\u{feff}\
+全球代码;全球名称;区域代码;区域名称;次区域代码;次区域名称;中间区域代码;中间区域名称;国家或地区;M49 代码;ISO-alpha2 代码;ISO-alpha3 代码;最不发达国家;内陆发展中国家;小岛屿发展中国家
+001;世界;002;非洲;015;北非;;;阿尔及利亚;012;DZ;DZA;;;
+001;世界;002;非洲;015;北非;;;埃及;818;EG;EGY;;;";
 
     #[test]
     fn count_2021_2() {
         const EXPECTED: usize = 248;
 
-        let mut reader = ReaderBuilder::new()
-            .delimiter(b';')
-            .has_headers(true)
-            .from_reader(M49_2021_2.as_bytes());
         let mut count = 0usize;
 
-        for result in reader.deserialize::<Record>() {
+        for result in read(M49_2021_2.as_bytes()) {
             let record = result.expect("Could not parse record");
             eprintln!("{record:?}");
             count += 1;
@@ -114,4 +334,97 @@ mod test {
 
         assert_eq!(EXPECTED, count);
     }
+
+    #[test]
+    fn bom() {
+        assert!(M49_2021_2.starts_with('\u{feff}'));
+
+        let first = read(M49_2021_2.as_bytes())
+            .next()
+            .expect("record")
+            .expect("valid record");
+        assert_eq!(1, first.global_code);
+        assert_eq!("Algeria", first.country_or_area);
+    }
+
+    #[test]
+    fn write_round_trip() {
+        let records = read(M49_2021_2.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records");
+
+        let mut output = Vec::new();
+        write(&records, &mut output).expect("written");
+
+        assert_eq!(M49_2021_2, String::from_utf8(output).expect("utf-8"));
+    }
+
+    #[test]
+    fn write_round_trip_french() {
+        let records = read(M49_FR.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records");
+
+        let mut output = Vec::new();
+        write(&records, &mut output).expect("written");
+        let output = String::from_utf8(output).expect("utf-8");
+
+        let english = M49_2021_2.lines().next().expect("header row");
+        let (_, rows) = M49_FR.split_once('\n').expect("rows");
+        assert_eq!(format!("{english}\n{rows}"), output);
+
+        let reread = read(output.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records");
+        assert_eq!(records, reread);
+    }
+
+    #[yare::parameterized(
+        french = { M49_FR, "Algérie", "Afrique septentrionale" },
+        chinese = { M49_ZH, "阿尔及利亚", "北非" },
+    )]
+    fn localized_headers(input: &str, name: &str, subregion: &str) {
+        let first = read(input.as_bytes())
+            .next()
+            .expect("record")
+            .expect("valid record");
+
+        assert_eq!(12, first.m49_code);
+        assert_eq!("DZ", first.alpha2);
+        assert_eq!(name, first.country_or_area);
+        assert_eq!(Some(subregion), first.subregion_name.as_deref());
+    }
+
+    #[test]
+    fn merge_by_code() {
+        let tables = [
+            (Language::English, M49_2021_2),
+            (Language::French, M49_FR),
+            (Language::Chinese, M49_ZH),
+        ]
+        .map(|(language, input)| {
+            let records = read(input.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .expect("valid records");
+            (language, records)
+        });
+
+        let names = merge(tables);
+        assert_eq!(248, names.len());
+
+        let egypt = &names[&818];
+        assert_eq!(Some("Egypt"), egypt.get(Language::English));
+        assert_eq!(Some("Égypte"), egypt.get(Language::French));
+        assert_eq!(Some("埃及"), egypt.get(Language::Chinese));
+        assert_eq!(None, egypt.get(Language::Russian));
+
+        assert_eq!(None, names[&4].get(Language::French));
+    }
+
+    #[test]
+    fn language_codes() {
+        for language in Language::ALL {
+            assert_eq!(Some(language), Language::from_code(language.code()));
+        }
+    }
 }
@@ -0,0 +1,245 @@
+//! Land and maritime neighbours of each country
+//!
+//! The data is a CSV file with an `alpha-2` column, a `land` column containing the space-separated
+//! alpha-2 codes of the countries it shares a land border with, and a `maritime` column containing
+//! those it shares a maritime boundary with, whether or not the boundary has been agreed. Both
+//! columns are empty for countries with no neighbours of that kind. Territories with codes of
+//! their own are neighbours in their own right, so Spain borders Gibraltar rather than the United
+//! Kingdom, and the Caspian Sea is treated as a sea.
+//!
+//! ## Sources
+//!
+//! The bundled [`SNAPSHOT`] was compiled by hand and is not generated from a dataset.
+//!
+//! - `land` can be generated instead from the `neighbours` column of the
+//!   [`countryInfo.txt`](https://download.geonames.org/export/dump/countryInfo.txt) export of
+//!   [GeoNames](https://www.geonames.org), which is licensed under CC BY 4.0 and must be attributed
+//!   when redistributed. Read it with [`read_country_info()`] and combine it with a table's
+//!   maritime neighbours with [`merge_land()`], or pass it to `iso3166-codegen` with
+//!   `--country-info`.
+//! - `maritime` is only available from the curated table. It lists boundaries whether or not they
+//!   have been agreed by treaty, including median lines and joint regime areas. Open datasets of
+//!   maritime boundaries, such as the Flanders Marine Institute's
+//!   [Maritime Boundaries Geodatabase](https://www.marineregions.org), are not parsed by this
+//!   crate.
+
+pub use crate::table::ReadError;
+
+use crate::table;
+use serde::{
+    Deserialize, Serialize,
+    de::{Deserializer, IgnoredAny},
+};
+use std::io::Read;
+
+/// A curated table of the land and maritime neighbours of every country, as of
+/// [`SNAPSHOT_DATE`].
+///
+/// See the [module documentation](self) for how to generate the land neighbours from `GeoNames`.
+pub const SNAPSHOT: &str = include_str!("neighbours/neighbours.csv");
+
+/// The date [`SNAPSHOT`] was last updated.
+pub const SNAPSHOT_DATE: &str = "2025-12-29";
+
+fn deserialize_codes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(value.split_whitespace().map(str::to_owned).collect())
+}
+
+/// A record in the neighbour table.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Record {
+    /// The alpha-2 code of the country
+    #[serde(rename = "alpha-2")]
+    pub alpha2: String,
+
+    /// The alpha-2 codes of the countries which share a land border with the country
+    #[serde(deserialize_with = "deserialize_codes")]
+    pub land: Vec<String>,
+
+    /// The alpha-2 codes of the countries which share a maritime boundary with the country
+    #[serde(deserialize_with = "deserialize_codes")]
+    pub maritime: Vec<String>,
+}
+
+/// Find the first record which lists a neighbour that does not list it back, or lists itself.
+///
+/// Neighbours which have no record of their own are not checked.
+#[must_use]
+pub fn find_asymmetric(records: &[Record]) -> Option<&Record> {
+    records.iter().find(|record| {
+        let lists = |other: &Record, land: bool| {
+            let codes = if land { &other.land } else { &other.maritime };
+            codes.contains(&record.alpha2)
+        };
+
+        [(true, &record.land), (false, &record.maritime)]
+            .into_iter()
+            .any(|(land, codes)| {
+                codes.iter().any(|code| {
+                    *code == record.alpha2
+                        || records
+                            .iter()
+                            .find(|other| other.alpha2 == *code)
+                            .is_some_and(|other| !lists(other, land))
+                })
+            })
+    })
+}
+
+/// Read the records of a neighbour CSV file.
+pub fn read<R: Read>(reader: R) -> impl Iterator<Item = Result<Record, ReadError>> {
+    table::read(reader)
+}
+
+/// A row of `countryInfo.txt`, of which only the `ISO` and `neighbours` columns are kept.
+#[derive(Deserialize)]
+struct CountryInfoRow(
+    String,
+    [IgnoredAny; 16],
+    #[serde(default, deserialize_with = "deserialize_country_info_codes")] Vec<String>,
+);
+
+fn deserialize_country_info_codes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(value
+        .split(',')
+        .filter(|code| !code.is_empty())
+        .map(str::to_owned)
+        .collect())
+}
+
+/// Read the land neighbours of each country from the
+/// [`countryInfo.txt`](https://download.geonames.org/export/dump/countryInfo.txt) export of
+/// [GeoNames](https://www.geonames.org).
+///
+/// The file is tab-separated, with comment lines starting with `#`. Each country's `land`
+/// neighbours come from its `neighbours` column, and `maritime` is left empty, as `GeoNames` does
+/// not record maritime boundaries.
+pub fn read_country_info<R: Read>(reader: R) -> impl Iterator<Item = Result<Record, ReadError>> {
+    table::read_commented(reader, b'\t').map(|result| {
+        result.map(|CountryInfoRow(alpha2, _, land)| Record {
+            alpha2,
+            land,
+            maritime: Vec::new(),
+        })
+    })
+}
+
+/// Replace the `land` neighbours of `records` with those of `land`, such as the records read by
+/// [`read_country_info()`], keeping each record's `maritime` neighbours.
+///
+/// Countries in `land` without a record of their own are added with no maritime neighbours, and
+/// records for countries which are not in `land` are kept as they are.
+#[must_use]
+pub fn merge_land(mut records: Vec<Record>, land: Vec<Record>) -> Vec<Record> {
+    for other in land {
+        match records
+            .iter_mut()
+            .find(|record| record.alpha2 == other.alpha2)
+        {
+            Some(record) => record.land = other.land,
+            None => records.push(Record {
+                maritime: Vec::new(),
+                ..other
+            }),
+        }
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Record, SNAPSHOT, find_asymmetric, merge_land, read, read_country_info};
+
+    fn snapshot() -> Vec<Record> {
+        read(SNAPSHOT.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records")
+    }
+
+    fn record(alpha2: &str) -> Record {
+        snapshot()
+            .into_iter()
+            .find(|record| record.alpha2 == alpha2)
+            .expect("record")
+    }
+
+    #[yare::parameterized(
+        lesotho = { "LS", &["ZA"], &[] },
+        san_marino = { "SM", &["IT"], &[] },
+        vatican = { "VA", &["IT"], &[] },
+        iceland = { "IS", &[], &["FO", "GL", "SJ"] },
+        spain = {
+            "ES",
+            &["AD", "FR", "GI", "MA", "PT"],
+            &["DZ", "EH", "FR", "GI", "IT", "MA", "PT"],
+        },
+        antarctica = { "AQ", &[], &[] },
+    )]
+    fn neighbours(alpha2: &str, land: &[&str], maritime: &[&str]) {
+        let record = record(alpha2);
+
+        assert_eq!(land, record.land);
+        assert_eq!(maritime, record.maritime);
+    }
+
+    #[test]
+    fn consistent() {
+        let records = snapshot();
+
+        assert_eq!(249, records.len());
+        assert_eq!(None, find_asymmetric(&records));
+    }
+
+    #[test]
+    fn country_info() {
+        let records = read_country_info(
+            "# GeoNames country info\n\
+             #ISO\tISO3\tISO-Numeric\tfips\tCountry\tCapital\tArea(in sq km)\tPopulation\t\
+             Continent\ttld\tCurrencyCode\tCurrencyName\tPhone\tPostal Code Format\t\
+             Postal Code Regex\tLanguages\tgeonameid\tneighbours\tEquivalentFipsCode\n\
+             IS\tISL\t352\tIC\tIceland\tReykjavik\t103000\t391810\tEU\t.is\tISK\tKrona\t354\t\
+             ###\t^(\\d{3})$\tis,en,de,da,sv,no\t2629691\t\t\n\
+             LS\tLSO\t426\tLT\tLesotho\tMaseru\t30355\t2108132\tAF\t.ls\tLSL\tLoti\t266\t\
+             ###\t^(\\d{3})$\ten-LS,st,zu,xh\t932692\tZA\t\n\
+             ZA\tZAF\t710\tSF\tSouth Africa\tPretoria\t1219912\t57779622\tAF\t.za\tZAR\tRand\t\
+             27\t####\t^(\\d{4})$\tzu,xh,af,nso,en-ZA,tn,st,ts,ss,ve,nr\t953987\t\
+             ZW,SZ,NA,MZ,LS,BW\n"
+                .as_bytes(),
+        )
+        .collect::<Result<Vec<_>, _>>()
+        .expect("valid records");
+
+        assert_eq!(3, records.len());
+        assert_eq!(0, records[0].land.len());
+        assert_eq!(["ZA"], records[1].land.as_slice());
+        assert_eq!(6, records[2].land.len());
+
+        let merged = merge_land(vec![record("IS"), record("LS")], records);
+        assert_eq!(&["FO", "GL", "SJ"], merged[0].maritime.as_slice());
+        assert_eq!(&["ZA"], merged[1].land.as_slice());
+        assert_eq!("ZA", merged[2].alpha2);
+        assert_eq!(0, merged[2].maritime.len());
+        assert_eq!(None, find_asymmetric(&merged[1..]));
+    }
+
+    #[test]
+    fn asymmetric() {
+        let records = read("alpha-2,land,maritime\nFR,ES,\nES,,\n".as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records");
+        assert_eq!(
+            Some("FR"),
+            find_asymmetric(&records).map(|r| r.alpha2.as_str())
+        );
+
+        let records = read("alpha-2,land,maritime\nFR,FR,\n".as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records");
+        assert!(find_asymmetric(&records).is_some());
+    }
+}
@@ -0,0 +1,307 @@
+//! Top-level domains from Mozilla's Public Suffix List
+//!
+//! The [Public Suffix List](https://publicsuffix.org/) contains a rule for every top-level domain
+//! in the IANA root zone, in its ICANN section. Rules are grouped into blocks separated by blank
+//! lines, and the first comment of the block containing an internationalized country code
+//! top-level domain names its A-label and country, e.g. `// xn--p1ai ("rf", Russian-Cyrillic) :
+//! RU`. Only the single-label rules of the ICANN section are read.
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{self, BufRead, BufReader, Read},
+};
+
+/// The country code top-level domains of the Public Suffix List, as of [`SNAPSHOT_VERSION`].
+pub const SNAPSHOT: &str = include_str!("psl/20230209-cctlds.dat");
+
+/// The version of the Public Suffix List [`SNAPSHOT`] was extracted from.
+pub const SNAPSHOT_VERSION: &str = "20230209";
+
+/// A top-level domain.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TopLevelDomain {
+    /// The domain as written in the list, without the leading dot, e.g. `рф`
+    pub name: String,
+
+    /// The ASCII form of the domain, which is the punycode A-label for internationalized
+    /// domains, e.g. `xn--p1ai`
+    pub ascii: String,
+
+    /// The ISO 3166 code given in the comment of an internationalized country code top-level
+    /// domain, e.g. `RU`, or `EU` for the European Union
+    pub country: Option<String>,
+}
+
+impl TopLevelDomain {
+    /// Whether the domain is an internationalized domain name.
+    #[must_use]
+    pub fn is_idn(&self) -> bool {
+        !self.name.is_ascii()
+    }
+}
+
+/// An error reading a Public Suffix List file.
+#[derive(Debug)]
+pub struct ReadError {
+    line: Option<u64>,
+    inner: io::Error,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.inner),
+            None => self.inner.fmt(f),
+        }
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+/// Encode a lowercase label as punycode, as described in RFC 3492, without the `xn--` prefix.
+fn punycode(label: &str) -> String {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+
+    fn adapt(mut delta: u32, points: u32, first: bool) -> u32 {
+        delta /= if first { 700 } else { 2 };
+        delta += delta / points;
+
+        let mut position = 0;
+        while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+            delta /= BASE - T_MIN;
+            position += BASE;
+        }
+
+        position + (BASE - T_MIN + 1) * delta / (delta + 38)
+    }
+
+    fn digit(value: u32) -> char {
+        char::from(if value < 26 {
+            b'a' + u8::try_from(value).unwrap_or_default()
+        } else {
+            b'0' + u8::try_from(value - 26).unwrap_or_default()
+        })
+    }
+
+    let input = label.chars().map(u32::from).collect::<Vec<_>>();
+    let mut retval = label.chars().filter(char::is_ascii).collect::<String>();
+    let basic = u32::try_from(retval.len()).unwrap_or(u32::MAX);
+    if basic > 0 {
+        retval.push('-');
+    }
+
+    let mut code_point = 0x80;
+    let mut delta = 0_u32;
+    let mut bias = 72;
+    let mut handled = basic;
+
+    while (handled as usize) < input.len() {
+        let next = input
+            .iter()
+            .copied()
+            .filter(|&c| c >= code_point)
+            .min()
+            .unwrap_or(code_point);
+        delta += (next - code_point) * (handled + 1);
+        code_point = next;
+
+        for &c in &input {
+            if c < code_point {
+                delta += 1;
+            } else if c == code_point {
+                let mut remainder = delta;
+                let mut position = BASE;
+                loop {
+                    let threshold = if position <= bias {
+                        T_MIN
+                    } else if position >= bias + T_MAX {
+                        T_MAX
+                    } else {
+                        position - bias
+                    };
+                    if remainder < threshold {
+                        break;
+                    }
+
+                    retval.push(digit(
+                        threshold + (remainder - threshold) % (BASE - threshold),
+                    ));
+                    remainder = (remainder - threshold) / (BASE - threshold);
+                    position += BASE;
+                }
+
+                retval.push(digit(remainder));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta += 1;
+        code_point += 1;
+    }
+
+    retval
+}
+
+/// Parse the country from the comment of an internationalized country code top-level domain.
+fn comment_country(comment: &str) -> Option<String> {
+    let (_, country) = comment.rsplit_once(" : ")?;
+    let country = country.trim();
+
+    (country.len() == 2 && country.bytes().all(|b| b.is_ascii_uppercase()))
+        .then(|| country.to_owned())
+}
+
+/// Read the top-level domains in the ICANN section of a Public Suffix List file.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file could not be read, or is not valid UTF-8.
+pub fn read_top_level_domains<R: Read>(reader: R) -> Result<Vec<TopLevelDomain>, ReadError> {
+    let mut retval = Vec::new();
+    let mut icann = false;
+    let mut comment = Option::<String>::None;
+    let mut in_block = false;
+
+    for (number, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line.map_err(|inner| ReadError {
+            line: u64::try_from(number + 1).ok(),
+            inner,
+        })?;
+        let line = line.trim();
+
+        if line == "// ===BEGIN ICANN DOMAINS===" {
+            icann = true;
+        } else if line == "// ===END ICANN DOMAINS===" {
+            break;
+        } else if line.is_empty() {
+            in_block = false;
+            comment = None;
+        } else if let Some(text) = line.strip_prefix("//") {
+            if !in_block {
+                comment = Some(text.trim().to_owned());
+            }
+            in_block = true;
+        } else {
+            in_block = true;
+            if !icann || line.contains('.') || line.starts_with(['*', '!']) {
+                continue;
+            }
+
+            let name = line.to_lowercase();
+            let (ascii, country) = if name.is_ascii() {
+                (name.clone(), None)
+            } else {
+                (
+                    format!("xn--{}", punycode(&name)),
+                    comment.as_deref().and_then(comment_country),
+                )
+            };
+
+            retval.push(TopLevelDomain {
+                name,
+                ascii,
+                country,
+            });
+        }
+    }
+
+    Ok(retval)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SNAPSHOT, TopLevelDomain, punycode, read_top_level_domains};
+
+    fn snapshot() -> Vec<TopLevelDomain> {
+        read_top_level_domains(SNAPSHOT.as_bytes()).expect("valid list")
+    }
+
+    #[yare::parameterized(
+        russia = { "рф", "p1ai" },
+        china = { "中国", "fiqs8s" },
+        greece = { "ελ", "qxam" },
+        saudi_arabia = { "السعودية", "mgberp4a5d4ar" },
+        rfc_mixed = { "bücher", "bcher-kva" },
+    )]
+    fn punycode_encoding(label: &str, expected: &str) {
+        assert_eq!(expected, punycode(label));
+    }
+
+    #[test]
+    fn snapshot_contents() {
+        let domains = snapshot();
+
+        assert_eq!(307, domains.len());
+        assert!(domains.iter().any(|tld| tld.name == "uk" && !tld.is_idn()));
+        let rf = domains.iter().find(|tld| tld.name == "рф").expect("рф");
+        assert_eq!("xn--p1ai", rf.ascii);
+        assert_eq!(Some("RU"), rf.country.as_deref());
+        assert!(
+            domains
+                .iter()
+                .all(|tld| tld.is_idn() == tld.country.is_some())
+        );
+    }
+
+    #[test]
+    fn comments_match_a_labels() {
+        // The first word of each IDN ccTLD's comment is its A-label, so this checks the encoder
+        // against every one of them.
+        let mut comment = "";
+        let mut checked = 0;
+        for line in SNAPSHOT.lines() {
+            if let Some(text) = line.strip_prefix("// xn--") {
+                comment = text;
+            } else if !line.is_empty() && !line.starts_with("//") && !line.is_ascii() {
+                let expected = comment.split_whitespace().next().expect("A-label");
+                assert_eq!(expected, punycode(line), "{line}");
+                checked += 1;
+            }
+        }
+
+        assert_eq!(69, checked);
+    }
+
+    #[test]
+    fn private_and_nested_rules_are_ignored() {
+        let list = "\
+// ===BEGIN ICANN DOMAINS===
+
+// uk : https://en.wikipedia.org/wiki/.uk
+*.sch.uk
+co.uk
+uk
+
+// ===END ICANN DOMAINS===
+// ===BEGIN PRIVATE DOMAINS===
+example
+";
+        let domains = read_top_level_domains(list.as_bytes()).expect("valid list");
+
+        assert_eq!(1, domains.len());
+        assert_eq!("uk", domains[0].ascii);
+    }
+
+    #[test]
+    fn read_error_line() {
+        let error = read_top_level_domains(&b"uk\n\xff\n"[..]).expect_err("invalid UTF-8");
+        assert_eq!(Some(2), error.line());
+    }
+}
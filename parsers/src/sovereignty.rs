@@ -0,0 +1,127 @@
+//! The independence and sovereignty of each country
+//!
+//! ISO 3166-1 marks each country as independent or not, and territories which are not
+//! independent are usually administered by another country, e.g. Puerto Rico by the United
+//! States. The data is a CSV file with an `alpha-2` column, an `independent` column containing
+//! `Yes` or `No` as in ISO's Online Browsing Platform, and a `sovereign` column containing the
+//! alpha-2 code of the administering country, which is empty for independent countries and for
+//! territories with no single sovereign, such as Antarctica, Western Sahara and Palestine.
+//!
+//! ## Sources
+//!
+//! The bundled [`SNAPSHOT`] was compiled by hand. Its `independent` column is transcribed from
+//! the *Independent* field of each country's entry in ISO's
+//! [Online Browsing Platform](https://www.iso.org/obp/ui/#search/code/), which has no
+//! machine-readable export that could be parsed instead. The `sovereign` column is not published
+//! by ISO, and was compiled for this crate from the status of each territory. The table is
+//! distributed under the same Apache-2.0 licence as the crate.
+
+pub use crate::table::ReadError;
+
+use crate::table;
+use serde::{Deserialize, Serialize, de::Deserializer};
+use std::io::Read;
+
+/// A curated table of the sovereignty of every country in ISO 3166-1, as of [`SNAPSHOT_DATE`].
+///
+/// See the [module documentation](self) for where each column comes from.
+pub const SNAPSHOT: &str = include_str!("sovereignty/sovereignty.csv");
+
+/// The date [`SNAPSHOT`] was last checked against ISO's Online Browsing Platform.
+pub const SNAPSHOT_DATE: &str = "2025-12-29";
+
+fn deserialize_independent<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    match value.as_str() {
+        "Yes" => Ok(true),
+        "No" => Ok(false),
+        other => Err(serde::de::Error::custom(format!(
+            "expected `Yes` or `No`, found `{other}`"
+        ))),
+    }
+}
+
+fn deserialize_sovereign<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(if value.is_empty() { None } else { Some(value) })
+}
+
+/// A record in the sovereignty table.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Record {
+    /// The alpha-2 code of the country
+    #[serde(rename = "alpha-2")]
+    pub alpha2: String,
+
+    /// Whether ISO considers the country independent
+    #[serde(deserialize_with = "deserialize_independent")]
+    pub independent: bool,
+
+    /// The alpha-2 code of the country which administers this one, if there is a single one
+    #[serde(deserialize_with = "deserialize_sovereign")]
+    pub sovereign: Option<String>,
+}
+
+/// Read the records of a sovereignty CSV file.
+pub fn read<R: Read>(reader: R) -> impl Iterator<Item = Result<Record, ReadError>> {
+    table::read(reader)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Record, SNAPSHOT, read};
+
+    fn snapshot() -> Vec<Record> {
+        read(SNAPSHOT.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("valid records")
+    }
+
+    #[yare::parameterized(
+        puerto_rico = { "PR", false, Some("US") },
+        greenland = { "GL", false, Some("DK") },
+        bouvet = { "BV", false, Some("NO") },
+        hong_kong = { "HK", false, Some("CN") },
+        macao = { "MO", false, Some("CN") },
+        antarctica = { "AQ", false, None },
+        western_sahara = { "EH", false, None },
+        palestine = { "PS", false, None },
+        holy_see = { "VA", true, None },
+        united_states = { "US", true, None },
+    )]
+    fn edge_cases(alpha2: &str, independent: bool, sovereign: Option<&str>) {
+        let records = snapshot();
+        let record = records
+            .iter()
+            .find(|record| record.alpha2 == alpha2)
+            .expect("record");
+
+        assert_eq!(independent, record.independent);
+        assert_eq!(sovereign, record.sovereign.as_deref());
+    }
+
+    #[test]
+    fn consistent() {
+        let records = snapshot();
+
+        assert_eq!(249, records.len());
+        assert_eq!(
+            194,
+            records.iter().filter(|record| record.independent).count()
+        );
+        for record in &records {
+            if let Some(sovereign) = &record.sovereign {
+                assert!(!record.independent, "{}", record.alpha2);
+                assert!(
+                    records
+                        .iter()
+                        .any(|other| other.alpha2 == *sovereign && other.independent),
+                    "{}",
+                    record.alpha2
+                );
+            }
+        }
+    }
+}
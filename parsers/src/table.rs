@@ -0,0 +1,127 @@
+//! Reading CSV tables with line-numbered errors, shared by the table formats in this crate
+
+use csv::ReaderBuilder;
+use serde::de::DeserializeOwned;
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Read,
+};
+
+/// An error reading a CSV file.
+#[derive(Debug)]
+pub struct ReadError {
+    line: Option<u64>,
+    inner: csv::Error,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+}
+
+impl From<csv::Error> for ReadError {
+    fn from(inner: csv::Error) -> Self {
+        let line = inner.position().map(csv::Position::line);
+        Self { line, inner }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.inner),
+            None => self.inner.fmt(f),
+        }
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+/// Read the records of a `,`-delimited CSV file, matching columns to fields by the header row.
+pub(crate) fn read<T: DeserializeOwned, R: Read>(
+    reader: R,
+) -> impl Iterator<Item = Result<T, ReadError>> {
+    ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(reader)
+        .into_deserialize()
+        .map(|result| result.map_err(ReadError::from))
+}
+
+/// Read the records of a CSV file with a header row, matching columns to fields by position.
+pub(crate) fn read_by_position<T: DeserializeOwned, R: Read>(
+    reader: R,
+    delimiter: u8,
+) -> impl Iterator<Item = Result<T, ReadError>> {
+    ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(true)
+        .from_reader(reader)
+        .into_records()
+        .map(|result| {
+            result
+                .and_then(|record| record.deserialize::<T>(None))
+                .map_err(ReadError::from)
+        })
+}
+
+/// Read the records of a file without a header row, skipping lines which start with `#` and
+/// matching columns to fields by position.
+///
+/// Quotes have no special meaning, and records may have fewer fields than `T` when the trailing
+/// fields are optional.
+pub(crate) fn read_commented<T: DeserializeOwned, R: Read>(
+    reader: R,
+    delimiter: u8,
+) -> impl Iterator<Item = Result<T, ReadError>> {
+    ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .comment(Some(b'#'))
+        .quoting(false)
+        .flexible(true)
+        .from_reader(reader)
+        .into_deserialize()
+        .map(|result| result.map_err(ReadError::from))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{read, read_by_position, read_commented};
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Row {
+        #[serde(rename = "code")]
+        _code: String,
+        #[serde(rename = "count")]
+        _count: u8,
+    }
+
+    #[test]
+    fn line() {
+        let error = read::<Row, _>("code,count\nUS,1\nPR,many\n".as_bytes())
+            .find_map(Result::err)
+            .expect("invalid record");
+        assert_eq!(Some(3), error.line());
+        assert!(error.to_string().starts_with("line 3: "), "{error}");
+
+        let error = read_by_position::<(String, u8), _>("a;b\nUS;1\nPR;many\n".as_bytes(), b';')
+            .find_map(Result::err)
+            .expect("invalid record");
+        assert_eq!(Some(3), error.line());
+
+        let error = read_commented::<(String, u8), _>("#a\tb\nUS\t1\nPR\tmany\n".as_bytes(), b'\t')
+            .find_map(Result::err)
+            .expect("invalid record");
+        assert_eq!(Some(3), error.line());
+    }
+}
@@ -0,0 +1,225 @@
+//! Time zones from the IANA [time zone database](https://www.iana.org/time-zones)
+//!
+//! The tzdata `zone.tab` and `zone1970.tab` files list the time zones of each country, one zone
+//! per line, with tab-separated columns for the country codes, the coordinates of the zone's
+//! principal location, the zone name and an optional comment. `zone.tab` has a single country
+//! per line, while `zone1970.tab` gives a comma-separated list of every country whose clocks have
+//! agreed with the zone since 1970, starting with the country the zone is in. The `iso3166.tab`
+//! file lists the English name tzdata uses for each country code. Lines starting with `#` are
+//! comments.
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{self, BufRead, BufReader, Read},
+};
+
+/// The `zone.tab` file of tzdata [`VERSION`].
+pub const ZONE_TAB: &str = include_str!("tzdata/2025b/zone.tab");
+
+/// The `zone1970.tab` file of tzdata [`VERSION`].
+pub const ZONE1970_TAB: &str = include_str!("tzdata/2025b/zone1970.tab");
+
+/// The `iso3166.tab` file of tzdata [`VERSION`].
+pub const ISO3166_TAB: &str = include_str!("tzdata/2025b/iso3166.tab");
+
+/// The version of tzdata the bundled files were taken from.
+pub const VERSION: &str = "2025b";
+
+/// A time zone in `zone.tab` or `zone1970.tab`.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Zone {
+    /// The alpha-2 codes of the countries which use the zone, starting with the country the zone
+    /// is in
+    pub countries: Vec<String>,
+
+    /// The ISO 6709 coordinates of the zone's principal location, e.g. `+5230+01322`
+    pub coordinates: String,
+
+    /// The zone name, e.g. `Europe/Berlin`
+    pub name: String,
+
+    /// The comment distinguishing the zone from the others in its country, if any
+    pub comments: Option<String>,
+}
+
+/// A country in `iso3166.tab`.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Country {
+    /// The alpha-2 code of the country
+    pub alpha2: String,
+
+    /// The usual English name of the country, which tzdata sometimes simplifies, e.g.
+    /// `Samoa (American)`
+    pub name: String,
+}
+
+/// The underlying cause of a [`ReadError`].
+#[derive(Debug)]
+enum ReadErrorKind {
+    Io(io::Error),
+    Column(&'static str),
+}
+
+/// An error reading a tzdata table.
+#[derive(Debug)]
+pub struct ReadError {
+    line: Option<u64>,
+    inner: ReadErrorKind,
+}
+
+impl ReadError {
+    /// The line of the file the error occurred on, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    fn new(inner: ReadErrorKind, index: usize) -> Self {
+        Self {
+            line: u64::try_from(index + 1).ok(),
+            inner,
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+
+        match &self.inner {
+            ReadErrorKind::Io(inner) => inner.fmt(f),
+            ReadErrorKind::Column(column) => write!(f, "missing or empty {column} column"),
+        }
+    }
+}
+
+impl StdError for ReadError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.inner {
+            ReadErrorKind::Io(inner) => Some(inner),
+            ReadErrorKind::Column(_) => None,
+        }
+    }
+}
+
+/// Read the non-comment lines of a table, split into their columns.
+fn read_lines<R: Read>(reader: R) -> impl Iterator<Item = Result<(usize, Vec<String>), ReadError>> {
+    BufReader::new(reader)
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match line {
+            Ok(line) if line.starts_with('#') || line.trim().is_empty() => None,
+            Ok(line) => Some(Ok((
+                index,
+                line.split('\t').map(str::to_owned).collect::<Vec<_>>(),
+            ))),
+            Err(error) => Some(Err(ReadError::new(ReadErrorKind::Io(error), index))),
+        })
+}
+
+/// Take a required column from a line.
+fn column(
+    columns: &mut impl Iterator<Item = String>,
+    name: &'static str,
+    index: usize,
+) -> Result<String, ReadError> {
+    columns
+        .next()
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| ReadError::new(ReadErrorKind::Column(name), index))
+}
+
+/// Read the zones of a `zone.tab` or `zone1970.tab` file, in file order.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file could not be read, or a line is missing a column.
+pub fn read_zones<R: Read>(reader: R) -> Result<Vec<Zone>, ReadError> {
+    read_lines(reader)
+        .map(|line| {
+            let (index, columns) = line?;
+            let mut columns = columns.into_iter();
+
+            Ok(Zone {
+                countries: column(&mut columns, "country code", index)?
+                    .split(',')
+                    .map(str::to_owned)
+                    .collect(),
+                coordinates: column(&mut columns, "coordinates", index)?,
+                name: column(&mut columns, "TZ", index)?,
+                comments: columns.next().filter(|comments| !comments.is_empty()),
+            })
+        })
+        .collect()
+}
+
+/// Read the countries of an `iso3166.tab` file, in file order.
+///
+/// # Errors
+///
+/// - [`ReadError`] if the file could not be read, or a line is missing a column.
+pub fn read_countries<R: Read>(reader: R) -> Result<Vec<Country>, ReadError> {
+    read_lines(reader)
+        .map(|line| {
+            let (index, columns) = line?;
+            let mut columns = columns.into_iter();
+
+            Ok(Country {
+                alpha2: column(&mut columns, "country code", index)?,
+                name: column(&mut columns, "name", index)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ISO3166_TAB, ZONE_TAB, ZONE1970_TAB, read_countries, read_zones};
+
+    #[yare::parameterized(
+        zone_tab = { ZONE_TAB, 1 },
+        zone1970_tab = { ZONE1970_TAB, 5 },
+    )]
+    fn berlin(table: &str, countries: usize) {
+        let zones = read_zones(table.as_bytes()).expect("valid zones");
+        let berlin = zones
+            .iter()
+            .find(|zone| zone.name == "Europe/Berlin")
+            .expect("Berlin");
+
+        assert_eq!("DE", berlin.countries[0]);
+        assert_eq!(countries, berlin.countries.len());
+        assert_eq!("+5230+01322", berlin.coordinates);
+    }
+
+    #[test]
+    fn comments() {
+        let zones = read_zones(ZONE_TAB.as_bytes()).expect("valid zones");
+        let busingen = zones
+            .iter()
+            .find(|zone| zone.name == "Europe/Busingen")
+            .expect("Busingen");
+
+        assert_eq!(Some("Busingen"), busingen.comments.as_deref());
+        assert!(zones.iter().all(|zone| zone.countries.len() == 1));
+    }
+
+    #[test]
+    fn countries() {
+        let countries = read_countries(ISO3166_TAB.as_bytes()).expect("valid countries");
+
+        assert_eq!(249, countries.len());
+        assert_eq!("AD", countries[0].alpha2);
+        assert_eq!("Andorra", countries[0].name);
+    }
+
+    #[test]
+    fn read_error_line() {
+        let error = read_zones("# comment\nDE\t+5230+01322\n".as_bytes()).expect_err("no zone");
+        assert_eq!(Some(2), error.line());
+        assert_eq!("line 2: missing or empty TZ column", error.to_string());
+    }
+}
@@ -0,0 +1,128 @@
+//! Generate the CLDR localized names when the `cldr` feature is enabled, and the country
+//! boundaries when the `boundaries` feature is enabled.
+//!
+//! The names are read from a local copy of the `cldr-localenames-full` package:
+//!
+//! - `ISO3166_CLDR_DIR`: The package's `main` directory, containing a directory per locale.
+//! - `ISO3166_CLDR_LOCALES`: An optional comma-separated list of locales to include, e.g.
+//!   `de,fr,zh-Hant`. All locales in the directory are included when it is not set.
+//!
+//! The boundaries are read from the snapshot bundled with `iso3166-parsers`, unless overridden:
+//!
+//! - `ISO3166_BOUNDARIES`: A `GeoJSON` file of country boundaries, e.g. Natural Earth's
+//!   `ne_50m_admin_0_countries.geojson` from version 5.1.2, which is in the public domain.
+//! - `ISO3166_BOUNDARY_TOLERANCE`: The tolerance to simplify the boundaries with, in degrees.
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rustc-check-cfg=cfg(iso3166_cldr_dir)");
+    println!("cargo::rustc-check-cfg=cfg(iso3166_boundaries)");
+
+    #[cfg(feature = "cldr")]
+    cldr::generate();
+
+    #[cfg(feature = "boundaries")]
+    boundaries::generate();
+}
+
+#[cfg(feature = "cldr")]
+mod cldr {
+    use iso3166_codegen::{Builder, Source};
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+    };
+
+    pub(crate) fn generate() {
+        println!("cargo::rerun-if-changed=src/all.json");
+        println!("cargo::rerun-if-env-changed=ISO3166_CLDR_DIR");
+        println!("cargo::rerun-if-env-changed=ISO3166_CLDR_LOCALES");
+
+        let mut builder = Builder::new().source(Source::LukesJsonFile("src/all.json".into()));
+
+        if let Some(dir) = env::var_os("ISO3166_CLDR_DIR").map(PathBuf::from) {
+            // Lets the CLDR tests tell a build without locales from one with missing names.
+            println!("cargo::rustc-cfg=iso3166_cldr_dir");
+
+            let locales = env::var("ISO3166_CLDR_LOCALES").map_or_else(
+                |_| all_locales(&dir),
+                |locales| {
+                    locales
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|locale| !locale.is_empty())
+                        .map(str::to_owned)
+                        .collect()
+                },
+            );
+
+            for locale in locales {
+                let path = dir.join(&locale).join("territories.json");
+                println!("cargo::rerun-if-changed={}", path.display());
+                builder = builder.cldr_file(path);
+            }
+        } else {
+            println!("cargo::warning=ISO3166_CLDR_DIR is not set, so no CLDR locales are included");
+        }
+
+        let mut out = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR"));
+        out.push("cldr.rs");
+
+        let code = builder
+            .render_cldr()
+            .expect("Could not generate the CLDR localized names");
+        fs::write(out, code).expect("Could not write the CLDR localized names");
+    }
+
+    /// Find every locale in the CLDR directory which has territory names.
+    fn all_locales(dir: &Path) -> Vec<String> {
+        let mut retval = fs::read_dir(dir)
+            .expect("Could not read ISO3166_CLDR_DIR")
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().join("territories.json").is_file())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect::<Vec<_>>();
+        retval.sort();
+        retval
+    }
+}
+
+#[cfg(feature = "boundaries")]
+mod boundaries {
+    use iso3166_codegen::{Builder, Source};
+    use std::{env, fs, path::PathBuf};
+
+    pub(crate) fn generate() {
+        println!("cargo::rerun-if-changed=src/all.json");
+        println!("cargo::rerun-if-env-changed=ISO3166_BOUNDARIES");
+        println!("cargo::rerun-if-env-changed=ISO3166_BOUNDARY_TOLERANCE");
+
+        let mut builder = Builder::new().source(Source::LukesJsonFile("src/all.json".into()));
+
+        builder = match env::var_os("ISO3166_BOUNDARIES").map(PathBuf::from) {
+            Some(path) => {
+                // Lets the boundary tests hold detailed boundaries to a tighter tolerance.
+                println!("cargo::rustc-cfg=iso3166_boundaries");
+                println!("cargo::rerun-if-changed={}", path.display());
+                builder.boundaries_file(path)
+            }
+            None => builder.boundaries(iso3166_parsers::boundaries::SNAPSHOT),
+        };
+
+        if let Ok(tolerance) = env::var("ISO3166_BOUNDARY_TOLERANCE") {
+            let tolerance = tolerance
+                .trim()
+                .parse()
+                .expect("ISO3166_BOUNDARY_TOLERANCE is not a number of degrees");
+            builder = builder.boundary_tolerance(tolerance);
+        }
+
+        let mut out = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR"));
+        out.push("boundaries.rs");
+
+        let code = builder
+            .render_boundaries()
+            .expect("Could not generate the country boundaries");
+        fs::write(out, code).expect("Could not write the country boundaries");
+    }
+}
@@ -0,0 +1,135 @@
+//! Coarse reverse geocoding against the generated [`BOUNDARY_RINGS`].
+
+use crate::{Alpha2, BOUNDARY_GRID, BOUNDARY_POINTS, BOUNDARY_RINGS, BoundaryRing, Coordinates};
+
+/// The number of columns of [`BOUNDARY_GRID`], each 10 degrees of longitude.
+const COLUMNS: usize = 36;
+
+/// Where a point is, as found by [`country_at()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Location {
+    /// The point is within the boundary of the country.
+    Land(Alpha2),
+    /// The point is not within the boundary of any country, such as at sea.
+    Ocean,
+}
+
+impl Location {
+    /// Get the country of a [`Location::Land`] location.
+    #[must_use]
+    pub const fn country(self) -> Option<Alpha2> {
+        match self {
+            Self::Land(country) => Some(country),
+            Self::Ocean => None,
+        }
+    }
+}
+
+/// Wrap a difference in longitude to between -180 and 180 degrees.
+fn wrap(degrees: f64) -> f64 {
+    degrees - 360.0 * libm::floor((degrees + 180.0) / 360.0)
+}
+
+impl BoundaryRing {
+    /// Get the ring's points, as latitude and longitude in millionths of a degree.
+    fn points(&self) -> &'static [[i32; 2]] {
+        &BOUNDARY_POINTS[self.start as usize..self.end as usize]
+    }
+
+    /// Check whether the ring contains a point, by counting the edges a ray crosses.
+    fn contains(&self, point: Coordinates) -> bool {
+        let [south, west, north, east] = self.bounds;
+        let (y, x) = (i64::from(point.latitude_e6), i64::from(point.longitude_e6));
+        if !(south..=north).contains(&point.latitude_e6)
+            || !(west..=east).contains(&point.longitude_e6)
+        {
+            return false;
+        }
+
+        let points = self.points();
+        let mut inside = false;
+        let mut previous = points[points.len() - 1];
+        for &current in points {
+            let [y1, x1] = previous.map(i64::from);
+            let [y2, x2] = current.map(i64::from);
+            if (y1 > y) != (y2 > y) {
+                // The sign of the cross product gives the side of the edge the point is on.
+                let side = (x2 - x1) * (y - y1) - (x - x1) * (y2 - y1);
+                if (side > 0) == (y2 > y1) {
+                    inside = !inside;
+                }
+            }
+            previous = current;
+        }
+
+        inside
+    }
+}
+
+/// Find the country at a latitude and longitude in decimal degrees, from the boundaries generated
+/// at build time.
+///
+/// A point is on the [`Location::Land`] of the smallest ring which contains it, once holes such as
+/// enclaves are cut out, and otherwise in the [`Location::Ocean`]. Longitudes outside -180 to 180
+/// degrees are wrapped.
+///
+/// Returns `None` if the latitude is not between -90 and 90 degrees, or either value is not
+/// finite.
+///
+/// # Accuracy
+///
+/// The result is only as accurate as the boundaries, which are simplified to within
+/// [`BOUNDARY_TOLERANCE`](crate::BOUNDARY_TOLERANCE) degrees of their source. The bundled snapshot
+/// is Natural Earth's 1:110m countries: the boundary tests find it within 11 km of the borders and
+/// coasts they cross, but it leaves out small countries and islands, such as Singapore, cuts off
+/// Antarctica at 85.6°S and puts a dozen coastal capitals, such as Montevideo, in the ocean.
+/// Building with Natural Earth's 1:50m countries in `ISO3166_BOUNDARIES` holds the same tests to
+/// 10 km plus the tolerance. Territorial waters and exclusive economic zones are not included, so
+/// a point off a coast is always in the [`Location::Ocean`].
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_static::{Alpha2, Location, country_at};
+///
+/// assert_eq!(Some(Location::Land(Alpha2::Germany)), country_at(52.52, 13.40));
+/// assert_eq!(Some(Location::Ocean), country_at(30.0, -40.0));
+/// assert_eq!(None, country_at(91.0, 0.0));
+/// ```
+#[must_use]
+pub fn country_at(latitude: f64, longitude: f64) -> Option<Location> {
+    if !latitude.is_finite() || !longitude.is_finite() || !(-90.0..=90.0).contains(&latitude) {
+        return None;
+    }
+
+    let point = Coordinates::from_degrees(latitude, wrap(longitude));
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let cell = |value: f64, origin: f64, count: usize| {
+        (libm::floor((value - origin) / 10.0) as usize).min(count - 1)
+    };
+    let row = cell(point.latitude(), -90.0, BOUNDARY_GRID.len() / COLUMNS);
+    let column = cell(point.longitude(), -180.0, COLUMNS);
+    let candidates = BOUNDARY_GRID
+        .get(row * COLUMNS + column)
+        .copied()
+        .unwrap_or_default();
+
+    // Rings are ordered from the smallest, so the first country which contains the point an odd
+    // number of times, counting its holes, is the most specific.
+    for ring in candidates.iter().map(|i| &BOUNDARY_RINGS[*i as usize]) {
+        if !ring.hole && ring.contains(point) {
+            let containing = candidates
+                .iter()
+                .map(|i| &BOUNDARY_RINGS[*i as usize])
+                .filter(|other| other.country == ring.country && other.contains(point))
+                .count();
+            if containing % 2 == 1 {
+                return Some(Location::Land(ring.country));
+            }
+        }
+    }
+
+    Some(Location::Ocean)
+}
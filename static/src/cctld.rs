@@ -0,0 +1,44 @@
+//! Country lookups by top-level domain, using the generated [`CCTLDS`].
+
+use crate::{Alpha2, CCTLDS};
+
+impl Alpha2 {
+    /// Find the country a country code top-level domain belongs to.
+    ///
+    /// The domain may be given with or without its leading dot, and internationalized domains
+    /// may be given in either their Unicode or punycode form, in any case. This includes the
+    /// ccTLDs which are not the alpha-2 code of their country, such as `.uk` for the United
+    /// Kingdom and `.ac` for Ascension Island. Top-level domains which do not belong to a single
+    /// country, such as `.eu` and `.su`, return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::Alpha2;
+    ///
+    /// let tld = |email: &'static str| email.rsplit('.').next().unwrap_or_default();
+    ///
+    /// assert_eq!(Some(Alpha2::UnitedKingdom), Alpha2::from_cctld(tld("jo@example.co.uk")));
+    /// assert_eq!(Some(Alpha2::Russia), Alpha2::from_cctld(tld("ivan@пример.рф")));
+    /// assert_eq!(Some(Alpha2::Russia), Alpha2::from_cctld(".xn--p1ai"));
+    /// assert_eq!(None, Alpha2::from_cctld(tld("jo@example.com")));
+    /// assert_eq!(None, Alpha2::from_cctld(".eu"));
+    /// ```
+    #[must_use]
+    pub fn from_cctld(domain: &str) -> Option<Alpha2> {
+        let domain = domain.strip_prefix('.').unwrap_or(domain);
+        let domain = domain.strip_suffix('.').unwrap_or(domain);
+
+        CCTLDS
+            .iter()
+            .find(|cctld| {
+                cctld.ascii.eq_ignore_ascii_case(domain)
+                    || (!domain.is_ascii()
+                        && cctld
+                            .name
+                            .chars()
+                            .eq(domain.chars().flat_map(char::to_lowercase)))
+            })
+            .map(|cctld| cctld.country)
+    }
+}
@@ -0,0 +1,57 @@
+//! Great-circle distances between countries, using the generated [`Coordinates`].
+
+use crate::{Alpha2, Coordinates};
+
+/// The mean radius of the Earth, in kilometres.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+impl Coordinates {
+    /// Get the great-circle distance to other coordinates, in kilometres.
+    ///
+    /// This uses the haversine formula on a sphere with the Earth's mean radius, so it is within
+    /// about half a percent of the distance along the Earth's surface.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::Coordinates;
+    ///
+    /// let london = Coordinates::from_degrees(51.51, -0.13);
+    /// let paris = Coordinates::from_degrees(48.86, 2.35);
+    /// assert_eq!(343, london.distance_km(paris).round() as u32);
+    /// ```
+    #[must_use]
+    pub fn distance_km(&self, other: Coordinates) -> f64 {
+        let latitude = self.latitude().to_radians();
+        let other_latitude = other.latitude().to_radians();
+        let half_latitude = (other_latitude - latitude) / 2.0;
+        let half_longitude = (other.longitude() - self.longitude()).to_radians() / 2.0;
+
+        let haversine = libm::pow(libm::sin(half_latitude), 2.0)
+            + libm::cos(latitude)
+                * libm::cos(other_latitude)
+                * libm::pow(libm::sin(half_longitude), 2.0);
+
+        2.0 * EARTH_RADIUS_KM * libm::asin(libm::sqrt(haversine.min(1.0)))
+    }
+}
+
+/// Get the great-circle distance between the centroids of two countries, in kilometres.
+///
+/// Returns `None` if either country has no [`Alpha2::centroid()`]. The centroids are approximate,
+/// so this is only meant for comparing rough distances, and is zero for a country and itself
+/// however large it is.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_static::{Alpha2, distance_km};
+///
+/// let distance = distance_km(Alpha2::Germany, Alpha2::France).expect("centroids");
+/// assert!((750.0..850.0).contains(&distance));
+/// assert_eq!(None, distance_km(Alpha2::Germany, Alpha2::UserXX));
+/// ```
+#[must_use]
+pub fn distance_km(a: Alpha2, b: Alpha2) -> Option<f64> {
+    Some(a.centroid()?.distance_km(b.centroid()?))
+}
@@ -0,0 +1,266 @@
+//! IBAN parsing and validation, using the generated [`IBAN_FORMATS`](crate::IBAN_FORMATS).
+
+use crate::{Alpha2, BbanCharacters, IbanFormat};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+    str::FromStr,
+};
+
+/// The longest IBAN allowed by ISO 13616.
+const MAX_LENGTH: usize = 34;
+
+/// The reasons an IBAN may be rejected by [`Iban::parse()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum IbanError {
+    /// The IBAN is not the length used by its country.
+    InvalidLength,
+    /// The IBAN contains characters other than letters, digits and whitespace.
+    InvalidCharset,
+    /// The IBAN does not start with the code of a country in the IBAN registry.
+    UnknownCountry,
+    /// The check digits or BBAN do not follow the country's format.
+    InvalidStructure,
+    /// The check digits do not match the rest of the IBAN.
+    InvalidChecksum,
+}
+
+impl Display for IbanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            IbanError::InvalidLength => f.write_str("Invalid Length"),
+            IbanError::InvalidCharset => f.write_str("Invalid Character Set"),
+            IbanError::UnknownCountry => f.write_str("Unknown Country"),
+            IbanError::InvalidStructure => f.write_str("Invalid Structure"),
+            IbanError::InvalidChecksum => f.write_str("Invalid Checksum"),
+        }
+    }
+}
+
+impl BbanCharacters {
+    /// Check whether an upper case ASCII character is allowed in the segment.
+    const fn matches(self, c: u8) -> bool {
+        match self {
+            Self::Digits => c.is_ascii_digit(),
+            Self::Letters => c.is_ascii_uppercase(),
+            Self::Alphanumeric => c.is_ascii_digit() || c.is_ascii_uppercase(),
+        }
+    }
+}
+
+/// Get the ISO 7064 mod 97-10 remainder of an IBAN, with its first four characters moved to the
+/// end and each letter replaced by two digits, `A` being 10.
+fn remainder(iban: &[u8]) -> u32 {
+    let (head, tail) = iban.split_at(4);
+
+    tail.iter().chain(head).fold(0, |remainder, &c| {
+        if c.is_ascii_digit() {
+            (remainder * 10 + u32::from(c - b'0')) % 97
+        } else {
+            (remainder * 100 + u32::from(c - b'A') + 10) % 97
+        }
+    })
+}
+
+/// A valid International Bank Account Number, in electronic format.
+///
+/// An IBAN is the alpha-2 code of a country, two check digits and a Basic Bank Account Number
+/// (BBAN) whose length and layout are fixed by the country, as listed in the SWIFT IBAN Registry.
+/// Parsing checks all three, and the parts of the BBAN which identify the bank, branch and
+/// account can then be borrowed without allocating.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_static::{Alpha2, Iban, IbanError};
+///
+/// let iban = Iban::parse("GB29 NWBK 6016 1331 9268 19").expect("valid IBAN");
+/// assert_eq!(Alpha2::UnitedKingdom, iban.country());
+/// assert_eq!("29", iban.check_digits());
+/// assert_eq!(Some("NWBK"), iban.bank());
+/// assert_eq!(Some("601613"), iban.branch());
+/// assert_eq!("31926819", iban.account());
+/// assert_eq!("GB29NWBK60161331926819", iban.to_string());
+/// assert_eq!("GB29 NWBK 6016 1331 9268 19", format!("{iban:#}"));
+///
+/// assert_eq!(Err(IbanError::InvalidChecksum), Iban::parse("GB28NWBK60161331926819"));
+/// assert_eq!(Err(IbanError::UnknownCountry), Iban::parse("US12345678901234"));
+/// ```
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Iban {
+    bytes: [u8; MAX_LENGTH],
+    format: &'static IbanFormat,
+}
+
+impl Iban {
+    /// Parse and validate an IBAN in electronic or print format.
+    ///
+    /// Whitespace is ignored and letters may be in either case, so `de89 3704 0044 0532 0130 00`
+    /// is accepted. The IBAN must start with the code of a country which has its own entry in
+    /// the registry, so territories such as Guernsey, which use IBANs starting with `GB`, are not
+    /// accepted as a prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IbanError`] describing the first check the IBAN fails, in the order of the
+    /// characters, the country, the length, the structure and the checksum.
+    pub fn parse(iban: &str) -> Result<Self, IbanError> {
+        let mut bytes = [0; MAX_LENGTH];
+        let mut length = 0;
+
+        for c in iban.bytes().filter(|c| !c.is_ascii_whitespace()) {
+            if !c.is_ascii_alphanumeric() {
+                return Err(IbanError::InvalidCharset);
+            }
+            *bytes.get_mut(length).ok_or(IbanError::InvalidLength)? = c.to_ascii_uppercase();
+            length += 1;
+        }
+
+        if length < 2 {
+            return Err(IbanError::InvalidLength);
+        }
+
+        let format = core::str::from_utf8(&bytes[..2])
+            .ok()
+            .and_then(|prefix| Alpha2::from_str_slice(prefix).ok())
+            .and_then(|country| {
+                country
+                    .iban_format()
+                    .filter(|format| format.country == country)
+            })
+            .ok_or(IbanError::UnknownCountry)?;
+
+        if length != usize::from(format.length) {
+            return Err(IbanError::InvalidLength);
+        }
+
+        let iban = &bytes[..length];
+        if !iban[2..4].iter().all(u8::is_ascii_digit) {
+            return Err(IbanError::InvalidStructure);
+        }
+
+        let mut bban = &iban[4..];
+        for segment in format.bban {
+            let (head, tail) = bban.split_at(usize::from(segment.length));
+            if !head.iter().all(|&c| segment.characters.matches(c)) {
+                return Err(IbanError::InvalidStructure);
+            }
+            bban = tail;
+        }
+
+        if remainder(iban) != 1 {
+            return Err(IbanError::InvalidChecksum);
+        }
+
+        Ok(Self { bytes, format })
+    }
+
+    /// Get the IBAN in electronic format, without spaces.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.slice(0, usize::from(self.format.length))
+    }
+
+    /// Get the country whose code starts the IBAN.
+    #[must_use]
+    pub const fn country(&self) -> Alpha2 {
+        self.format.country
+    }
+
+    /// Get the country's IBAN format, which the IBAN follows.
+    #[must_use]
+    pub const fn format(&self) -> &'static IbanFormat {
+        self.format
+    }
+
+    /// Get the two check digits which follow the country code.
+    #[must_use]
+    pub fn check_digits(&self) -> &str {
+        self.slice(2, 4)
+    }
+
+    /// Get the Basic Bank Account Number, the part of the IBAN after the check digits.
+    #[must_use]
+    pub fn bban(&self) -> &str {
+        self.slice(4, usize::from(self.format.length))
+    }
+
+    /// Get the bank identifier within the BBAN, if the country's format has one.
+    #[must_use]
+    pub fn bank(&self) -> Option<&str> {
+        self.format.bank.map(|range| self.identifier(range))
+    }
+
+    /// Get the branch identifier within the BBAN, if the country's format has one.
+    #[must_use]
+    pub fn branch(&self) -> Option<&str> {
+        self.format.branch.map(|range| self.identifier(range))
+    }
+
+    /// Get the rest of the BBAN after the bank and branch identifiers.
+    ///
+    /// This is the account number, along with any national check digits which some countries,
+    /// such as France, place after it.
+    #[must_use]
+    pub fn account(&self) -> &str {
+        let start = [self.format.bank, self.format.branch]
+            .into_iter()
+            .flatten()
+            .map(|(_, end)| usize::from(end))
+            .max()
+            .unwrap_or_default();
+
+        self.slice(4 + start, usize::from(self.format.length))
+    }
+
+    /// Get the part of the BBAN between a `(start, end)` pair of offsets.
+    fn identifier(&self, (start, end): (u8, u8)) -> &str {
+        self.slice(4 + usize::from(start), 4 + usize::from(end))
+    }
+
+    /// Get the characters between two offsets in the IBAN, which are always ASCII.
+    fn slice(&self, start: usize, end: usize) -> &str {
+        core::str::from_utf8(&self.bytes[start..end]).unwrap_or_default()
+    }
+}
+
+impl Debug for Iban {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("Iban").field(&self.as_str()).finish()
+    }
+}
+
+/// Formats the IBAN in electronic format, or with `{:#}` in print format, in groups of four
+/// characters separated by spaces.
+impl Display for Iban {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !f.alternate() {
+            return f.write_str(self.as_str());
+        }
+
+        for (i, c) in self.as_str().chars().enumerate() {
+            if i > 0 && i % 4 == 0 {
+                f.write_char(' ')?;
+            }
+            f.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for Iban {
+    type Error = IbanError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl FromStr for Iban {
+    type Err = IbanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
@@ -0,0 +1,84 @@
+//! Conversions to and from ICU4X region subtags.
+
+use crate::{Alpha2, Error, Region};
+use icu_locid::subtags::Region as IcuRegion;
+
+/// Convert a region subtag which is known to be valid.
+fn icu_region(subtag: &str) -> IcuRegion {
+    match IcuRegion::try_from_bytes(subtag.as_bytes()) {
+        Ok(region) => region,
+        Err(_) => unreachable!("alpha-2 codes and M49 areas are valid region subtags"),
+    }
+}
+
+impl From<Alpha2> for IcuRegion {
+    fn from(value: Alpha2) -> Self {
+        icu_region(value.as_str())
+    }
+}
+
+impl From<Region> for IcuRegion {
+    fn from(value: Region) -> Self {
+        match value {
+            Region::Country(alpha2) => alpha2.into(),
+            Region::Area(area) => {
+                let code = u16::from(area);
+                let digits = [
+                    b'0' + u8::try_from(code / 100).unwrap_or_default(),
+                    b'0' + u8::try_from(code / 10 % 10).unwrap_or_default(),
+                    b'0' + u8::try_from(code % 10).unwrap_or_default(),
+                ];
+
+                icu_region(core::str::from_utf8(&digits).unwrap_or_default())
+            }
+        }
+    }
+}
+
+impl TryFrom<IcuRegion> for Region {
+    type Error = Error;
+
+    fn try_from(value: IcuRegion) -> Result<Self, Self::Error> {
+        value.as_str().parse()
+    }
+}
+
+impl TryFrom<IcuRegion> for Alpha2 {
+    type Error = Error;
+
+    fn try_from(value: IcuRegion) -> Result<Self, Self::Error> {
+        Region::try_from(value)?.alpha2().ok_or(Error::UnknownCode)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Alpha2, Area, Error, Region};
+    use icu_locid::{Locale, subtags::Region as IcuRegion};
+
+    #[yare::parameterized(
+        country = { Region::Country(Alpha2::Canada), "CA" },
+        area = { Region::Area(Area::LatinAmericaAndTheCaribbean), "419" },
+        world = { Region::Area(Area::World), "001" },
+    )]
+    fn roundtrip(region: Region, subtag: &str) {
+        let icu = IcuRegion::from(region);
+        assert_eq!(subtag, icu.as_str());
+        assert_eq!(Ok(region), Region::try_from(icu));
+    }
+
+    #[test]
+    fn locale() {
+        let locale = "fr-CA".parse::<Locale>().expect("valid locale");
+        let region = locale.id.region.expect("region");
+
+        assert_eq!(Ok(Alpha2::Canada), Alpha2::try_from(region));
+        assert_eq!(IcuRegion::from(Alpha2::Canada), region);
+    }
+
+    #[test]
+    fn area_is_not_alpha2() {
+        let region = IcuRegion::try_from_bytes(b"419").expect("valid region");
+        assert_eq!(Err(Error::UnknownCode), Alpha2::try_from(region));
+    }
+}
@@ -3,6 +3,17 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
+#[cfg(feature = "boundaries")]
+mod boundaries;
+mod cctld;
+mod geography;
+mod iban;
+#[cfg(feature = "icu")]
+mod icu;
+mod locale;
+mod names;
+mod neighbours;
+mod phone;
 #[cfg(feature = "serde")]
 mod serde_;
 
@@ -11,7 +22,69 @@ use core::{
     str::FromStr,
 };
 
-iso3166_macros::generate!(lukes_json = "all.json");
+#[cfg(not(any(feature = "pregenerated", feature = "macros")))]
+compile_error!("Either the `pregenerated` or `macros` feature must be enabled.");
+
+#[cfg(all(feature = "pregenerated", not(feature = "macros")))]
+#[allow(clippy::all, clippy::pedantic)]
+mod generated {
+    include!("generated.rs");
+}
+
+#[cfg(all(feature = "pregenerated", not(feature = "macros")))]
+pub use generated::*;
+
+#[cfg(feature = "macros")]
+iso3166_macros::generate!(
+    lukes_json = "all.json",
+    data_date = "2025-12-29",
+    include_m49,
+    name_index,
+    include_iso_codes,
+    include_sovereignty,
+    include_groups,
+    include_currencies,
+    include_withdrawn_currencies,
+    include_calling_codes,
+    include_public_suffix_list,
+    include_territory_info,
+    include_time_zones,
+    include_geography,
+    include_neighbours,
+    include_iban_registry
+);
+
+#[cfg(feature = "cldr")]
+#[allow(clippy::all, clippy::pedantic)]
+mod cldr {
+    use crate::Alpha2;
+
+    include!(concat!(env!("OUT_DIR"), "/cldr.rs"));
+}
+
+#[cfg(feature = "cldr")]
+pub use cldr::*;
+
+#[cfg(feature = "boundaries")]
+#[allow(clippy::all, clippy::pedantic)]
+mod boundary_tables {
+    use crate::Alpha2;
+
+    include!(concat!(env!("OUT_DIR"), "/boundaries.rs"));
+}
+
+#[cfg(feature = "boundaries")]
+pub use boundary_tables::*;
+
+#[cfg(feature = "boundaries")]
+pub use boundaries::{Location, country_at};
+pub use geography::distance_km;
+pub use iban::{Iban, IbanError};
+pub use locale::{Area, Region};
+pub use names::normalize;
+#[cfg(feature = "alloc")]
+pub use names::{Candidate, Match};
+pub use neighbours::{Borders, Component, Components, Route, components};
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -147,6 +220,712 @@ mod test {
     const USA_EXPECTED3: &str = "USA";
     const USA_EXPECTED_U16: u16 = 840;
 
+    #[test]
+    fn pregenerated_is_current() {
+        let expected = iso3166_codegen::Builder::new()
+            .source(iso3166_codegen::Source::LukesJsonFile(
+                concat!(env!("CARGO_MANIFEST_DIR"), "/src/all.json").into(),
+            ))
+            .data_date(DATA_DATE)
+            .m49(
+                iso3166_parsers::m49::Language::English,
+                iso3166_parsers::m49::SNAPSHOT,
+            )
+            .name_index(true)
+            .iso_codes(iso3166_parsers::iso_codes::SNAPSHOT)
+            .sovereignty(iso3166_parsers::sovereignty::SNAPSHOT)
+            .groups(iso3166_parsers::groups::SNAPSHOT)
+            .currencies(iso3166_parsers::iso4217::LIST_ONE)
+            .withdrawn_currencies(iso3166_parsers::iso4217::LIST_THREE)
+            .calling_codes(iso3166_parsers::e164::SNAPSHOT)
+            .public_suffix_list(iso3166_parsers::psl::SNAPSHOT)
+            .territory_info(iso3166_parsers::cldr::TERRITORY_INFO)
+            .time_zones(iso3166_parsers::tzdata::ZONE_TAB)
+            .geography(iso3166_parsers::geo::SNAPSHOT)
+            .neighbours(iso3166_parsers::neighbours::SNAPSHOT)
+            .iban_registry(iso3166_parsers::iban::SNAPSHOT)
+            .examples(true)
+            .render()
+            .expect("generated code");
+
+        assert!(
+            expected == include_str!("generated.rs"),
+            "src/generated.rs is out of date, see the README for how to regenerate it"
+        );
+    }
+
+    #[test]
+    fn provenance() {
+        assert_eq!("2025-12-29", DATA_DATE);
+        assert_eq!(249, DATA_RECORD_COUNT);
+        assert_eq!(64, DATA_SHA256.len());
+        assert!(DATA_SHA256.bytes().all(|b| b.is_ascii_hexdigit()));
+        assert!(DATA_SOURCE.starts_with("https://"));
+    }
+
+    #[test]
+    fn name_in() {
+        assert_eq!(Some("Germany"), Alpha2::Germany.name_in(Language::English));
+        assert_eq!(
+            Some("Bolivia (Plurinational State of)"),
+            Alpha2::Bolivia.name_in(Language::English)
+        );
+        assert_eq!(None, Alpha2::Germany.name_in(Language::French));
+        assert_eq!(None, Alpha2::Taiwan.name_in(Language::English));
+        assert_eq!(None, Alpha2::UserXX.name_in(Language::English));
+        assert_eq!("zh", Language::Chinese.code());
+    }
+
+    #[yare::parameterized(
+        reunion = { Alpha2::Reunion, Some("Réunion"), Some("Reunion"), Some("RÉUNION") },
+        cote_divoire = { Alpha2::CoteDIvoire, Some("Côte d'Ivoire"), Some("Cote d'Ivoire"), Some("CÔTE D'IVOIRE") },
+        ascii = { Alpha2::Canada, Some("Canada"), Some("Canada"), Some("CANADA") },
+        user = { Alpha2::UserXX, None, None, None },
+    )]
+    fn names(
+        input: Alpha2,
+        name: Option<&str>,
+        name_ascii: Option<&str>,
+        name_upper: Option<&str>,
+    ) {
+        assert_eq!(name, input.name());
+        assert_eq!(name_ascii, input.name_ascii());
+        assert_eq!(name_upper, input.name_upper());
+        assert!(input.name_ascii().is_none_or(str::is_ascii));
+    }
+
+    #[yare::parameterized(
+        usa = { Alpha2::UnitedStatesOfAmerica, Some("United States of America") },
+        turkiye = { Alpha2::Turkey, Some("Republic of Türkiye") },
+        none = { Alpha2::Canada, None },
+    )]
+    fn formal_name(input: Alpha2, expected: Option<&str>) {
+        assert_eq!(expected, input.formal_name());
+    }
+
+    #[yare::parameterized(
+        puerto_rico = { Alpha2::PuertoRico, false, Some(Alpha2::UnitedStatesOfAmerica) },
+        greenland = { Alpha2::Greenland, false, Some(Alpha2::Denmark) },
+        bouvet = { Alpha2::BouvetIsland, false, Some(Alpha2::Norway) },
+        hong_kong = { Alpha2::HongKong, false, Some(Alpha2::China) },
+        antarctica = { Alpha2::Antarctica, false, None },
+        western_sahara = { Alpha2::WesternSahara, false, None },
+        palestine = { Alpha2::Palestine, false, None },
+        germany = { Alpha2::Germany, true, None },
+        user = { Alpha2::UserXX, false, None },
+    )]
+    fn sovereignty(input: Alpha2, independent: bool, sovereign: Option<Alpha2>) {
+        assert_eq!(independent, input.is_independent());
+        assert_eq!(sovereign, input.sovereign());
+        if let Some(sovereign) = sovereign {
+            assert!(sovereign.dependencies().contains(&input));
+        }
+
+        let numeric = Numeric::try_from(input).ok();
+        assert_eq!(
+            sovereign.and_then(|s| Numeric::try_from(s).ok()),
+            numeric.and_then(|n| n.sovereign())
+        );
+    }
+
+    #[test]
+    fn dependencies() {
+        assert_eq!(
+            &[Alpha3::HongKong, Alpha3::Macao],
+            Alpha3::China.dependencies()
+        );
+        assert_eq!(0, Alpha2::Antarctica.dependencies().len());
+    }
+
+    #[yare::parameterized(
+        uk_eu_current = { Alpha2::UnitedKingdom, Group::EuropeanUnion, None, false },
+        uk_eu_member = { Alpha2::UnitedKingdom, Group::EuropeanUnion, Some("2020-01-31"), true },
+        uk_eu_left = { Alpha2::UnitedKingdom, Group::EuropeanUnion, Some("2020-02-01"), false },
+        germany_eu = { Alpha2::Germany, Group::EuropeanUnion, None, true },
+        norway_eea = { Alpha2::Norway, Group::EuropeanEconomicArea, None, true },
+        norway_eu = { Alpha2::Norway, Group::EuropeanUnion, None, false },
+        switzerland_schengen = { Alpha2::Switzerland, Group::Schengen, None, true },
+        ireland_schengen = { Alpha2::Ireland, Group::Schengen, None, false },
+        bulgaria_euro = { Alpha2::Bulgaria, Group::Eurozone, Some("2025-12-31"), false },
+        sweden_nato = { Alpha2::Sweden, Group::Nato, Some("2024-03-07"), true },
+        user = { Alpha2::UserXX, Group::Oecd, None, false },
+    )]
+    fn groups(country: Alpha2, group: Group, date: Option<&str>, expected: bool) {
+        let actual = match date {
+            Some(date) => country.was_member_of(group, Date::parse(date).expect("valid date")),
+            None => country.is_member_of(group),
+        };
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn group_members() {
+        assert_eq!("EU", Group::EuropeanUnion.code());
+        assert_eq!(27, Group::EuropeanUnion.members().count());
+        let date = |value| Date::parse(value).expect("valid date");
+        assert_eq!(
+            28,
+            Group::EuropeanUnion.members_on(date("2020-01-01")).count()
+        );
+        assert_eq!(
+            6,
+            Group::EuropeanUnion.members_on(date("1958-01-01")).count()
+        );
+        assert!(Group::ALL.contains(&Group::G20));
+    }
+
+    #[yare::parameterized(
+        germany = { Alpha2::Germany, &[Currency::EUR] },
+        switzerland = { Alpha2::Switzerland, &[Currency::CHE, Currency::CHF, Currency::CHW] },
+        panama = { Alpha2::Panama, &[Currency::PAB, Currency::USD] },
+        curacao = { Alpha2::Curacao, &[Currency::XCG] },
+        antarctica = { Alpha2::Antarctica, &[] },
+        user = { Alpha2::UserXX, &[] },
+    )]
+    fn currencies(country: Alpha2, expected: &[Currency]) {
+        assert_eq!(expected, country.currencies());
+        for currency in expected {
+            assert!(currency.countries().contains(&country));
+        }
+    }
+
+    #[test]
+    fn currency_details() {
+        assert_eq!(Some(Currency::JPY), Currency::from_code("JPY"));
+        assert_eq!(None, Currency::from_code("jpy"));
+        assert_eq!(392, Currency::JPY.numeric());
+        assert_eq!(Some(0), Currency::JPY.minor_units());
+        assert_eq!(Some(3), Currency::KWD.minor_units());
+        assert!(Currency::USN.is_fund());
+        assert!(!Currency::HRK.is_current());
+        assert_eq!(&[Alpha2::Croatia], Currency::HRK.former_countries());
+        assert_eq!(
+            &[Alpha2::Curacao, Alpha2::SintMaartenDutchPart],
+            Currency::ANG.former_countries()
+        );
+        assert_eq!("2023-01", Alpha2::Croatia.former_currencies()[0].withdrawn);
+    }
+
+    #[yare::parameterized(
+        new_york = { "+1 212 555 0100", &[Alpha2::UnitedStatesOfAmerica] },
+        toronto = { "+1 416 555 0100", &[Alpha2::Canada] },
+        puerto_rico = { "+1-939-555-0100", &[Alpha2::PuertoRico] },
+        dominican_republic = { "18095550100", &[Alpha2::DominicanRepublic] },
+        nanp_incomplete = { "+1 4", &[Alpha2::Bermuda, Alpha2::Canada, Alpha2::Grenada, Alpha2::UnitedStatesOfAmerica] },
+        jersey = { "+44 1534 123456", &[Alpha2::Jersey] },
+        london = { "+44 20 7946 0000", &[Alpha2::UnitedKingdom] },
+        uk_incomplete = { "+44", &[Alpha2::UnitedKingdom, Alpha2::Guernsey, Alpha2::Jersey, Alpha2::IsleOfMan] },
+        kazakhstan = { "+7 727 123 4567", &[Alpha2::Kazakhstan] },
+        russia = { "+7 495 123 4567", &[Alpha2::Russia] },
+        vatican = { "+39 06 698 12345", &[Alpha2::HolySee] },
+        rome = { "+39 06 1234 5678", &[Alpha2::Italy] },
+        guadeloupe = { "+590 590 12 34 56", &[Alpha2::Guadeloupe] },
+        saint_barthelemy = { "+590 590 27 12 34", &[Alpha2::SaintBarthelemy] },
+        shared_mobile = { "+590 690 12 34 56", &[Alpha2::Guadeloupe] },
+        unassigned = { "+999 123", &[] },
+        invalid = { "+1 800 FLOWERS", &[] },
+        empty = { "", &[] },
+    )]
+    fn from_phone_number(number: &str, expected: &[Alpha2]) {
+        assert_eq!(
+            expected,
+            Alpha2::from_phone_number(number).collect::<std::vec::Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn calling_codes() {
+        assert_eq!(&["+1"], Alpha2::UnitedStatesOfAmerica.calling_codes());
+        assert_eq!(&["+44"], Alpha2::IsleOfMan.calling_codes());
+        assert_eq!(&["+39"], Alpha2::HolySee.calling_codes());
+        assert_eq!(0, Alpha2::UserXX.calling_codes().len());
+        assert!(
+            CALLING_PREFIXES
+                .iter()
+                .all(|entry| entry.prefix.starts_with(entry.country_code))
+        );
+    }
+
+    #[yare::parameterized(
+        united_kingdom = { "uk", Some(Alpha2::UnitedKingdom) },
+        dormant = { "gb", Some(Alpha2::UnitedKingdom) },
+        ascension = { "ac", Some(Alpha2::SaintHelenaAscensionAndTristanDaCunha) },
+        leading_dot = { ".de", Some(Alpha2::Germany) },
+        uppercase = { "FR.", Some(Alpha2::France) },
+        idn = { "рф", Some(Alpha2::Russia) },
+        idn_uppercase = { "РФ", Some(Alpha2::Russia) },
+        punycode = { "xn--p1ai", Some(Alpha2::Russia) },
+        punycode_uppercase = { "XN--P1AI", Some(Alpha2::Russia) },
+        european_union = { "eu", None },
+        soviet_union = { "su", None },
+        generic = { "com", None },
+        empty = { "", None },
+    )]
+    fn from_cctld(domain: &str, expected: Option<Alpha2>) {
+        assert_eq!(expected, Alpha2::from_cctld(domain));
+    }
+
+    #[test]
+    fn cctlds() {
+        assert_eq!(
+            "sh",
+            Alpha2::SaintHelenaAscensionAndTristanDaCunha.cctlds()[0].name
+        );
+        assert_eq!(0, Alpha2::SaintBarthelemy.cctlds().len());
+        assert!(
+            CCTLDS
+                .iter()
+                .all(|cctld| Alpha2::from_cctld(cctld.ascii) == Some(cctld.country))
+        );
+    }
+
+    #[yare::parameterized(
+        bcp47 = { "en-US", Ok(Some(Region::Country(Alpha2::UnitedStatesOfAmerica))) },
+        lowercase = { "pt-br", Ok(Some(Region::Country(Alpha2::Brazil))) },
+        area = { "es-419", Ok(Some(Region::Area(Area::LatinAmericaAndTheCaribbean))) },
+        numeric_country = { "en-840", Ok(Some(Region::Country(Alpha2::UnitedStatesOfAmerica))) },
+        script = { "zh-Hant-TW", Ok(Some(Region::Country(Alpha2::Taiwan))) },
+        extlang = { "zh-yue-HK", Ok(Some(Region::Country(Alpha2::HongKong))) },
+        variant = { "sl-IT-nedis", Ok(Some(Region::Country(Alpha2::Italy))) },
+        extension = { "en-GB-u-ca-gregory", Ok(Some(Region::Country(Alpha2::UnitedKingdom))) },
+        posix = { "de_AT.UTF-8", Ok(Some(Region::Country(Alpha2::Austria))) },
+        posix_modifier = { "sr_RS@latin", Ok(Some(Region::Country(Alpha2::Serbia))) },
+        language_only = { "en", Ok(None) },
+        script_only = { "zh-Hans", Ok(None) },
+        variant_only = { "de-1996", Ok(None) },
+        posix_c = { "C.UTF-8", Ok(None) },
+        private_use = { "x-whatever", Ok(None) },
+        unknown_country = { "en-JJ", Err(Error::UnknownCode) },
+        unknown_area = { "en-999", Err(Error::UnknownCode) },
+        empty = { "", Err(Error::InvalidLength) },
+        empty_subtag = { "en--US", Err(Error::InvalidLength) },
+        long_subtag = { "en-abcdefghi", Err(Error::InvalidLength) },
+        invalid_charset = { "en US", Err(Error::InvalidCharset) },
+        numeric_language = { "12-US", Err(Error::InvalidCharset) },
+    )]
+    fn region_from_locale(locale: &str, expected: Result<Option<Region>, Error>) {
+        assert_eq!(expected, Region::from_locale(locale));
+    }
+
+    #[yare::parameterized(
+        country = { "CA", Region::Country(Alpha2::Canada) },
+        area = { "001", Region::Area(Area::World) },
+    )]
+    fn region_display_roundtrip(subtag: &str, region: Region) {
+        assert_eq!(subtag, region.to_string());
+        assert_eq!(Ok(region), subtag.parse());
+    }
+
+    #[test]
+    fn area_from_u16() {
+        for area in Area::ALL {
+            assert_eq!(Ok(*area), Area::try_from(u16::from(*area)));
+        }
+        assert_eq!(Err(Error::UnknownCode), Area::from_u16(840));
+    }
+
+    #[yare::parameterized(
+        canada = { Alpha2::Canada, &["en-CA", "fr-CA"] },
+        switzerland = { Alpha2::Switzerland, &["de-CH", "fr-CH", "it-CH", "rm-CH"] },
+        united_states = { Alpha2::UnitedStatesOfAmerica, &["en-US"] },
+        india = { Alpha2::India, &["hi-IN", "en-IN"] },
+        eritrea = { Alpha2::Eritrea, &["ti-ER", "en-ER", "ar-ER"] },
+        sint_maarten = { Alpha2::SintMaartenDutchPart, &["en-SX", "nl-SX"] },
+        antarctica = { Alpha2::Antarctica, &[] },
+    )]
+    fn locales(country: Alpha2, expected: &[&str]) {
+        assert_eq!(expected, country.locales().collect::<std::vec::Vec<_>>());
+    }
+
+    #[test]
+    fn languages() {
+        let languages = Alpha2::Thailand.languages();
+        assert_eq!("th", languages[0].code);
+        assert!(languages[0].is_official());
+        assert!(!languages[1].is_official());
+        assert!(
+            languages
+                .windows(2)
+                .all(|pair| pair[0].population_percent >= pair[1].population_percent)
+        );
+    }
+
+    #[yare::parameterized(
+        berlin = { "Europe/Berlin", Some(Alpha2::Germany) },
+        busingen = { "Europe/Busingen", Some(Alpha2::Germany) },
+        new_york = { "America/New_York", Some(Alpha2::UnitedStatesOfAmerica) },
+        kolkata = { "Asia/Kolkata", Some(Alpha2::India) },
+        longyearbyen = { "Arctic/Longyearbyen", Some(Alpha2::SvalbardAndJanMayen) },
+        alias = { "US/Eastern", None },
+        utc = { "Etc/UTC", None },
+        case_sensitive = { "europe/berlin", None },
+    )]
+    fn country_for_zone(zone: &str, expected: Option<Alpha2>) {
+        assert_eq!(expected, super::country_for_zone(zone));
+    }
+
+    #[test]
+    fn time_zones() {
+        assert_eq!(&["Europe/London"], Alpha2::UnitedKingdom.time_zones());
+        assert_eq!(
+            "America/New_York",
+            Alpha2::UnitedStatesOfAmerica.time_zones()[0]
+        );
+        assert_eq!(0, Alpha2::UserXX.time_zones().len());
+
+        for zone in Alpha2::Australia.time_zones() {
+            assert_eq!(Some(Alpha2::Australia), super::country_for_zone(zone));
+        }
+    }
+
+    #[test]
+    fn tzdata_country_names() {
+        // The names tzdata simplifies, which do not match any name we know.
+        const SIMPLIFIED: &[&str] = &[
+            "AS", "BQ", "CD", "CF", "CG", "FK", "GB", "KP", "KR", "MF", "MM", "SX", "SZ", "TC",
+            "TF", "UM", "VC", "VG", "WS",
+        ];
+
+        let countries = iso3166_parsers::tzdata::read_countries(
+            iso3166_parsers::tzdata::ISO3166_TAB.as_bytes(),
+        )
+        .expect("valid iso3166.tab");
+        assert_eq!(DATA_RECORD_COUNT, countries.len());
+
+        for country in countries {
+            let alpha2 = Alpha2::from_str(&country.alpha2).expect("known alpha-2 code");
+            let found = Alpha2::from_name(&country.name);
+
+            if SIMPLIFIED.contains(&country.alpha2.as_str()) {
+                assert_eq!(None, found, "{} now matches", country.name);
+            } else {
+                assert_eq!(Some(alpha2), found, "{}", country.name);
+            }
+        }
+    }
+
+    #[yare::parameterized(
+        fiji = { Alpha2::Fiji, -17.8, 178.0, -16.8, -179.9 },
+        russia = { Alpha2::Russia, 55.76, 37.62, 66.0, -172.0 },
+        kiribati = { Alpha2::Kiribati, 1.33, 172.98, 1.87, -157.4 },
+    )]
+    fn bounding_box_crosses_antimeridian(
+        country: Alpha2,
+        west_latitude: f64,
+        west_longitude: f64,
+        east_latitude: f64,
+        east_longitude: f64,
+    ) {
+        let bounds = country.bounding_box().expect("bounding box");
+
+        assert!(bounds.crosses_antimeridian());
+        assert!(bounds.west() > bounds.east());
+        assert!(bounds.contains(Coordinates::from_degrees(west_latitude, west_longitude)));
+        assert!(bounds.contains(Coordinates::from_degrees(east_latitude, east_longitude)));
+        assert!(!bounds.contains(Coordinates::from_degrees(east_latitude, 0.0)));
+    }
+
+    #[test]
+    fn geography() {
+        let capital = Alpha2::Germany.capital().expect("capital");
+        assert_eq!("Berlin", capital.name);
+        assert_eq!(52_520_000, capital.coordinates.latitude_e6);
+        assert!((capital.coordinates.longitude() - 13.4).abs() < 1e-9);
+        assert_eq!(None, Alpha2::Antarctica.capital());
+        assert_eq!(None, Alpha2::UserXX.centroid());
+
+        let germany = Alpha2::Germany.bounding_box().expect("bounding box");
+        assert!(!germany.crosses_antimeridian());
+        assert!(germany.contains(capital.coordinates));
+        assert!(!germany.contains(Alpha2::France.capital().expect("capital").coordinates));
+
+        for country in (0..=999)
+            .filter_map(|code| Numeric::from_u16(code).ok())
+            .filter_map(|numeric| Alpha2::from_numeric(numeric).ok())
+        {
+            let (Some(centroid), Some(bounds)) = (country.centroid(), country.bounding_box())
+            else {
+                continue;
+            };
+
+            assert!(bounds.contains(centroid), "{country:?}");
+            if let Some(capital) = country.capital() {
+                assert!(bounds.contains(capital.coordinates), "{country:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn coordinates_from_degrees() {
+        let point = Coordinates::from_degrees(-33.868_82, 151.209_296);
+        assert_eq!(-33_868_820, point.latitude_e6);
+        assert_eq!(151_209_296, point.longitude_e6);
+        assert_eq!(Coordinates::default(), Coordinates::from_degrees(0.0, 0.0));
+    }
+
+    #[yare::parameterized(
+        same = { Alpha2::Germany, Alpha2::Germany, 0.0, 0.0 },
+        neighbours = { Alpha2::Germany, Alpha2::France, 750.0, 850.0 },
+        across_antimeridian = { Alpha2::Fiji, Alpha2::Samoa, 900.0, 1000.0 },
+        antipodes = { Alpha2::Spain, Alpha2::NewZealand, 19_500.0, 20_000.0 },
+    )]
+    fn distance(a: Alpha2, b: Alpha2, min: f64, max: f64) {
+        let distance = distance_km(a, b).expect("centroids");
+
+        assert!((min..=max).contains(&distance), "{distance}");
+        assert_eq!(Some(distance), distance_km(b, a));
+    }
+
+    #[test]
+    fn distance_capitals() {
+        let london = Alpha2::UnitedKingdom.capital().expect("capital");
+        let paris = Alpha2::France.capital().expect("capital");
+
+        let distance = london.coordinates.distance_km(paris.coordinates);
+        assert!((340.0..=346.0).contains(&distance), "{distance}");
+        assert_eq!(None, distance_km(Alpha2::Germany, Alpha2::UserXX));
+    }
+
+    #[yare::parameterized(
+        iceland = { Alpha2::Iceland },
+        japan = { Alpha2::Japan },
+        australia = { Alpha2::Australia },
+        new_zealand = { Alpha2::NewZealand },
+        madagascar = { Alpha2::Madagascar },
+        cuba = { Alpha2::Cuba },
+    )]
+    fn islands(country: Alpha2) {
+        assert_eq!(0, country.land_neighbours().len());
+        assert_ne!(0, country.maritime_neighbours().len());
+
+        let mut component = country.component(Borders::Land);
+        assert_eq!(Some(country), component.next());
+        assert_eq!(None, component.next());
+    }
+
+    #[yare::parameterized(
+        lesotho = { Alpha2::Lesotho, Alpha2::SouthAfrica },
+        san_marino = { Alpha2::SanMarino, Alpha2::Italy },
+        vatican = { Alpha2::HolySee, Alpha2::Italy },
+    )]
+    fn enclaves(enclave: Alpha2, surrounding: Alpha2) {
+        assert_eq!(&[surrounding], enclave.land_neighbours());
+        assert_eq!(0, enclave.maritime_neighbours().len());
+        assert!(surrounding.land_neighbours().contains(&enclave));
+        assert_eq!(
+            Some(1),
+            enclave.border_crossings(surrounding, Borders::Land)
+        );
+        assert_eq!(
+            None,
+            enclave.border_crossings(surrounding, Borders::Maritime)
+        );
+    }
+
+    #[test]
+    fn neighbours_symmetric() {
+        for country in (0..=999)
+            .filter_map(|code| Numeric::from_u16(code).ok())
+            .filter_map(|numeric| Alpha2::from_numeric(numeric).ok())
+        {
+            for neighbour in country.land_neighbours() {
+                assert!(
+                    neighbour.land_neighbours().contains(&country),
+                    "{country:?}"
+                );
+            }
+            for neighbour in country.maritime_neighbours() {
+                assert!(
+                    neighbour.maritime_neighbours().contains(&country),
+                    "{country:?}"
+                );
+            }
+        }
+
+        assert_eq!(0, Alpha2::UserXX.land_neighbours().len());
+    }
+
+    #[test]
+    fn route() {
+        let route = Alpha2::SanMarino
+            .route_to(Alpha2::HolySee, Borders::Land)
+            .expect("route");
+        assert_eq!(2, route.crossings());
+        assert_eq!(3, route.len());
+        assert_eq!(
+            [Alpha2::SanMarino, Alpha2::Italy, Alpha2::HolySee],
+            route.collect::<std::vec::Vec<_>>()[..]
+        );
+
+        let route = Alpha2::Germany
+            .route_to(Alpha2::Germany, Borders::Land)
+            .expect("route");
+        assert_eq!(0, route.crossings());
+        assert_eq!([Alpha2::Germany], route.collect::<std::vec::Vec<_>>()[..]);
+
+        assert_eq!(
+            None,
+            Alpha2::Iceland.border_crossings(Alpha2::Norway, Borders::Land)
+        );
+        assert_eq!(
+            Some(2),
+            Alpha2::Iceland.border_crossings(Alpha2::Norway, Borders::Any)
+        );
+        assert_eq!(
+            Some(2),
+            Alpha2::Lesotho.border_crossings(Alpha2::Botswana, Borders::Land)
+        );
+        assert_eq!(
+            None,
+            Alpha2::Germany.border_crossings(Alpha2::UserXX, Borders::Any)
+        );
+        assert_eq!(
+            None,
+            Alpha2::Spain.border_crossings(Alpha2::Brazil, Borders::Land)
+        );
+        assert_eq!(
+            None,
+            Alpha2::France.border_crossings(Alpha2::FrenchGuiana, Borders::Land)
+        );
+        assert_eq!(
+            Some(1),
+            Alpha2::FrenchGuiana.border_crossings(Alpha2::Brazil, Borders::Land)
+        );
+    }
+
+    #[test]
+    fn components() {
+        let land = super::components(Borders::Land).collect::<std::vec::Vec<_>>();
+        let total = land.iter().map(|c| c.clone().count()).sum::<usize>();
+        assert_eq!(249, total);
+
+        let eurasia = land
+            .iter()
+            .find(|component| component.contains(Alpha2::Germany))
+            .expect("Eurasia");
+        assert!(eurasia.contains(Alpha2::China));
+        assert!(eurasia.contains(Alpha2::SouthAfrica));
+        assert!(!eurasia.contains(Alpha2::UnitedStatesOfAmerica));
+        assert!(!eurasia.contains(Alpha2::UserXX));
+
+        let americas = Alpha2::Canada.component(Borders::Land);
+        assert!(americas.contains(Alpha2::Argentina));
+        assert!(americas.contains(Alpha2::FrenchGuiana));
+
+        let any = super::components(Borders::Any).count();
+        assert!(any < land.len());
+        assert_eq!(0, Alpha2::UserXX.component(Borders::Any).count());
+    }
+
+    #[yare::parameterized(
+        germany = { "DE89370400440532013000", Alpha2::Germany, Some("37040044"), None, "0532013000" },
+        print_format = { "de89 3704 0044 0532 0130 00", Alpha2::Germany, Some("37040044"), None, "0532013000" },
+        italy = { "IT60X0542811101000000123456", Alpha2::Italy, Some("05428"), Some("11101"), "000000123456" },
+        france = { "FR1420041010050500013M02606", Alpha2::France, Some("20041"), Some("01005"), "0500013M02606" },
+        norway = { "NO9386011117947", Alpha2::Norway, Some("8601"), None, "1117947" },
+    )]
+    fn iban(input: &str, country: Alpha2, bank: Option<&str>, branch: Option<&str>, account: &str) {
+        let iban = Iban::parse(input).expect("valid IBAN");
+        assert_eq!(country, iban.country());
+        assert_eq!(bank, iban.bank());
+        assert_eq!(branch, iban.branch());
+        assert_eq!(account, iban.account());
+        assert_eq!(input.replace(' ', "").to_uppercase(), iban.as_str());
+        assert_eq!(Ok(iban), input.parse());
+    }
+
+    #[yare::parameterized(
+        empty = { "", IbanError::InvalidLength },
+        short = { "DE8937040044053201300", IbanError::InvalidLength },
+        long = { "DE893704004405320130000", IbanError::InvalidLength },
+        too_long = { "MT84MALT011000012345MTLCAST001SXXXX", IbanError::InvalidLength },
+        punctuation = { "DE89-3704-0044-0532-0130-00", IbanError::InvalidCharset },
+        unicode = { "DE89370400440532013000💩", IbanError::InvalidCharset },
+        no_registry = { "US64SVBKUS6S3300958879", IbanError::UnknownCountry },
+        territory = { "GG29NWBK60161331926819", IbanError::UnknownCountry },
+        not_a_country = { "1289370400440532013000", IbanError::UnknownCountry },
+        check_letters = { "DEXX370400440532013000", IbanError::InvalidStructure },
+        bban_letters = { "DE89370400440532O13000", IbanError::InvalidStructure },
+        transposed = { "DE89370400440532031000", IbanError::InvalidChecksum },
+        check_digits = { "DE88370400440532013000", IbanError::InvalidChecksum },
+    )]
+    fn iban_invalid(input: &str, expected: IbanError) {
+        assert_eq!(Err(expected), Iban::parse(input));
+    }
+
+    #[test]
+    fn iban_formats() {
+        for format in IBAN_FORMATS {
+            let iban = Iban::parse(format.example).expect(format.example);
+            assert_eq!(format.country, iban.country());
+            assert_eq!(Some(format), format.country.iban_format());
+            assert_eq!(
+                usize::from(format.length),
+                format
+                    .bban
+                    .iter()
+                    .map(|s| usize::from(s.length))
+                    .sum::<usize>()
+                    + 4
+            );
+        }
+
+        assert_eq!(
+            Alpha2::UnitedKingdom.iban_format(),
+            Alpha2::IsleOfMan.iban_format()
+        );
+        assert_eq!(Alpha2::France.iban_format(), Alpha2::Reunion.iban_format());
+        assert_eq!(None, Alpha2::UserXX.iban_format());
+    }
+
+    #[test]
+    fn iban_display() {
+        let iban = Iban::parse("IT60X0542811101000000123456").expect("valid IBAN");
+        assert_eq!("IT60X0542811101000000123456", iban.to_string());
+        assert_eq!(
+            "IT60 X054 2811 1010 0000 0123 456",
+            std::format!("{iban:#}")
+        );
+        assert_eq!(
+            "Iban(\"IT60X0542811101000000123456\")",
+            std::format!("{iban:?}")
+        );
+    }
+
+    #[test]
+    fn group_scheduled_accession() {
+        // Bulgaria's adoption of the euro on 2026-01-01 was agreed before the data's date.
+        assert_eq!("2025-12-29", DATA_DATE);
+        assert!(!Alpha2::Bulgaria.is_member_of(Group::Eurozone));
+        assert!(!Group::Eurozone.members().any(|c| c == Alpha2::Bulgaria));
+        assert_eq!(20, Group::Eurozone.members().count());
+
+        let euro = Date::new(2026, 1, 1).expect("valid date");
+        assert!(Alpha2::Bulgaria.was_member_of(Group::Eurozone, euro));
+        assert_eq!(21, Group::Eurozone.members_on(euro).count());
+    }
+
+    #[yare::parameterized(
+        valid = { "2020-01-31", Some((2020, 1, 31)) },
+        leap = { "2024-02-29", Some((2024, 2, 29)) },
+        century = { "2000-02-29", Some((2000, 2, 29)) },
+        not_leap = { "1900-02-29", None },
+        unpadded = { "2020-2-1", None },
+        slashes = { "2020/01/31", None },
+        month = { "2020-13-01", None },
+        day = { "2020-04-31", None },
+        zero = { "2020-01-00", None },
+        empty = { "", None },
+    )]
+    fn date_parse(input: &str, expected: Option<(u16, u8, u8)>) {
+        let date = Date::parse(input);
+        assert_eq!(expected, date.map(|d| (d.year(), d.month(), d.day())));
+        assert_eq!(date, expected.and_then(|(y, m, d)| Date::new(y, m, d)));
+        if let Some(date) = date {
+            assert_eq!(input, date.to_string());
+        }
+    }
+
     #[test]
     fn numeric_display() {
         let src = Numeric::UnitedStatesOfAmerica;
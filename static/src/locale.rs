@@ -0,0 +1,376 @@
+//! Region subtags of BCP 47 language tags and POSIX locale names, and the likely locales of each
+//! country, using the generated [`Alpha2::languages()`].
+
+use crate::{Alpha2, CountryLanguage, Error, Numeric};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// The UN M49 areas which may be used as the region subtag of a BCP 47 language tag.
+///
+/// These are the numeric region subtags in the IANA Language Subtag Registry. BCP 47 does not
+/// register the numeric codes of individual countries, which are written as their alpha-2 code.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum Area {
+    /// World (001)
+    World = 1,
+    /// Africa (002)
+    Africa = 2,
+    /// North America (003)
+    NorthAmerica = 3,
+    /// South America (005)
+    SouthAmerica = 5,
+    /// Oceania (009)
+    Oceania = 9,
+    /// Western Africa (011)
+    WesternAfrica = 11,
+    /// Central America (013)
+    CentralAmerica = 13,
+    /// Eastern Africa (014)
+    EasternAfrica = 14,
+    /// Northern Africa (015)
+    NorthernAfrica = 15,
+    /// Middle Africa (017)
+    MiddleAfrica = 17,
+    /// Southern Africa (018)
+    SouthernAfrica = 18,
+    /// Americas (019)
+    Americas = 19,
+    /// Northern America (021)
+    NorthernAmerica = 21,
+    /// Caribbean (029)
+    Caribbean = 29,
+    /// Eastern Asia (030)
+    EasternAsia = 30,
+    /// Southern Asia (034)
+    SouthernAsia = 34,
+    /// South-eastern Asia (035)
+    SouthEasternAsia = 35,
+    /// Southern Europe (039)
+    SouthernEurope = 39,
+    /// Australia and New Zealand (053)
+    AustraliaAndNewZealand = 53,
+    /// Melanesia (054)
+    Melanesia = 54,
+    /// Micronesia (057)
+    Micronesia = 57,
+    /// Polynesia (061)
+    Polynesia = 61,
+    /// Asia (142)
+    Asia = 142,
+    /// Central Asia (143)
+    CentralAsia = 143,
+    /// Western Asia (145)
+    WesternAsia = 145,
+    /// Europe (150)
+    Europe = 150,
+    /// Eastern Europe (151)
+    EasternEurope = 151,
+    /// Northern Europe (154)
+    NorthernEurope = 154,
+    /// Western Europe (155)
+    WesternEurope = 155,
+    /// Sub-Saharan Africa (202)
+    SubSaharanAfrica = 202,
+    /// Latin America and the Caribbean (419)
+    LatinAmericaAndTheCaribbean = 419,
+}
+
+impl Area {
+    /// Every area, in numeric order.
+    pub const ALL: &[Area] = &[
+        Self::World,
+        Self::Africa,
+        Self::NorthAmerica,
+        Self::SouthAmerica,
+        Self::Oceania,
+        Self::WesternAfrica,
+        Self::CentralAmerica,
+        Self::EasternAfrica,
+        Self::NorthernAfrica,
+        Self::MiddleAfrica,
+        Self::SouthernAfrica,
+        Self::Americas,
+        Self::NorthernAmerica,
+        Self::Caribbean,
+        Self::EasternAsia,
+        Self::SouthernAsia,
+        Self::SouthEasternAsia,
+        Self::SouthernEurope,
+        Self::AustraliaAndNewZealand,
+        Self::Melanesia,
+        Self::Micronesia,
+        Self::Polynesia,
+        Self::Asia,
+        Self::CentralAsia,
+        Self::WesternAsia,
+        Self::Europe,
+        Self::EasternEurope,
+        Self::NorthernEurope,
+        Self::WesternEurope,
+        Self::SubSaharanAfrica,
+        Self::LatinAmericaAndTheCaribbean,
+    ];
+
+    /// Find the area with the given M49 code.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::UnknownCode`] if the code is not one of the areas.
+    pub fn from_u16(code: u16) -> Result<Self, Error> {
+        Self::ALL
+            .iter()
+            .find(|area| **area as u16 == code)
+            .copied()
+            .ok_or(Error::UnknownCode)
+    }
+
+    /// Get the English M49 name of the area.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::World => "World",
+            Self::Africa => "Africa",
+            Self::NorthAmerica => "North America",
+            Self::SouthAmerica => "South America",
+            Self::Oceania => "Oceania",
+            Self::WesternAfrica => "Western Africa",
+            Self::CentralAmerica => "Central America",
+            Self::EasternAfrica => "Eastern Africa",
+            Self::NorthernAfrica => "Northern Africa",
+            Self::MiddleAfrica => "Middle Africa",
+            Self::SouthernAfrica => "Southern Africa",
+            Self::Americas => "Americas",
+            Self::NorthernAmerica => "Northern America",
+            Self::Caribbean => "Caribbean",
+            Self::EasternAsia => "Eastern Asia",
+            Self::SouthernAsia => "Southern Asia",
+            Self::SouthEasternAsia => "South-eastern Asia",
+            Self::SouthernEurope => "Southern Europe",
+            Self::AustraliaAndNewZealand => "Australia and New Zealand",
+            Self::Melanesia => "Melanesia",
+            Self::Micronesia => "Micronesia",
+            Self::Polynesia => "Polynesia",
+            Self::Asia => "Asia",
+            Self::CentralAsia => "Central Asia",
+            Self::WesternAsia => "Western Asia",
+            Self::Europe => "Europe",
+            Self::EasternEurope => "Eastern Europe",
+            Self::NorthernEurope => "Northern Europe",
+            Self::WesternEurope => "Western Europe",
+            Self::SubSaharanAfrica => "Sub-Saharan Africa",
+            Self::LatinAmericaAndTheCaribbean => "Latin America and the Caribbean",
+        }
+    }
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:03}", *self as u16)
+    }
+}
+
+impl From<Area> for u16 {
+    fn from(value: Area) -> Self {
+        value as u16
+    }
+}
+
+impl TryFrom<u16> for Area {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::from_u16(value)
+    }
+}
+
+/// The region of a locale, which is either a country or an M49 area.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Region {
+    /// A country, written as its alpha-2 code, e.g. `US` in `en-US`.
+    Country(Alpha2),
+    /// An M49 area, written as its three-digit code, e.g. `419` in `es-419`.
+    Area(Area),
+}
+
+/// Whether the subtag is alphabetic, with a length in the given range.
+fn is_alpha(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+impl Region {
+    /// Extract the region from a BCP 47 language tag, such as `pt-BR` or `es-419`, or a POSIX
+    /// locale name, such as `de_AT.UTF-8`.
+    ///
+    /// The language, extended language and script subtags before the region are skipped, and
+    /// the codeset and modifier of a POSIX locale name are ignored. Numeric region subtags which
+    /// are the code of a country, rather than an area, return that country. Tags without a
+    /// region, such as `en`, `C` or private use tags, return `None`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCharset`] if the locale contains characters other than ASCII letters,
+    ///   digits and separators, or does not start with a language.
+    /// - [`Error::InvalidLength`] if a subtag is empty or longer than eight characters.
+    /// - [`Error::UnknownCode`] if the region is not a known country or area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::{Alpha2, Area, Region};
+    ///
+    /// assert_eq!(
+    ///     Ok(Some(Region::Country(Alpha2::Brazil))),
+    ///     Region::from_locale("pt-BR")
+    /// );
+    /// assert_eq!(
+    ///     Ok(Some(Region::Country(Alpha2::Austria))),
+    ///     Region::from_locale("de_AT.UTF-8")
+    /// );
+    /// assert_eq!(
+    ///     Ok(Some(Region::Area(Area::LatinAmericaAndTheCaribbean))),
+    ///     Region::from_locale("es-419")
+    /// );
+    /// assert_eq!(Ok(None), Region::from_locale("zh-Hant"));
+    /// ```
+    pub fn from_locale(locale: &str) -> Result<Option<Self>, Error> {
+        // The codeset and modifier of a POSIX locale name follow the territory.
+        let tag = locale.split(['.', '@']).next().unwrap_or_default();
+        if tag == "C" || tag == "POSIX" {
+            return Ok(None);
+        }
+
+        let mut subtags = tag.split(['-', '_']);
+        for subtag in subtags.clone() {
+            if !subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                return Err(Error::InvalidCharset);
+            }
+            if !(1..=8).contains(&subtag.len()) {
+                return Err(Error::InvalidLength);
+            }
+        }
+
+        let language = subtags.next().unwrap_or_default();
+        if language.eq_ignore_ascii_case("x") || language.eq_ignore_ascii_case("i") {
+            return Ok(None);
+        }
+        if !is_alpha(language, 2, 8) {
+            return Err(Error::InvalidCharset);
+        }
+
+        let mut subtags = subtags.peekable();
+        if language.len() <= 3 {
+            for _ in 0..3 {
+                if subtags.next_if(|subtag| is_alpha(subtag, 3, 3)).is_none() {
+                    break;
+                }
+            }
+        }
+        subtags.next_if(|subtag| is_alpha(subtag, 4, 4));
+
+        match subtags.next() {
+            Some(region)
+                if is_alpha(region, 2, 2)
+                    || (region.len() == 3 && region.bytes().all(|b| b.is_ascii_digit())) =>
+            {
+                region.parse().map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Get the country of the region, if it is not an area.
+    #[must_use]
+    pub const fn alpha2(&self) -> Option<Alpha2> {
+        match self {
+            Self::Country(alpha2) => Some(*alpha2),
+            Self::Area(_) => None,
+        }
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Country(alpha2) => alpha2.fmt(f),
+            Self::Area(area) => area.fmt(f),
+        }
+    }
+}
+
+impl From<Alpha2> for Region {
+    fn from(value: Alpha2) -> Self {
+        Self::Country(value)
+    }
+}
+
+impl From<Area> for Region {
+    fn from(value: Area) -> Self {
+        Self::Area(value)
+    }
+}
+
+impl FromStr for Region {
+    type Err = Error;
+
+    /// Parse a region subtag on its own, e.g. `US` or `419`, in any case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_ascii() {
+            return Err(Error::InvalidCharset);
+        }
+
+        match s.as_bytes() {
+            [a, b] => {
+                let upper = [a.to_ascii_uppercase(), b.to_ascii_uppercase()];
+                let upper = core::str::from_utf8(&upper).map_err(|_| Error::InvalidCharset)?;
+
+                Alpha2::from_str_slice(upper).map(Self::Country)
+            }
+            [_, _, _] => {
+                let code = s.parse::<u16>().map_err(|_| Error::InvalidCharset)?;
+                if let Ok(area) = Area::from_u16(code) {
+                    Ok(Self::Area(area))
+                } else {
+                    Alpha2::from_numeric(Numeric::from_u16(code)?).map(Self::Country)
+                }
+            }
+            _ => Err(Error::InvalidLength),
+        }
+    }
+}
+
+impl Alpha2 {
+    /// Get the likely locale tags of the country, such as `en-CA` and `fr-CA`.
+    ///
+    /// These are the tags of the languages which are official, or de facto official, throughout
+    /// the country, ordered by the share of the population which speaks them. Languages which
+    /// are only official in a region, such as Tamil in India, are left out. When no language is
+    /// official, the most widely spoken language is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::Alpha2;
+    ///
+    /// let locales = |country: Alpha2| country.locales().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(vec!["en-CA", "fr-CA"], locales(Alpha2::Canada));
+    /// assert_eq!(vec!["en-US"], locales(Alpha2::UnitedStatesOfAmerica));
+    /// assert!(locales(Alpha2::Antarctica).is_empty());
+    /// ```
+    pub fn locales(&self) -> impl Iterator<Item = &'static str> {
+        let languages = self.languages();
+        let any_official = languages.iter().any(CountryLanguage::is_official);
+
+        languages
+            .iter()
+            .enumerate()
+            .filter(move |(index, language)| {
+                language.is_official() || (!any_official && *index == 0)
+            })
+            .map(|(_, language)| language.locale)
+    }
+}
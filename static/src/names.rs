@@ -0,0 +1,339 @@
+//! Country lookups by name, using the generated [`NAME_INDEX`].
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use crate::{Alpha2, IndexedName, NAME_INDEX, STOP_WORDS, transliterate};
+use core::{char::ToLowercase, cmp::Ordering, str::Chars};
+
+#[cfg(feature = "alloc")]
+use crate::NameKind;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// A character of a normalized name, either from a transliteration or a lowercased character.
+enum Folded {
+    Ascii(Chars<'static>),
+    Lower(ToLowercase),
+}
+
+impl Iterator for Folded {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            Folded::Ascii(chars) => chars.next().map(|c| c.to_ascii_lowercase()),
+            Folded::Lower(lower) => lower.next(),
+        }
+    }
+}
+
+/// Normalize a name into the form used by the [`NAME_INDEX`] keys.
+///
+/// Names are split into words on anything other than letters and digits, [`STOP_WORDS`] are
+/// dropped, `St` is expanded to `saint`, and the remaining words are transliterated, lowercased,
+/// and joined without spaces, so `St. Kitts & Nevis` becomes `saintkittsnevis`.
+pub fn normalize(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| {
+            !word.is_empty()
+                && !STOP_WORDS
+                    .iter()
+                    .any(|stop| word.eq_ignore_ascii_case(stop))
+        })
+        .map(|word| {
+            if word.eq_ignore_ascii_case("st") {
+                "saint"
+            } else {
+                word
+            }
+        })
+        .flat_map(str::chars)
+        .flat_map(|c| match transliterate(c) {
+            Some(ascii) => Folded::Ascii(ascii.chars()),
+            None => Folded::Lower(c.to_lowercase()),
+        })
+}
+
+/// Whether `key` starts with the characters of `prefix`.
+fn has_prefix(key: &str, prefix: impl Iterator<Item = char>) -> bool {
+    let mut key = key.chars();
+    prefix.into_iter().all(|c| key.next() == Some(c))
+}
+
+/// The index of the first entry whose key is not less than the normalized `name`.
+fn lower_bound(name: &str) -> usize {
+    NAME_INDEX.partition_point(|entry| entry.key.chars().cmp(normalize(name)) == Ordering::Less)
+}
+
+/// How a [`Candidate`] matched a search query, from best to worst.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Match {
+    /// The normalized name is the normalized query.
+    Exact,
+    /// The normalized name starts with the normalized query.
+    Prefix,
+    /// The normalized name contains the normalized query.
+    Substring,
+}
+
+/// A country found by [`Alpha2::search()`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Candidate {
+    /// The country which matched.
+    pub country: Alpha2,
+    /// The name the country matched under.
+    pub name: &'static str,
+    /// The kind of name which matched.
+    pub kind: NameKind,
+    /// How the name matched the query.
+    pub matched: Match,
+}
+
+impl Alpha2 {
+    /// Look up a country by one of its names, ignoring case, diacritics, punctuation, and the
+    /// words `the` and `and`.
+    ///
+    /// Short names, ISO formal names, common names and a list of common aliases are all
+    /// accepted. When a name refers to more than one country, the country with the name of the
+    /// preferred [`NameKind`](crate::NameKind) is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::Alpha2;
+    ///
+    /// assert_eq!(Some(Alpha2::UnitedStatesOfAmerica), Alpha2::from_name("U.S.A."));
+    /// assert_eq!(Some(Alpha2::CoteDIvoire), Alpha2::from_name("Ivory Coast"));
+    /// assert_eq!(Some(Alpha2::VietNam), Alpha2::from_name("viet nam"));
+    /// assert_eq!(None, Alpha2::from_name("Atlantis"));
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Alpha2> {
+        NAME_INDEX[lower_bound(name)..]
+            .iter()
+            .take_while(|entry| entry.key.chars().eq(normalize(name)))
+            .min_by_key(|entry| entry.kind)
+            .map(|entry| entry.country)
+    }
+
+    /// Find the names which start with the given prefix, after normalization, in the order of
+    /// their normalized form.
+    ///
+    /// A country is returned once for each of its names which matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::Alpha2;
+    ///
+    /// let names = Alpha2::autocomplete("united k")
+    ///     .map(|entry| entry.country)
+    ///     .collect::<Vec<_>>();
+    /// assert!(names.contains(&Alpha2::UnitedKingdom));
+    /// ```
+    pub fn autocomplete(prefix: &str) -> impl Iterator<Item = &'static IndexedName> + '_ {
+        NAME_INDEX[lower_bound(prefix)..]
+            .iter()
+            .take_while(move |entry| has_prefix(entry.key, normalize(prefix)))
+    }
+
+    /// Search for countries whose names match the query exactly, by prefix, or by substring,
+    /// after normalization.
+    ///
+    /// Each country is returned once, under its best matching name. The candidates are ranked by
+    /// how they matched, then by the [`NameKind`](crate::NameKind) of the name, then by the length
+    /// of the name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::{Alpha2, Match};
+    ///
+    /// let candidates = Alpha2::search("korea");
+    /// assert_eq!(Alpha2::SouthKorea, candidates[0].country);
+    /// assert_eq!(Match::Prefix, candidates[0].matched);
+    /// assert!(candidates.iter().any(|c| c.country == Alpha2::NorthKorea));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn search(query: &str) -> Vec<Candidate> {
+        let query = normalize(query).collect::<String>();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut candidates = NAME_INDEX
+            .iter()
+            .filter_map(|entry| {
+                let matched = if entry.key == query {
+                    Match::Exact
+                } else if entry.key.starts_with(&query) {
+                    Match::Prefix
+                } else if entry.key.contains(&query) {
+                    Match::Substring
+                } else {
+                    return None;
+                };
+
+                Some(Candidate {
+                    country: entry.country,
+                    name: entry.name,
+                    kind: entry.kind,
+                    matched,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        candidates.sort_by_key(|candidate| {
+            (
+                candidate.matched,
+                candidate.kind,
+                candidate.name.len(),
+                candidate.name,
+            )
+        });
+
+        let mut seen = Vec::<Alpha2>::with_capacity(candidates.len());
+        candidates.retain(|candidate| {
+            if seen.contains(&candidate.country) {
+                false
+            } else {
+                seen.push(candidate.country);
+                true
+            }
+        });
+
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    #[cfg(feature = "alloc")]
+    use super::Match;
+    use super::normalize;
+    use crate::{Alpha2, NAME_INDEX, STOP_WORDS};
+    use std::string::String;
+
+    #[test]
+    fn index_is_normalized() {
+        for entry in NAME_INDEX {
+            assert_eq!(
+                entry.key,
+                normalize(entry.name).collect::<String>(),
+                "{}",
+                entry.name
+            );
+        }
+
+        assert!(NAME_INDEX.windows(2).all(|pair| pair[0].key <= pair[1].key));
+    }
+
+    #[yare::parameterized(
+        united_states = { "United States", Some(Alpha2::UnitedStatesOfAmerica) },
+        usa = { "U.S.A.", Some(Alpha2::UnitedStatesOfAmerica) },
+        formal = { "the united states of america", Some(Alpha2::UnitedStatesOfAmerica) },
+        viet_nam = { "Viet Nam", Some(Alpha2::VietNam) },
+        vietnam = { "Vietnam", Some(Alpha2::VietNam) },
+        cote_divoire = { "Côte d'Ivoire", Some(Alpha2::CoteDIvoire) },
+        cote_divoire_ascii = { "COTE D’IVOIRE", Some(Alpha2::CoteDIvoire) },
+        ivory_coast = { "Ivory Coast", Some(Alpha2::CoteDIvoire) },
+        saint = { "St Lucia", Some(Alpha2::SaintLucia) },
+        empty = { " . ", None },
+        unknown = { "Atlantis", None },
+    )]
+    fn from_name(input: &str, expected: Option<Alpha2>) {
+        assert_eq!(expected, Alpha2::from_name(input));
+    }
+
+    #[test]
+    fn autocomplete() {
+        let countries = Alpha2::autocomplete("United")
+            .map(|entry| entry.country)
+            .collect::<std::vec::Vec<_>>();
+
+        assert!(countries.contains(&Alpha2::UnitedStatesOfAmerica));
+        assert!(countries.contains(&Alpha2::UnitedArabEmirates));
+        assert!(!countries.contains(&Alpha2::Germany));
+        assert_eq!(0, Alpha2::autocomplete("xyzzy").count());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[yare::parameterized(
+        accented = { "Réunion", Alpha2::Reunion, Match::Exact },
+        unaccented = { "REUNION", Alpha2::Reunion, Match::Exact },
+        cedilla = { "curacao", Alpha2::Curacao, Match::Exact },
+        transliterated = { "turkiye", Alpha2::Turkey, Match::Exact },
+        accented_prefix = { "Côte", Alpha2::CoteDIvoire, Match::Prefix },
+        stop_word = { "the gambia", Alpha2::Gambia, Match::Exact },
+        ampersand = { "Trinidad & Tobago", Alpha2::TrinidadAndTobago, Match::Exact },
+        saint = { "st kitts", Alpha2::SaintKittsAndNevis, Match::Prefix },
+        shorter_name_first = { "korea", Alpha2::SouthKorea, Match::Prefix },
+        exact_first = { "congo", Alpha2::Congo, Match::Exact },
+    )]
+    fn search_first(query: &str, country: Alpha2, matched: Match) {
+        let candidates = Alpha2::search(query);
+        assert_eq!(
+            Some((country, matched)),
+            candidates.first().map(|c| (c.country, c.matched))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn search_ranking() {
+        let countries = |query| {
+            Alpha2::search(query)
+                .iter()
+                .map(|candidate| (candidate.country, candidate.matched))
+                .collect::<std::vec::Vec<_>>()
+        };
+
+        assert_eq!(
+            [
+                (Alpha2::Guinea, Match::Exact),
+                (Alpha2::GuineaBissau, Match::Prefix),
+                (Alpha2::PapuaNewGuinea, Match::Substring),
+                (Alpha2::EquatorialGuinea, Match::Substring),
+            ],
+            countries("guinea").as_slice()
+        );
+        assert_eq!(
+            [
+                (Alpha2::SouthKorea, Match::Prefix),
+                (Alpha2::NorthKorea, Match::Prefix),
+            ],
+            countries("korea").as_slice()
+        );
+        assert_eq!(
+            [
+                (Alpha2::AlandIslands, Match::Prefix),
+                (Alpha2::NewZealand, Match::Substring),
+            ],
+            countries("aland").as_slice()
+        );
+        assert_eq!(
+            [(Alpha2::VietNam, Match::Exact)],
+            countries("vietnam").as_slice()
+        );
+
+        // Queries which normalize to nothing match nothing, rather than everything.
+        assert_eq!(0, Alpha2::search("").len());
+        assert_eq!(0, Alpha2::search("the").len());
+        assert_eq!(0, Alpha2::search("and & the").len());
+    }
+
+    #[test]
+    fn stop_words() {
+        assert_eq!(&["and", "the"], STOP_WORDS);
+        assert_eq!(
+            "bosniaherzegovina",
+            normalize("The Bosnia AND Herzegovina").collect::<String>()
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Border graph searches over the generated [`Alpha2::land_neighbours()`] and
+//! [`Alpha2::maritime_neighbours()`].
+
+use crate::{Alpha2, Numeric};
+
+/// The number of possible numeric codes, which index the countries during a search.
+const CODES: usize = 1000;
+
+/// The marker for a country which has not been reached.
+const UNREACHED: u16 = u16::MAX;
+
+/// The kinds of border which connect countries in a graph search.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Borders {
+    /// Only land borders connect countries.
+    #[default]
+    Land,
+    /// Only maritime boundaries connect countries.
+    Maritime,
+    /// Both land borders and maritime boundaries connect countries.
+    Any,
+}
+
+impl Borders {
+    /// Get the neighbours of a country across these kinds of border.
+    fn neighbours(self, country: Alpha2) -> impl Iterator<Item = Alpha2> {
+        let (land, maritime): (&[Alpha2], &[Alpha2]) = match self {
+            Borders::Land => (country.land_neighbours(), &[]),
+            Borders::Maritime => (&[], country.maritime_neighbours()),
+            Borders::Any => (country.land_neighbours(), country.maritime_neighbours()),
+        };
+
+        land.iter().chain(maritime).copied()
+    }
+}
+
+/// Get the search index of a country, which is its numeric code.
+fn index(country: Alpha2) -> Option<usize> {
+    Numeric::from_alpha2(country)
+        .ok()
+        .map(|numeric| usize::from(numeric as u16))
+}
+
+/// Get the country with the given search index, if there is one.
+fn country(index: usize) -> Option<Alpha2> {
+    let numeric = Numeric::from_u16(u16::try_from(index).ok()?).ok()?;
+    Alpha2::from_numeric(numeric).ok()
+}
+
+/// Search breadth-first from `start`, recording the index each country was first reached from,
+/// with `start` reached from itself.
+fn search(start: Alpha2, borders: Borders) -> [u16; CODES] {
+    let mut parent = [UNREACHED; CODES];
+    let mut queue = [0; CODES];
+    let Some(first) = index(start) else {
+        return parent;
+    };
+
+    // Indices are below `CODES`, so they always fit in a `u16`.
+    let narrow = |index: usize| u16::try_from(index).unwrap_or(UNREACHED);
+    parent[first] = narrow(first);
+    queue[0] = first;
+    let (mut head, mut tail) = (0, 1);
+
+    while head < tail {
+        let current = queue[head];
+        head += 1;
+
+        let Some(country) = country(current) else {
+            continue;
+        };
+        for next in borders.neighbours(country).filter_map(index) {
+            if parent[next] == UNREACHED {
+                parent[next] = narrow(current);
+                queue[tail] = next;
+                tail += 1;
+            }
+        }
+    }
+
+    parent
+}
+
+/// A shortest route between two countries, which iterates over the countries along it from the
+/// start to the destination, inclusive.
+#[derive(Clone, Debug)]
+pub struct Route {
+    parent: [u16; CODES],
+    next: Option<usize>,
+    crossings: usize,
+    remaining: usize,
+}
+
+impl Route {
+    /// The number of borders crossed along the route, which is zero when the start is the
+    /// destination.
+    #[must_use]
+    pub const fn crossings(&self) -> usize {
+        self.crossings
+    }
+}
+
+impl Iterator for Route {
+    type Item = Alpha2;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let parent = usize::from(self.parent[current]);
+
+        self.next = (parent != current).then_some(parent);
+        self.remaining -= 1;
+        country(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Route {}
+
+/// A group of countries which are connected to each other by borders, which iterates over the
+/// countries in numeric order.
+#[derive(Clone, Debug)]
+pub struct Component {
+    parent: [u16; CODES],
+    next: usize,
+}
+
+impl Component {
+    /// Whether the given country is in the group.
+    #[must_use]
+    pub fn contains(&self, country: Alpha2) -> bool {
+        index(country).is_some_and(|index| self.parent[index] != UNREACHED)
+    }
+}
+
+impl Iterator for Component {
+    type Item = Alpha2;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < CODES {
+            let current = self.next;
+            self.next += 1;
+
+            if self.parent[current] != UNREACHED {
+                return country(current);
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over every group of connected countries, in the numeric order of their first
+/// country.
+#[derive(Clone, Debug)]
+pub struct Components {
+    borders: Borders,
+    seen: [bool; CODES],
+    next: usize,
+}
+
+impl Iterator for Components {
+    type Item = Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < CODES {
+            let current = self.next;
+            self.next += 1;
+
+            let Some(start) = country(current).filter(|_| !self.seen[current]) else {
+                continue;
+            };
+
+            let component = start.component(self.borders);
+            for (seen, parent) in self.seen.iter_mut().zip(component.parent) {
+                *seen |= parent != UNREACHED;
+            }
+            return Some(component);
+        }
+
+        None
+    }
+}
+
+/// Get every group of countries which are connected to each other by the given kinds of border.
+///
+/// Countries with no neighbours, such as islands when only land borders are used, are groups of
+/// their own.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso3166_static::{Alpha2, Borders, components};
+///
+/// let britain = components(Borders::Land)
+///     .find(|component| component.contains(Alpha2::UnitedKingdom))
+///     .expect("component");
+/// assert!(britain.contains(Alpha2::Ireland));
+/// assert!(!britain.contains(Alpha2::France));
+/// ```
+#[must_use]
+pub fn components(borders: Borders) -> Components {
+    Components {
+        borders,
+        seen: [false; CODES],
+        next: 0,
+    }
+}
+
+impl Alpha2 {
+    /// Find a route to another country which crosses the fewest borders of the given kinds.
+    ///
+    /// Returns `None` if the destination cannot be reached, or either country is user-assigned.
+    /// When several routes cross as few borders, one of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::{Alpha2, Borders};
+    ///
+    /// let route = Alpha2::Portugal.route_to(Alpha2::Germany, Borders::Land).expect("route");
+    /// assert_eq!(3, route.crossings());
+    /// assert_eq!(
+    ///     vec![Alpha2::Portugal, Alpha2::Spain, Alpha2::France, Alpha2::Germany],
+    ///     route.collect::<Vec<_>>()
+    /// );
+    /// assert!(Alpha2::Iceland.route_to(Alpha2::Norway, Borders::Land).is_none());
+    /// ```
+    #[must_use]
+    pub fn route_to(&self, destination: Alpha2, borders: Borders) -> Option<Route> {
+        // Searching from the destination lets the route be followed forwards from the start.
+        let parent = search(destination, borders);
+        let start = index(*self).filter(|&start| parent[start] != UNREACHED)?;
+
+        let mut crossings = 0;
+        let mut current = start;
+        while usize::from(parent[current]) != current {
+            current = usize::from(parent[current]);
+            crossings += 1;
+        }
+
+        Some(Route {
+            parent,
+            next: Some(start),
+            crossings,
+            remaining: crossings + 1,
+        })
+    }
+
+    /// Get the fewest borders of the given kinds which must be crossed to reach another country.
+    ///
+    /// Returns `None` if the destination cannot be reached, or either country is user-assigned.
+    #[must_use]
+    pub fn border_crossings(&self, destination: Alpha2, borders: Borders) -> Option<usize> {
+        self.route_to(destination, borders)
+            .map(|route| route.crossings())
+    }
+
+    /// Get the group of countries which can be reached from the country across the given kinds
+    /// of border, including the country itself.
+    ///
+    /// The group of a user-assigned code is empty.
+    #[must_use]
+    pub fn component(&self, borders: Borders) -> Component {
+        Component {
+            parent: search(*self, borders),
+            next: 0,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Country lookups by phone number, using the generated [`CALLING_PREFIXES`].
+
+use crate::{Alpha2, CALLING_PREFIXES};
+
+/// How a prefix compares with the digits of a phone number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Comparison {
+    /// The number starts with the prefix.
+    Matches,
+    /// The number is a proper prefix of the prefix, so it is too short to tell.
+    Incomplete,
+    /// The number does not start with the prefix.
+    Differs,
+}
+
+/// Whether the number contains only digits, whitespace, a leading `+` and the separators `-`,
+/// `.`, `(` and `)`, and at least one digit.
+fn is_valid(number: &str) -> bool {
+    let number = number.trim_start().strip_prefix('+').unwrap_or(number);
+
+    number.bytes().any(|b| b.is_ascii_digit())
+        && number
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_whitespace() || matches!(c, '-' | '.' | '(' | ')'))
+}
+
+/// Compare a prefix with the digits of a number.
+fn compare(prefix: &str, number: &str) -> Comparison {
+    let mut digits = number.bytes().filter(u8::is_ascii_digit);
+
+    for p in prefix.bytes() {
+        match digits.next() {
+            Some(d) if d == p => {}
+            Some(_) => return Comparison::Differs,
+            None => return Comparison::Incomplete,
+        }
+    }
+
+    Comparison::Matches
+}
+
+impl Alpha2 {
+    /// Find the countries which an E.164 phone number may belong to, in numeric order.
+    ///
+    /// The number is matched against the [`CALLING_PREFIXES`], and the countries with the
+    /// longest matching prefix are returned, so `+1 416` is in Canada while `+1 212` is in the
+    /// United States. When the number is too short to tell, e.g. `+44`, every country with a
+    /// longer prefix which the number could still match is returned as well. Numbers which
+    /// several countries share, such as the mobile numbers of Guadeloupe, Saint Barthélemy and
+    /// Saint Martin, belong to the main country for the calling code, as in libphonenumber.
+    ///
+    /// A leading `+`, whitespace, and the separators `-`, `.`, `(` and `)` are allowed, and
+    /// numbers containing anything else return no countries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iso3166_static::Alpha2;
+    ///
+    /// let countries = |number| Alpha2::from_phone_number(number).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(vec![Alpha2::Canada], countries("+1 (416) 555-0100"));
+    /// assert_eq!(vec![Alpha2::UnitedStatesOfAmerica], countries("+1 212 555 0100"));
+    /// assert_eq!(vec![Alpha2::Jamaica], countries("+1 876 555 0100"));
+    /// assert_eq!(vec![Alpha2::Guernsey], countries("+44 1481 223456"));
+    /// assert_eq!(vec![Alpha2::UnitedKingdom], countries("+44 20 7946 0000"));
+    /// assert_eq!(4, countries("+44").len());
+    /// assert!(countries("call me").is_empty());
+    /// ```
+    pub fn from_phone_number(number: &str) -> impl Iterator<Item = Alpha2> + '_ {
+        let valid = is_valid(number);
+        let longest = CALLING_PREFIXES
+            .iter()
+            .filter(|entry| valid && compare(entry.prefix, number) == Comparison::Matches)
+            .map(|entry| entry.prefix.len())
+            .max();
+
+        // The prefixes are grouped by country, so skipping repeats makes each country unique.
+        let mut previous = None;
+        CALLING_PREFIXES
+            .iter()
+            .filter(move |entry| {
+                valid
+                    && match compare(entry.prefix, number) {
+                        Comparison::Matches => Some(entry.prefix.len()) == longest,
+                        Comparison::Incomplete => true,
+                        Comparison::Differs => false,
+                    }
+            })
+            .filter_map(move |entry| {
+                if previous == Some(entry.country) {
+                    None
+                } else {
+                    previous = Some(entry.country);
+                    Some(entry.country)
+                }
+            })
+    }
+}
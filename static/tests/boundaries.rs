@@ -0,0 +1,147 @@
+//! Tests for reverse geocoding, built from the boundary snapshot in `iso3166-parsers`, Natural
+//! Earth's 1:110m countries, unless `ISO3166_BOUNDARIES` points at another file, which is expected
+//! to be as detailed as Natural Earth's 1:50m countries.
+
+#![cfg(feature = "boundaries")]
+
+#[cfg(iso3166_boundaries)]
+use iso3166_static::BOUNDARY_TOLERANCE;
+use iso3166_static::{Alpha2, BOUNDARY_RINGS, Location, Numeric, country_at};
+
+/// The furthest a transect may leave its first location from where it really crosses a border or
+/// coast, in kilometres, for the 1:110m snapshot, whose worst transect below is out by 11 km.
+#[cfg(not(iso3166_boundaries))]
+const CROSSING_KM: f64 = 15.0;
+
+/// Capitals which the 1:110m snapshot puts in the sea or a neighbour, as they are on a coast or
+/// small island its coarse outlines cut off, or within a few kilometres of a border.
+#[cfg(not(iso3166_boundaries))]
+const MISPLACED_CAPITALS: &[Alpha2] = &[
+    Alpha2::Bahamas,
+    Alpha2::Cyprus,
+    Alpha2::Djibouti,
+    Alpha2::EquatorialGuinea,
+    Alpha2::FalklandIslandsMalvinas,
+    Alpha2::Israel,
+    Alpha2::Libya,
+    Alpha2::NewCaledonia,
+    Alpha2::SierraLeone,
+    Alpha2::Uruguay,
+    Alpha2::Vanuatu,
+    Alpha2::WesternSahara,
+];
+
+/// Every capital is found in its country with boundaries as detailed as Natural Earth's 1:50m.
+#[cfg(iso3166_boundaries)]
+const MISPLACED_CAPITALS: &[Alpha2] = &[];
+
+/// The furthest a transect may leave its first location from where it really crosses a border or
+/// coast, in kilometres, allowing for the simplification of detailed boundaries.
+#[cfg(iso3166_boundaries)]
+const CROSSING_KM: f64 = 10.0 + BOUNDARY_TOLERANCE * 111.2;
+
+#[test]
+fn capitals() {
+    for country in (0..=999)
+        .filter_map(|code| Numeric::from_u16(code).ok())
+        .filter_map(|numeric| Alpha2::from_numeric(numeric).ok())
+    {
+        let Some(capital) = country.capital() else {
+            continue;
+        };
+        if MISPLACED_CAPITALS.contains(&country)
+            || !BOUNDARY_RINGS.iter().any(|ring| ring.country == country)
+        {
+            continue;
+        }
+
+        let coordinates = capital.coordinates;
+        assert_eq!(
+            Some(Location::Land(country)),
+            country_at(coordinates.latitude(), coordinates.longitude()),
+            "{}",
+            capital.name
+        );
+    }
+}
+
+#[yare::parameterized(
+    berlin = { 52.52, 13.40, Location::Land(Alpha2::Germany) },
+    maseru = { -29.31, 27.48, Location::Land(Alpha2::Lesotho) },
+    around_lesotho = { -29.0, 25.0, Location::Land(Alpha2::SouthAfrica) },
+    antarctica = { -80.0, 0.0, Location::Land(Alpha2::Antarctica) },
+    // Points about 15 km either side of a border, the accuracy of the snapshot.
+    strasbourg_west = { 48.58, 7.6, Location::Land(Alpha2::France) },
+    strasbourg_east = { 48.58, 8.0, Location::Land(Alpha2::Germany) },
+    forty_ninth_south = { 48.87, -100.0, Location::Land(Alpha2::UnitedStatesOfAmerica) },
+    forty_ninth_north = { 49.13, -100.0, Location::Land(Alpha2::Canada) },
+    atlantic = { 30.0, -40.0, Location::Ocean },
+    pacific = { 0.0, -140.0, Location::Ocean },
+    off_portugal = { 38.7, -11.0, Location::Ocean },
+    fiji_antimeridian = { -16.5, 179.9, Location::Land(Alpha2::Fiji) },
+    wrapped = { 52.52, 373.40, Location::Land(Alpha2::Germany) },
+)]
+fn locations(latitude: f64, longitude: f64, expected: Location) {
+    assert_eq!(Some(expected), country_at(latitude, longitude));
+}
+
+// Transects across borders and coasts, with the point at which each really crosses.
+#[yare::parameterized(
+    forty_ninth = { [48.5, -100.0], [49.5, -100.0], [49.0, -100.0], Location::Land(Alpha2::UnitedStatesOfAmerica), Location::Land(Alpha2::Canada) },
+    rhine = { [48.58, 7.3], [48.58, 8.3], [48.58, 7.8], Location::Land(Alpha2::France), Location::Land(Alpha2::Germany) },
+    oder = { [52.35, 14.0], [52.35, 15.0], [52.35, 14.56], Location::Land(Alpha2::Germany), Location::Land(Alpha2::Poland) },
+    sonora = { [30.8, -110.0], [31.8, -110.0], [31.33, -110.0], Location::Land(Alpha2::Mexico), Location::Land(Alpha2::UnitedStatesOfAmerica) },
+    lisbon_coast = { [38.75, -9.0], [38.75, -10.0], [38.75, -9.48], Location::Land(Alpha2::Portugal), Location::Ocean },
+    hampshire_coast = { [50.5, -1.0], [51.0, -1.0], [50.78, -1.0], Location::Ocean, Location::Land(Alpha2::UnitedKingdom) },
+    dutch_coast = { [52.1, 5.0], [52.1, 3.5], [52.1, 4.27], Location::Land(Alpha2::Netherlands), Location::Ocean },
+    danube = { [43.3, 25.37], [44.0, 25.37], [43.64, 25.37], Location::Land(Alpha2::Bulgaria), Location::Land(Alpha2::Romania) },
+    valparaiso_coast = { [-33.05, -71.3], [-33.05, -72.0], [-33.05, -71.63], Location::Land(Alpha2::Chile), Location::Ocean },
+    rio_coast = { [-22.7, -43.2], [-23.2, -43.2], [-22.99, -43.2], Location::Land(Alpha2::Brazil), Location::Ocean },
+    ibaraki_coast = { [36.0, 140.3], [36.0, 141.3], [36.0, 140.63], Location::Land(Alpha2::Japan), Location::Ocean },
+)]
+fn crossings(start: [f64; 2], end: [f64; 2], actual: [f64; 2], first: Location, last: Location) {
+    const STEPS: u16 = 1000;
+
+    let at = |step: u16| {
+        let t = f64::from(step) / f64::from(STEPS);
+        [
+            start[0] + (end[0] - start[0]) * t,
+            start[1] + (end[1] - start[1]) * t,
+        ]
+    };
+
+    assert_eq!(Some(first), country_at(start[0], start[1]));
+    assert_eq!(Some(last), country_at(end[0], end[1]));
+
+    let crossing = (0..=STEPS)
+        .map(at)
+        .find(|point| country_at(point[0], point[1]) != Some(first))
+        .expect("a crossing");
+
+    let north = (crossing[0] - actual[0]) * 111.2;
+    let east = (crossing[1] - actual[1]) * 111.2 * actual[0].to_radians().cos();
+    let distance = north.hypot(east);
+    assert!(
+        distance <= CROSSING_KM,
+        "crossed at {crossing:?}, {distance:.1} km from {actual:?}"
+    );
+}
+
+#[yare::parameterized(
+    north = { 90.5, 0.0 },
+    south = { -91.0, 0.0 },
+    nan = { f64::NAN, 0.0 },
+    infinite = { 0.0, f64::INFINITY },
+)]
+fn invalid(latitude: f64, longitude: f64) {
+    assert_eq!(None, country_at(latitude, longitude));
+}
+
+#[test]
+fn country() {
+    assert_eq!(
+        Some(Alpha2::Portugal),
+        Location::Land(Alpha2::Portugal).country()
+    );
+    assert_eq!(None, Location::Ocean.country());
+}
@@ -0,0 +1,46 @@
+//! Tests for the CLDR localized names, built from the German excerpt in `iso3166-parsers` when
+//! `ISO3166_CLDR_DIR` points at `parsers/src/cldr`.
+//!
+//! The tests which need the German names are ignored when `ISO3166_CLDR_DIR` is not set, and run
+//! with e.g. `ISO3166_CLDR_DIR=$PWD/parsers/src/cldr cargo test --features cldr`.
+
+#![cfg(feature = "cldr")]
+
+use iso3166_static::{Alpha2, CLDR_LOCALES, LocalizedName};
+
+#[cfg(not(iso3166_cldr_dir))]
+#[test]
+fn without_locales() {
+    assert_eq!(0, CLDR_LOCALES.len());
+    assert_eq!(None, Alpha2::Germany.localized_name("de"));
+}
+
+#[test]
+#[cfg_attr(
+    not(iso3166_cldr_dir),
+    ignore = "ISO3166_CLDR_DIR is not set, so no CLDR locales are included"
+)]
+fn localized_name() {
+    assert!(CLDR_LOCALES.contains(&"de"), "{CLDR_LOCALES:?}");
+    assert_eq!(
+        Some(LocalizedName {
+            name: "Vereinigtes Königreich",
+            short: Some("VK"),
+            variant: None,
+        }),
+        Alpha2::UnitedKingdom.localized_name("de")
+    );
+
+    let congo = Alpha2::Congo.localized_name("de").expect("Congo");
+    assert_eq!(Some("Kongo (Republik)"), congo.variant);
+
+    // Unknown regional locales fall back to their parent.
+    assert_eq!(
+        Some("Deutschland"),
+        Alpha2::Germany
+            .localized_name("de-AT")
+            .map(|name| name.name)
+    );
+    assert_eq!(None, Alpha2::Germany.localized_name("xx"));
+    assert_eq!(None, Alpha2::UserXX.localized_name("de"));
+}
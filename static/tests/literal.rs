@@ -0,0 +1,51 @@
+//! Tests for the compile-time validated literal macros against the generated types.
+
+use iso3166_macros::{alpha2, alpha3, numeric};
+use iso3166_static::{Alpha2, Alpha3, Numeric};
+
+#[test]
+fn expressions() {
+    assert_eq!(Alpha2::UnitedStatesOfAmerica, alpha2!("US"));
+    assert_eq!(Alpha2::UserZZ, alpha2!("ZZ"));
+    assert_eq!(Alpha3::UnitedStatesOfAmerica, alpha3!("USA"));
+    assert_eq!(Alpha3::UserXAA, alpha3!("XAA"));
+    assert_eq!(Numeric::UnitedStatesOfAmerica, numeric!(840));
+    assert_eq!(Numeric::User950, numeric!(950));
+}
+
+#[test]
+fn constants() {
+    const USA: Alpha2 = alpha2!("US");
+
+    assert_eq!(Alpha2::UnitedStatesOfAmerica, USA);
+}
+
+fn north_america(value: Alpha2) -> Option<bool> {
+    match value {
+        alpha2!("CA" | "MX" | "US") => Some(true),
+        alpha2!("ZZ") => None,
+        _ => Some(false),
+    }
+}
+
+#[yare::parameterized(
+    canada = { Alpha2::Canada, Some(true) },
+    mexico = { Alpha2::Mexico, Some(true) },
+    usa = { Alpha2::UnitedStatesOfAmerica, Some(true) },
+    germany = { Alpha2::Germany, Some(false) },
+    unknown = { Alpha2::UserZZ, None },
+)]
+fn patterns(input: Alpha2, expected: Option<bool>) {
+    assert_eq!(expected, north_america(input));
+}
+
+#[test]
+fn numeric_patterns() {
+    let region = match Numeric::Germany {
+        numeric!(276 | 250) => "europe",
+        numeric!(840) => "america",
+        _ => "elsewhere",
+    };
+
+    assert_eq!("europe", region);
+}
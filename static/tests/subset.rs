@@ -0,0 +1,66 @@
+//! Tests for the `Iso3166Subset` derive against the generated types.
+
+use core::str::FromStr;
+use iso3166_macros::Iso3166Subset;
+use iso3166_static::{Alpha2, Alpha3, Error, Numeric};
+
+#[derive(Clone, Copy, Debug, Eq, Iso3166Subset, PartialEq)]
+#[cfg_attr(feature = "serde", iso3166(serde))]
+#[allow(clippy::upper_case_acronyms)]
+enum Market {
+    Germany,
+    FR,
+    GBR,
+    #[iso3166("US")]
+    America,
+}
+
+#[yare::parameterized(
+    germany = { Market::Germany, Alpha2::Germany, Alpha3::Germany, Numeric::Germany },
+    france = { Market::FR, Alpha2::France, Alpha3::France, Numeric::France },
+    uk = { Market::GBR, Alpha2::UnitedKingdom, Alpha3::UnitedKingdom, Numeric::UnitedKingdom },
+    usa = {
+        Market::America,
+        Alpha2::UnitedStatesOfAmerica,
+        Alpha3::UnitedStatesOfAmerica,
+        Numeric::UnitedStatesOfAmerica
+    },
+)]
+fn roundtrip(market: Market, alpha2: Alpha2, alpha3: Alpha3, numeric: Numeric) {
+    assert_eq!(alpha2, Alpha2::from(market));
+    assert_eq!(alpha3, Alpha3::from(market));
+    assert_eq!(numeric, Numeric::from(market));
+
+    assert_eq!(Ok(market), Market::try_from(alpha2));
+    assert_eq!(Ok(market), Market::try_from(alpha3));
+    assert_eq!(Ok(market), Market::try_from(numeric));
+}
+
+#[test]
+fn outside_subset() {
+    assert_eq!(Err(Error::UnknownCode), Market::try_from(Alpha2::Canada));
+    assert_eq!(Err(Error::UnknownCode), Market::try_from(Alpha3::UserZZZ));
+    assert_eq!(Err(Error::UnknownCode), Market::try_from(Numeric::User900));
+}
+
+#[yare::parameterized(
+    alpha2 = { "US", Ok(Market::America) },
+    alpha3 = { "DEU", Ok(Market::Germany) },
+    outside = { "CA", Err(Error::UnknownCode) },
+    length = { "GERMANY", Err(Error::InvalidLength) },
+)]
+fn from_str(input: &str, expected: Result<Market, Error>) {
+    assert_eq!(expected, Market::from_str(input));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let json = serde_json::to_string(&Market::GBR).expect("serialization");
+    assert_eq!("\"GB\"", json);
+
+    let actual = serde_json::from_str::<Market>(&json).expect("deserialization");
+    assert_eq!(Market::GBR, actual);
+
+    assert!(serde_json::from_str::<Market>("\"CA\"").is_err());
+}